@@ -0,0 +1,399 @@
+//! A parser for a small line-based text format describing linear models; see
+//! [`Solver::load_linear_text`] for a description of the format.
+use std::io::BufRead;
+
+use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
+use crate::basic_types::LinearTextError;
+use crate::constraints;
+use crate::variables::DomainId;
+use crate::variables::TransformableVariable;
+use crate::Solver;
+
+/// A single non-empty line of the text format.
+#[derive(Debug, PartialEq, Eq)]
+enum Line<'a> {
+    /// `name in lower_bound..upper_bound`
+    Declaration {
+        name: &'a str,
+        lower_bound: i32,
+        upper_bound: i32,
+    },
+    /// `a_1 x_1 + ... + a_n x_n <comparison> rhs`
+    Constraint {
+        terms: Vec<(i32, &'a str)>,
+        comparison: Comparison,
+        rhs: i32,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Comparison {
+    LessThanOrEqual,
+    GreaterThanOrEqual,
+    Equal,
+}
+
+pub(crate) fn load_linear_text(
+    solver: &mut Solver,
+    reader: impl BufRead,
+) -> Result<HashMap<String, DomainId>, LinearTextError> {
+    let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+    // The whole model is checked before the solver is modified
+    let model = parse_model(&lines)?;
+
+    let mut variables = HashMap::default();
+    for (line_number, line) in model {
+        match line {
+            Line::Declaration {
+                name,
+                lower_bound,
+                upper_bound,
+            } => {
+                let domain = solver
+                    .try_new_named_bounded_integer(lower_bound, upper_bound, name)
+                    .map_err(|error| LinearTextError::VariableCreation {
+                        line: line_number,
+                        error,
+                    })?;
+                let _ = variables.insert(name.to_owned(), domain);
+            }
+            Line::Constraint {
+                terms,
+                comparison,
+                rhs,
+            } => {
+                let terms = terms
+                    .into_iter()
+                    .map(|(coefficient, name)| variables[name].scaled(coefficient))
+                    .collect::<Vec<_>>();
+
+                let result = match comparison {
+                    Comparison::LessThanOrEqual => solver
+                        .add_constraint(constraints::less_than_or_equals(terms, rhs))
+                        .post(),
+                    Comparison::Equal => solver
+                        .add_constraint(constraints::equals(terms, rhs))
+                        .post(),
+                    Comparison::GreaterThanOrEqual => {
+                        unreachable!("'>=' constraints are rewritten by parse_model")
+                    }
+                };
+
+                result.map_err(|error| LinearTextError::Constraint {
+                    line: line_number,
+                    error,
+                })?;
+            }
+        }
+    }
+
+    Ok(variables)
+}
+
+/// Parses the non-empty lines together with their (1-based) line numbers, and checks that every
+/// variable is declared exactly once and before it is used. Constraints using `>=` are rewritten
+/// to `<=` by negating both sides.
+fn parse_model(lines: &[String]) -> Result<Vec<(usize, Line<'_>)>, LinearTextError> {
+    let mut model = Vec::new();
+    let mut declared: HashSet<&str> = HashSet::default();
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        let syntax_error = |message| LinearTextError::Syntax {
+            line: line_number,
+            message,
+        };
+
+        let Some(parsed) = parse_line(line).map_err(syntax_error)? else {
+            continue;
+        };
+
+        let parsed = match parsed {
+            Line::Declaration { name, .. } => {
+                if !declared.insert(name) {
+                    return Err(LinearTextError::DuplicateVariable {
+                        line: line_number,
+                        name: name.into(),
+                    });
+                }
+                parsed
+            }
+            Line::Constraint {
+                terms,
+                comparison,
+                rhs,
+            } => {
+                if let Some(&(_, name)) = terms.iter().find(|(_, name)| !declared.contains(name)) {
+                    return Err(LinearTextError::UndeclaredVariable {
+                        line: line_number,
+                        name: name.into(),
+                    });
+                }
+
+                if comparison == Comparison::GreaterThanOrEqual {
+                    let terms = terms
+                        .into_iter()
+                        .map(|(coefficient, name)| {
+                            coefficient
+                                .checked_neg()
+                                .map(|coefficient| (coefficient, name))
+                                .ok_or_else(|| {
+                                    format!("the coefficient of '{name}' cannot be negated").into()
+                                })
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(syntax_error)?;
+                    let rhs = rhs.checked_neg().ok_or_else(|| {
+                        syntax_error(format!("the right-hand side {rhs} cannot be negated").into())
+                    })?;
+
+                    Line::Constraint {
+                        terms,
+                        comparison: Comparison::LessThanOrEqual,
+                        rhs,
+                    }
+                } else {
+                    Line::Constraint {
+                        terms,
+                        comparison,
+                        rhs,
+                    }
+                }
+            }
+        };
+
+        model.push((line_number, parsed));
+    }
+
+    Ok(model)
+}
+
+/// Parses a single line, returning `None` if it is empty or a comment, or a message describing
+/// why the line is invalid.
+fn parse_line(line: &str) -> Result<Option<Line<'_>>, Box<str>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('%') {
+        return Ok(None);
+    }
+
+    let tokens = line.split_whitespace().collect::<Vec<_>>();
+
+    if let [name, "in", range] = tokens.as_slice() {
+        let (lower_bound, upper_bound) = range
+            .split_once("..")
+            .ok_or_else(|| format!("expected a range 'lb..ub', got '{range}'"))?;
+
+        return Ok(Some(Line::Declaration {
+            name: parse_identifier(name)?,
+            lower_bound: parse_integer(lower_bound)?,
+            upper_bound: parse_integer(upper_bound)?,
+        }));
+    }
+
+    let comparison_index = tokens
+        .iter()
+        .position(|token| parse_comparison(token).is_some())
+        .ok_or("expected a variable declaration or a linear constraint")?;
+    let comparison = parse_comparison(tokens[comparison_index]).unwrap();
+
+    let rhs = match &tokens[comparison_index + 1..] {
+        [rhs] => parse_integer(rhs)?,
+        _ => return Err("expected a single integer as the right-hand side".into()),
+    };
+
+    Ok(Some(Line::Constraint {
+        terms: parse_terms(&tokens[..comparison_index])?,
+        comparison,
+        rhs,
+    }))
+}
+
+/// Parses a sum of terms of the form `[coefficient] variable`, separated by `+` or `-`.
+fn parse_terms<'a>(tokens: &[&'a str]) -> Result<Vec<(i32, &'a str)>, Box<str>> {
+    let mut terms = Vec::new();
+    let mut sign: Option<i32> = None;
+    let mut coefficient: Option<i32> = None;
+
+    for &token in tokens {
+        let expects_term = terms.is_empty() || sign.is_some();
+
+        match token {
+            "+" | "-" if sign.is_none() && coefficient.is_none() => {
+                sign = Some(if token == "-" { -1 } else { 1 });
+            }
+            _ if expects_term && coefficient.is_none() && token.parse::<i32>().is_ok() => {
+                coefficient = Some(parse_integer(token)?);
+            }
+            _ if expects_term => {
+                let name = parse_identifier(token)?;
+                let scale = sign
+                    .unwrap_or(1)
+                    .checked_mul(coefficient.unwrap_or(1))
+                    .ok_or_else(|| format!("the coefficient of '{name}' is out of range"))?;
+
+                terms.push((scale, name));
+                sign = None;
+                coefficient = None;
+            }
+            _ => return Err(format!("expected '+' or '-', got '{token}'").into()),
+        }
+    }
+
+    if sign.is_some() || coefficient.is_some() {
+        return Err("expected a variable at the end of the left-hand side".into());
+    }
+    if terms.is_empty() {
+        return Err("expected at least one term on the left-hand side".into());
+    }
+
+    Ok(terms)
+}
+
+fn parse_comparison(token: &str) -> Option<Comparison> {
+    match token {
+        "<=" => Some(Comparison::LessThanOrEqual),
+        ">=" => Some(Comparison::GreaterThanOrEqual),
+        "==" => Some(Comparison::Equal),
+        _ => None,
+    }
+}
+
+fn parse_integer(token: &str) -> Result<i32, Box<str>> {
+    token
+        .parse()
+        .map_err(|_| format!("'{token}' is not a valid integer").into())
+}
+
+fn parse_identifier(token: &str) -> Result<&str, Box<str>> {
+    let mut characters = token.chars();
+    let is_valid = characters
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && characters.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_valid {
+        Ok(token)
+    } else {
+        Err(format!("'{token}' is not a valid variable name").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn terms_with_signs_and_implicit_coefficients_are_parsed() {
+        let line = parse_line("3 x0 - 4 x1 + x2 + -2 x3 <= -2").unwrap();
+
+        assert_eq!(
+            Some(Line::Constraint {
+                terms: vec![(3, "x0"), (-4, "x1"), (1, "x2"), (-2, "x3")],
+                comparison: Comparison::LessThanOrEqual,
+                rhs: -2,
+            }),
+            line
+        );
+    }
+
+    #[test]
+    fn declarations_comments_and_empty_lines_are_parsed() {
+        assert_eq!(
+            Some(Line::Declaration {
+                name: "y0",
+                lower_bound: -1,
+                upper_bound: 3,
+            }),
+            parse_line("y0 in -1..3").unwrap()
+        );
+        assert_eq!(None, parse_line("% a comment").unwrap());
+        assert_eq!(None, parse_line("   ").unwrap());
+    }
+
+    #[test]
+    fn missing_operator_between_terms_is_rejected() {
+        assert!(parse_line("3 x0 4 x1 <= 2").is_err());
+        assert!(parse_line("3 x0 + <= 2").is_err());
+        assert!(parse_line("<= 2").is_err());
+    }
+
+    #[test]
+    fn loaded_model_propagates_like_the_equivalent_constraints() {
+        let text = "\
+% 2 x + 3 y <= 6, x + y >= 2 and y == z
+x in 0..10
+y in 0..10
+z in 1..5
+2 x + 3 y <= 6
+x + y >= 2
+y - z == 0
+";
+        let mut loaded = Solver::default();
+        let variables = loaded.load_linear_text(text.as_bytes()).unwrap();
+
+        let mut built = Solver::default();
+        let x = built.new_bounded_integer(0, 10);
+        let y = built.new_bounded_integer(0, 10);
+        let z = built.new_bounded_integer(1, 5);
+        let _ = built
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(2), y.scaled(3)],
+                6,
+            ))
+            .post();
+        let _ = built
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(-1), y.scaled(-1)],
+                -2,
+            ))
+            .post();
+        let _ = built
+            .add_constraint(constraints::equals([y.scaled(1), z.scaled(-1)], 0))
+            .post();
+
+        for (name, expected) in [("x", x), ("y", y), ("z", z)] {
+            let actual = variables[name];
+            assert_eq!(
+                (built.lower_bound(&expected), built.upper_bound(&expected)),
+                (loaded.lower_bound(&actual), loaded.upper_bound(&actual)),
+                "bounds of {name} differ"
+            );
+        }
+    }
+
+    #[test]
+    fn undeclared_variable_is_reported_with_its_line() {
+        let mut solver = Solver::default();
+        let result = solver.load_linear_text("x in 0..1\n\nx + y <= 1\n".as_bytes());
+
+        assert!(matches!(
+            result,
+            Err(LinearTextError::UndeclaredVariable { line: 3, ref name }) if name.as_ref() == "y"
+        ));
+    }
+
+    #[test]
+    fn invalid_line_leaves_the_solver_unchanged() {
+        let mut solver = Solver::default();
+        let result = solver.load_linear_text("x in 0..5\nx <= 2\nx <= 1 +\n".as_bytes());
+
+        assert!(matches!(
+            result,
+            Err(LinearTextError::Syntax { line: 3, .. })
+        ));
+        // The variable declared on the first line has not been created
+        assert_eq!(DomainId::new(0), solver.new_bounded_integer(0, 5));
+    }
+
+    #[test]
+    fn duplicate_declaration_is_rejected() {
+        let mut solver = Solver::default();
+        let result = solver.load_linear_text("x in 0..1\nx in 0..2\n".as_bytes());
+
+        assert!(matches!(
+            result,
+            Err(LinearTextError::DuplicateVariable { line: 2, .. })
+        ));
+    }
+}
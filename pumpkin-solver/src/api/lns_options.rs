@@ -0,0 +1,27 @@
+#[cfg(doc)]
+use crate::Solver;
+
+/// Options which determine how the large neighbourhood search of [`Solver::minimise_with_lns`] and
+/// [`Solver::maximise_with_lns`] behaves.
+#[derive(Clone, Copy, Debug)]
+pub struct LnsOptions {
+    /// The probability with which a variable is relaxed in an iteration; all variables which are
+    /// not relaxed are fixed to their value in the best solution found so far.
+    pub relaxation_probability: f64,
+    /// The number of conflicts which the solver may encounter while searching a single
+    /// neighbourhood before moving on to the next neighbourhood.
+    pub conflict_budget: u64,
+    /// The maximum number of neighbourhoods which are searched. If [`None`], then the search only
+    /// stops when the termination condition triggers or when optimality is proven.
+    pub max_iterations: Option<u64>,
+}
+
+impl Default for LnsOptions {
+    fn default() -> Self {
+        LnsOptions {
+            relaxation_probability: 0.3,
+            conflict_budget: 1000,
+            max_iterations: None,
+        }
+    }
+}
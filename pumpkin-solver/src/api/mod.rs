@@ -1,3 +1,6 @@
+pub(crate) mod linear_text;
+pub(crate) mod lns_options;
+pub(crate) mod objective;
 mod outputs;
 pub(crate) mod solver;
 
@@ -9,6 +12,8 @@ pub mod results {
     //! - For a **satisfaction** problem using **assumptions**
     //!   ([`SatisfactionResultUnderAssumptions`])
     //! - For an **optimisation** problem ([`OptimisationResult`])
+    //! - For checking whether a bound on an objective can be satisfied
+    //!   ([`ObjectiveBoundResult`])
     //!
     //! On these results, different methods can be called which ensure that the solver is in the
     //! right state for these operations. For example,
@@ -17,12 +22,14 @@ pub mod results {
     pub use crate::api::outputs::solution_callback_arguments::SolutionCallbackArguments;
     pub use crate::api::outputs::solution_iterator;
     pub use crate::api::outputs::unsatisfiable;
+    pub use crate::api::outputs::ObjectiveBoundResult;
     pub use crate::api::outputs::OptimisationResult;
     pub use crate::api::outputs::ProblemSolution;
     pub use crate::api::outputs::SatisfactionResult;
     pub use crate::api::outputs::SatisfactionResultUnderAssumptions;
     pub use crate::api::outputs::SolutionReference;
     pub use crate::basic_types::Solution;
+    pub use crate::basic_types::ViolatedConstraint;
     #[cfg(doc)]
     use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
     #[cfg(doc)]
@@ -47,11 +54,17 @@ pub mod variables {
     //!   used when interacting with the [`Solver`]. A [`Literal`] is used when a
     //!   [`PropositionalVariable`] is given a polarity (i.e. it is the positive [`Literal`] or its
     //!   negated version). A [`Literal`] can be created using [`Solver::new_literal`].
+    //!
+    //! Additionally, a [`SetVariable`] represents a subset of a fixed universe of integers, with a
+    //! 0/1 [`DomainId`] for every element which indicates whether it is a member; it can be
+    //! created using [`Solver::new_set_variable`].
     pub use crate::engine::variables::AffineView;
     pub use crate::engine::variables::DomainId;
+    pub use crate::engine::variables::FlattenedVariable;
     pub use crate::engine::variables::IntegerVariable;
     pub use crate::engine::variables::Literal;
     pub use crate::engine::variables::PropositionalVariable;
+    pub use crate::engine::variables::SetVariable;
     pub use crate::engine::variables::TransformableVariable;
     #[cfg(doc)]
     use crate::Solver;
@@ -70,11 +83,16 @@ pub mod options {
     //! - The restart strategy of the solver
     //! - The learned clause database management approach
     //! - The proof logging
+    //! - The large neighbourhood search
+    //! - The direction of the objective
+    pub use crate::api::lns_options::LnsOptions;
+    pub use crate::api::objective::OptimisationDirection;
     pub use crate::basic_types::sequence_generators::SequenceGeneratorType;
     pub use crate::engine::LearnedClauseSortingStrategy;
     pub use crate::engine::LearningOptions;
     pub use crate::engine::RestartOptions;
     pub use crate::engine::SatisfactionSolverOptions as SolverOptions;
+    pub use crate::engine::SearchTreeLog;
     pub use crate::propagators::CumulativeExplanationType;
     pub use crate::propagators::CumulativeOptions;
     pub use crate::propagators::CumulativePropagationMethod;
@@ -94,6 +112,7 @@ pub mod termination {
     //! the time budget is exceeded.
     pub use crate::engine::termination::combinator::*;
     pub use crate::engine::termination::indefinite::*;
+    pub use crate::engine::termination::interrupt::*;
     pub use crate::engine::termination::os_signal::*;
     pub use crate::engine::termination::time_budget::*;
     pub use crate::engine::termination::TerminationCondition;
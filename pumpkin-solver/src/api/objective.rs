@@ -0,0 +1,24 @@
+use crate::basic_types::Solution;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
+#[cfg(doc)]
+use crate::Solver;
+
+/// Whether the objective which is set using [`Solver::set_objective`] is minimised or maximised.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptimisationDirection {
+    Minimise,
+    Maximise,
+}
+
+/// The objective stored in the [`Solver`], together with the state of the optimisation which is
+/// kept across calls to [`Solver::optimise`].
+#[derive(Debug)]
+pub(crate) struct Objective {
+    /// The variable which is minimised; when maximising, this is the objective variable scaled
+    /// by `-1`.
+    pub(crate) variable: AffineView<DomainId>,
+    pub(crate) direction: OptimisationDirection,
+    /// The best solution found so far together with its value of [`Objective::variable`].
+    pub(crate) incumbent: Option<(i64, Solution)>,
+}
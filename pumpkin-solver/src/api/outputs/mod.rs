@@ -8,6 +8,7 @@ pub mod unsatisfiable;
 use crate::branching::Brancher;
 #[cfg(doc)]
 use crate::termination::TerminationCondition;
+use crate::variables::Literal;
 #[cfg(doc)]
 use crate::Solver;
 
@@ -46,7 +47,15 @@ pub enum SatisfactionResultUnderAssumptions<'solver, 'brancher, B: Brancher> {
 pub enum OptimisationResult {
     /// Indicates that an optimal solution has been found and proven to be optimal. It provides an
     /// instance of [`Solution`] which contains the optimal solution.
-    Optimal(Solution),
+    ///
+    /// The `blocking_core` contains the literal of the bound requiring the objective to improve
+    /// upon `solution`, which was shown to be unsatisfiable together with the constraints in the
+    /// [`Solver`]; i.e. it is the core which blocks any further improvement. It is empty if the
+    /// bound was not needed to prove optimality.
+    Optimal {
+        solution: Solution,
+        blocking_core: Box<[Literal]>,
+    },
     /// Indicates that a solution was found and provides an instance of [`Solution`] which contains
     /// best known solution by the solver.
     Satisfiable(Solution),
@@ -56,3 +65,20 @@ pub enum OptimisationResult {
     /// [`TerminationCondition`] triggering.
     Unknown,
 }
+
+/// The result of a call to [`Solver::solve_and_get_core_on_unsat`].
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ObjectiveBoundResult {
+    /// Indicates that a solution which satisfies the bound on the objective was found and provides
+    /// the corresponding [`Solution`].
+    Satisfiable(Solution),
+    /// Indicates that there is no solution which satisfies the bound on the objective and the
+    /// assumptions. The `blocking_core` is a subset of the assumptions (including the literal of
+    /// the objective bound) which together cannot be satisfied; it is empty if the problem has no
+    /// solution at all.
+    Unsatisfiable { blocking_core: Box<[Literal]> },
+    /// Indicates that it is not known whether a solution exists. This is likely due to a
+    /// [`TerminationCondition`] triggering.
+    Unknown,
+}
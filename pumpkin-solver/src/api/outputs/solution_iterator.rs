@@ -18,6 +18,8 @@ pub struct SolutionIterator<'solver, 'brancher, 'termination, B: Brancher, T> {
     termination: &'termination mut T,
     next_blocking_clause: Option<Vec<Literal>>,
     has_solution: bool,
+    /// Whether the iterator (as an [`Iterator`]) has stopped yielding solutions.
+    is_exhausted: bool,
 }
 
 impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
@@ -34,6 +36,7 @@ impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
             termination,
             next_blocking_clause: None,
             has_solution: false,
+            is_exhausted: false,
         }
     }
 
@@ -66,6 +69,30 @@ impl<'solver, 'brancher, 'termination, B: Brancher, T: TerminationCondition>
     }
 }
 
+/// Yields the solutions which are found by [`SolutionIterator::next_solution`] until no more
+/// solutions exist, the problem is unsatisfiable, or the termination condition is triggered. In
+/// the latter cases, the reason why the iteration stopped is not reported; use
+/// [`SolutionIterator::next_solution`] if it is needed.
+impl<B: Brancher, T: TerminationCondition> Iterator for SolutionIterator<'_, '_, '_, B, T> {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_exhausted {
+            return None;
+        }
+
+        match self.next_solution() {
+            IteratedSolution::Solution(solution) => Some(solution),
+            IteratedSolution::Finished
+            | IteratedSolution::Unknown
+            | IteratedSolution::Unsatisfiable => {
+                self.is_exhausted = true;
+                None
+            }
+        }
+    }
+}
+
 /// Creates a clause which prevents the current solution from occurring again by going over the
 /// defined output variables and creating a clause which prevents those values from
 /// being assigned.
@@ -106,3 +133,46 @@ pub enum IteratedSolution {
     /// There exists no solution
     Unsatisfiable,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::HashSet;
+    use crate::constraints;
+    use crate::results::ProblemSolution;
+    use crate::termination::Indefinite;
+
+    #[test]
+    fn taking_solutions_yields_distinct_solutions() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 3);
+        let y = solver.new_bounded_integer(0, 3);
+        let _ = solver
+            .add_constraint(constraints::binary_not_equals(x, y))
+            .post();
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let solutions = solver
+            .solutions(&mut brancher, &mut Indefinite)
+            .take(3)
+            .map(|solution| (solution.get_integer_value(x), solution.get_integer_value(y)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(3, solutions.len());
+        assert_eq!(3, solutions.iter().collect::<HashSet<_>>().len());
+        assert!(solutions.iter().all(|(x, y)| x != y));
+    }
+
+    #[test]
+    fn iterating_ends_when_all_solutions_are_found() {
+        let mut solver = Solver::default();
+        let _ = solver.new_bounded_integer(0, 2);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let mut termination = Indefinite;
+        let mut solutions = solver.solutions(&mut brancher, &mut termination);
+
+        assert_eq!(3, solutions.by_ref().count());
+        assert!(solutions.next().is_none());
+    }
+}
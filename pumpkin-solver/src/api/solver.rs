@@ -1,12 +1,27 @@
+use std::io::BufRead;
 use std::num::NonZero;
 
+use log::warn;
+
+use super::linear_text;
+use super::lns_options::LnsOptions;
+use super::objective::Objective;
+use super::objective::OptimisationDirection;
+use super::results::ObjectiveBoundResult;
 use super::results::OptimisationResult;
 use super::results::SatisfactionResult;
 use super::results::SatisfactionResultUnderAssumptions;
 use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ConstraintOperationError;
+use crate::basic_types::HashMap;
 use crate::basic_types::HashSet;
+use crate::basic_types::LinearTextError;
+use crate::basic_types::ProblemSolution;
+use crate::basic_types::Random;
+use crate::basic_types::RestartOptionError;
 use crate::basic_types::Solution;
+use crate::basic_types::VariableCreationError;
+use crate::basic_types::ViolatedConstraint;
 use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
 #[cfg(doc)]
 use crate::branching::value_selection::ValueSelector;
@@ -16,15 +31,24 @@ use crate::branching::Brancher;
 use crate::branching::PhaseSaving;
 use crate::branching::SolutionGuidedValueSelector;
 use crate::branching::Vsids;
+use crate::constraints;
+use crate::constraints::Constraint;
 use crate::constraints::ConstraintPoster;
+use crate::engine::constraint_satisfaction_solver::CoreExtractionResult;
 use crate::engine::predicates::predicate::Predicate;
+use crate::engine::predicates::predicate_constructor::PredicateConstructor;
 use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorStatistics;
+use crate::engine::termination::interrupt::InterruptHandle;
 use crate::engine::termination::TerminationCondition;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
+use crate::engine::variables::SetVariable;
 use crate::engine::ConstraintSatisfactionSolver;
 use crate::options::LearningOptions;
+#[cfg(doc)]
+use crate::options::RestartOptions;
 use crate::options::SolverOptions;
 use crate::predicate;
 use crate::pumpkin_assert_simple;
@@ -33,7 +57,9 @@ use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
 use crate::results::SolutionCallbackArguments;
 use crate::statistics::statistic_logging::log_statistic;
 use crate::statistics::statistic_logging::log_statistic_postfix;
+use crate::variables::AffineView;
 use crate::variables::PropositionalVariable;
+use crate::variables::TransformableVariable;
 
 /// The main interaction point which allows the creation of variables, the addition of constraints,
 /// and solving problems.
@@ -90,6 +116,45 @@ pub struct Solver {
     /// The function is called whenever an optimisation function finds a solution; see
     /// [`Solver::with_solution_callback`].
     solution_callback: Box<dyn Fn(SolutionCallbackArguments)>,
+    /// The normalised form (left-hand side and right-hand side) and the tag of every linear
+    /// inequality which has been posted; it is used to avoid adding a second propagator for an
+    /// identical inequality with the same tag.
+    posted_linear_inequalities: HashSet<NormalisedLinearConstraint>,
+    /// The normalised form and the tag of every linear equality which has been posted, which is
+    /// used in the same way as [`Solver::posted_linear_inequalities`].
+    posted_linear_equalities: HashSet<NormalisedLinearConstraint>,
+    /// The objective which is optimised by [`Solver::optimise`]; see [`Solver::set_objective`].
+    objective: Option<Objective>,
+    /// The violation indicators and penalties of the soft constraints which have been added
+    /// through [`Solver::add_soft_constraint`].
+    soft_constraints: Vec<(DomainId, i32)>,
+}
+
+/// A linear inequality `\sum a_i * x_i <= c` (or equality `\sum a_i * x_i = c`), described by the
+/// pairs `(x_i, a_i)` and `c`, together with the tag it was posted with.
+type NormalisedLinearConstraint = (Box<[(DomainId, i64)]>, i64, Option<NonZero<u32>>);
+
+/// Returns the left-hand and right-hand sides of the posted `linear_constraints` in a fixed order;
+/// a constraint which is posted with several tags is only returned once.
+fn unique_linear_constraints(
+    linear_constraints: &HashSet<NormalisedLinearConstraint>,
+) -> Vec<(&[(DomainId, i64)], i64)> {
+    let mut unique_constraints = linear_constraints
+        .iter()
+        .map(|(terms, rhs, _)| (terms.as_ref(), *rhs))
+        .collect::<Vec<_>>();
+    unique_constraints.sort_by_key(|&(terms, rhs)| {
+        (
+            terms
+                .iter()
+                .map(|&(domain, coefficient)| (domain.id, coefficient))
+                .collect::<Vec<_>>(),
+            rhs,
+        )
+    });
+    unique_constraints.dedup();
+
+    unique_constraints
 }
 
 impl Default for Solver {
@@ -97,6 +162,10 @@ impl Default for Solver {
         Self {
             satisfaction_solver: Default::default(),
             solution_callback: create_empty_function(),
+            posted_linear_inequalities: HashSet::default(),
+            posted_linear_equalities: HashSet::default(),
+            objective: None,
+            soft_constraints: Vec::default(),
         }
     }
 }
@@ -116,18 +185,66 @@ impl std::fmt::Debug for Solver {
 
 impl Solver {
     /// Creates a solver with the provided [`LearningOptions`] and [`SolverOptions`].
-    pub fn with_options(learning_options: LearningOptions, solver_options: SolverOptions) -> Self {
+    ///
+    /// Restart options which are invalid (see [`RestartOptions::validate`]) are replaced by valid
+    /// values and a warning is logged for each of them; see [`Solver::try_with_options`] for a
+    /// variant which returns an error instead.
+    pub fn with_options(
+        learning_options: LearningOptions,
+        mut solver_options: SolverOptions,
+    ) -> Self {
+        for error in solver_options.restart_options.replace_invalid_options() {
+            warn!("{error}, it is replaced by a valid value");
+        }
+
         Solver {
             satisfaction_solver: ConstraintSatisfactionSolver::new(
                 learning_options,
                 solver_options,
             ),
             solution_callback: create_empty_function(),
+            posted_linear_inequalities: HashSet::default(),
+            posted_linear_equalities: HashSet::default(),
+            objective: None,
+            soft_constraints: Vec::default(),
         }
     }
 
+    /// Creates a solver with the provided [`LearningOptions`] and [`SolverOptions`], or returns a
+    /// [`RestartOptionError`] if the restart options are inconsistent (see
+    /// [`RestartOptions::validate`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::options::*;
+    /// # use pumpkin_solver::RestartOptionError;
+    /// # use pumpkin_solver::Solver;
+    /// let solver_options = SolverOptions {
+    ///     restart_options: RestartOptions {
+    ///         sequence_generator_type: SequenceGeneratorType::Geometric,
+    ///         geometric_coef: None,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let result = Solver::try_with_options(LearningOptions::default(), solver_options);
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(RestartOptionError::MissingGeometricCoefficient)
+    /// ));
+    /// ```
+    pub fn try_with_options(
+        learning_options: LearningOptions,
+        solver_options: SolverOptions,
+    ) -> Result<Self, RestartOptionError> {
+        solver_options.restart_options.validate()?;
+
+        Ok(Self::with_options(learning_options, solver_options))
+    }
+
     /// Adds a call-back to the [`Solver`] which is called every time that a solution is found when
-    /// optimising using [`Solver::maximise`] or [`Solver::minimise`].
+    /// optimising using [`Solver::maximise`], [`Solver::minimise`] or [`Solver::optimise`].
     ///
     /// Note that this will also
     /// perform the call-back on the optimal solution which is returned in
@@ -151,9 +268,145 @@ impl Solver {
         log_statistic_postfix();
     }
 
+    /// Resets the statistics of the solver (e.g. the number of conflicts and decisions), such that
+    /// statistics which are logged afterwards only describe the solving which happens after this
+    /// call. This can be used to obtain statistics for separate phases of solving.
+    ///
+    /// The constraints, the learned clauses, and the domains of the variables are not affected.
+    pub fn reset_statistics(&mut self) {
+        self.satisfaction_solver.reset_statistics()
+    }
+
+    /// Creates a diagnostic snapshot of the solver which can be included in a bug report; it
+    /// contains the decision level, the sizes of the trails, the number of propagators, the last
+    /// conflict (if the solver is in a conflicting state) and the statistics.
+    pub fn dump_state(&self) -> String {
+        self.satisfaction_solver.dump_state()
+    }
+
+    /// Returns the name and the [`PropagatorStatistics`] of every propagator in the solver, in the
+    /// order in which they were added.
+    ///
+    /// The statistics describe how often a propagator was called without changing any domain;
+    /// a high [`PropagatorStatistics::thrashing_ratio`] indicates that a propagator is woken up
+    /// more often than necessary. They are reset by [`Solver::reset_statistics`].
+    pub fn propagator_stats(&self) -> impl Iterator<Item = (&str, PropagatorStatistics)> + '_ {
+        self.satisfaction_solver.propagator_statistics()
+    }
+
+    /// Returns an iterator over the literals of the learned clauses which are currently stored by
+    /// the solver. Clauses which have been removed from the learned clause database are skipped.
+    ///
+    /// This is mainly intended for debugging and inspecting the clausal side of the solver.
+    pub fn learned_clauses(&self) -> impl Iterator<Item = &[Literal]> + '_ {
+        self.satisfaction_solver.learned_clauses()
+    }
+
+    /// Checks whether `solution` satisfies all of the constraints which have been added to the
+    /// solver, returning the violated constraints otherwise.
+    ///
+    /// Clauses are evaluated directly; the other constraints are checked by the propagators
+    /// which enforce them, i.e. a constraint is violated if its propagator reports a conflict
+    /// on the assignment of `solution`.
+    ///
+    /// The `solution` should assign every variable of the solver; i.e. no variables should have
+    /// been created after it was found.
+    pub fn verify(&self, solution: &Solution) -> Result<(), Vec<ViolatedConstraint>> {
+        let violated_constraints = self.satisfaction_solver.verify_solution(solution);
+
+        if violated_constraints.is_empty() {
+            Ok(())
+        } else {
+            Err(violated_constraints)
+        }
+    }
+
+    /// Writes the model in the DIMACS CNF format to `writer`; this can be used to compare against
+    /// external SAT solvers. The root-level assignments are written as unit clauses, learned
+    /// clauses are not written.
+    ///
+    /// Only models consisting purely of clauses can be exported; if constraints which are
+    /// propagated by a propagator have been added, then an error of kind
+    /// [`std::io::ErrorKind::InvalidInput`] is returned.
+    pub fn export_dimacs(&self, writer: impl std::io::Write) -> std::io::Result<()> {
+        self.satisfaction_solver.export_dimacs(writer)
+    }
+
+    /// Writes the model in the OPB (pseudo-Boolean) format to `writer`; this can be used to
+    /// compare against external pseudo-Boolean solvers. The clauses are written as in
+    /// [`Solver::export_dimacs`], and every linear inequality which is posted through
+    /// [`constraints::less_than_or_equals`] (or the constraints which are built on it) is written
+    /// over the literals `[x >= 1]` of its 0/1 variables `x`. The linear equalities which are posted
+    /// through [`constraints::equals`] are written in the same way as OPB equalities.
+    ///
+    /// Only models consisting of clauses and linear (in)equalities over 0/1 variables can be
+    /// exported; otherwise an error of kind [`std::io::ErrorKind::InvalidInput`] is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 1);
+    /// let y = solver.new_bounded_integer(0, 1);
+    /// solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 1))
+    ///     .post()
+    ///     .expect("the constraint is consistent");
+    ///
+    /// let mut output = Vec::new();
+    /// solver
+    ///     .export_opb(&mut output)
+    ///     .expect("the model consists of a linear inequality over 0/1 variables");
+    /// assert!(String::from_utf8(output).unwrap().starts_with("* #variable="));
+    /// ```
+    pub fn export_opb(&self, writer: impl std::io::Write) -> std::io::Result<()> {
+        // Every recorded inequality and equality is propagated by its own propagator.
+        if self.satisfaction_solver.num_propagators()
+            != self.posted_linear_inequalities.len() + self.posted_linear_equalities.len()
+        {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the model contains constraints which are not linear (in)equalities",
+            ));
+        }
+
+        self.satisfaction_solver.export_opb(
+            &unique_linear_constraints(&self.posted_linear_inequalities),
+            &unique_linear_constraints(&self.posted_linear_equalities),
+            writer,
+        )
+    }
+
     pub(crate) fn get_satisfaction_solver_mut(&mut self) -> &mut ConstraintSatisfactionSolver {
         &mut self.satisfaction_solver
     }
+
+    /// Records that the linear inequality `\sum terms_i <= rhs` is posted with `tag`, where every
+    /// term is a pair of a domain and its coefficient.
+    ///
+    /// Returns `false` if an identical inequality was posted before with the same tag.
+    pub(crate) fn record_posted_linear_inequality(
+        &mut self,
+        terms: Box<[(DomainId, i64)]>,
+        rhs: i64,
+        tag: Option<NonZero<u32>>,
+    ) -> bool {
+        self.posted_linear_inequalities.insert((terms, rhs, tag))
+    }
+
+    /// Records that the linear equality `\sum terms_i = rhs` is posted with `tag`, where every
+    /// term is a pair of a domain and its coefficient.
+    ///
+    /// Returns `false` if an identical equality was posted before with the same tag.
+    pub(crate) fn record_posted_linear_equality(
+        &mut self,
+        terms: Box<[(DomainId, i64)]>,
+        rhs: i64,
+        tag: Option<NonZero<u32>>,
+    ) -> bool {
+        self.posted_linear_equalities.insert((terms, rhs, tag))
+    }
 }
 
 /// Methods to retrieve information about variables
@@ -205,6 +458,25 @@ impl Solver {
     pub fn upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
         self.satisfaction_solver.get_upper_bound(variable)
     }
+
+    /// Get the value of the given [`IntegerVariable`] in the current state of the solver, which
+    /// is `None` if the variable is not fixed; contrary to [`Solver::lower_bound`] and
+    /// [`Solver::upper_bound`], this cannot accidentally return a bound as if it were a value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let y = solver.new_bounded_integer(5, 5);
+    ///
+    /// assert_eq!(solver.current_value(&x), None);
+    /// assert_eq!(solver.current_value(&y), Some(5));
+    /// ```
+    pub fn current_value(&self, variable: &impl IntegerVariable) -> Option<i32> {
+        let lower_bound = self.lower_bound(variable);
+        (lower_bound == self.upper_bound(variable)).then_some(lower_bound)
+    }
 }
 
 /// Functions to create and retrieve integer and propositional variables.
@@ -267,6 +539,9 @@ impl Solver {
 
     /// Create a new integer variable with the given bounds.
     ///
+    /// Panics if the domain contains more values than allowed by
+    /// [`SolverOptions::max_domain_size`]; see [`Solver::try_new_bounded_integer`].
+    ///
     /// # Example
     /// ```rust
     /// # use pumpkin_solver::Solver;
@@ -282,6 +557,9 @@ impl Solver {
 
     /// Create a new named integer variable with the given bounds.
     ///
+    /// Panics if the domain contains more values than allowed by
+    /// [`SolverOptions::max_domain_size`]; see [`Solver::try_new_named_bounded_integer`].
+    ///
     /// # Example
     /// ```rust
     /// # use pumpkin_solver::Solver;
@@ -303,6 +581,53 @@ impl Solver {
         )
     }
 
+    /// Create a new integer variable with the given bounds, returning an error if the domain
+    /// contains more values than allowed by [`SolverOptions::max_domain_size`].
+    ///
+    /// Note that [`Solver::new_bounded_integer`] panics in that case instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::options::LearningOptions;
+    /// # use pumpkin_solver::options::SolverOptions;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::with_options(
+    ///     LearningOptions::default(),
+    ///     SolverOptions {
+    ///         max_domain_size: Some(1000),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    ///
+    /// assert!(solver.try_new_bounded_integer(0, 10).is_ok());
+    /// assert!(solver.try_new_bounded_integer(0, i32::MAX).is_err());
+    /// ```
+    pub fn try_new_bounded_integer(
+        &mut self,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> Result<DomainId, VariableCreationError> {
+        self.satisfaction_solver
+            .try_create_new_integer_variable(lower_bound, upper_bound, None)
+    }
+
+    /// Create a new named integer variable with the given bounds, returning an error if the domain
+    /// contains more values than allowed by [`SolverOptions::max_domain_size`].
+    ///
+    /// Note that [`Solver::new_named_bounded_integer`] panics in that case instead.
+    pub fn try_new_named_bounded_integer(
+        &mut self,
+        lower_bound: i32,
+        upper_bound: i32,
+        name: impl Into<String>,
+    ) -> Result<DomainId, VariableCreationError> {
+        self.satisfaction_solver.try_create_new_integer_variable(
+            lower_bound,
+            upper_bound,
+            Some(name.into()),
+        )
+    }
+
     /// Create a new integer variable which has a domain of predefined values. We remove duplicates
     /// by converting to a hash set
     ///
@@ -339,6 +664,35 @@ impl Solver {
         self.satisfaction_solver
             .create_new_integer_variable_sparse(values.into(), Some(name.into()))
     }
+
+    /// Create a new set variable whose value is a subset of `universe`; duplicate elements in
+    /// `universe` are ignored. A 0/1 integer variable is created for every element, which
+    /// indicates whether the element is a member of the set.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let set = solver.new_set_variable(&[5, 1, 3]);
+    /// assert_eq!(set.universe(), &[1, 3, 5]);
+    ///
+    /// let membership = set.membership(3).unwrap();
+    /// assert_eq!(solver.lower_bound(&membership), 0);
+    /// assert_eq!(solver.upper_bound(&membership), 1);
+    /// ```
+    pub fn new_set_variable(&mut self, universe: &[i32]) -> SetVariable {
+        let mut universe = universe.to_vec();
+        universe.sort_unstable();
+        universe.dedup();
+
+        let members = universe
+            .iter()
+            .map(|_| self.new_bounded_integer(0, 1))
+            .collect();
+
+        SetVariable::new(universe.into(), members)
+    }
 }
 
 /// Functions for solving with the constraints that have been added to the [`Solver`].
@@ -373,6 +727,33 @@ impl Solver {
         }
     }
 
+    /// Returns an iterator which lazily yields distinct solutions to the satisfaction problem;
+    /// after every solution, a clause which blocks that solution is added to the solver.
+    ///
+    /// The iterator ends when no more solutions exist or when `termination` is triggered. This is
+    /// the same as iterating over [`Solver::get_solution_iterator`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 9);
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let mut termination = Indefinite;
+    /// let solutions = solver.solutions(&mut brancher, &mut termination).take(3);
+    ///
+    /// assert_eq!(solutions.count(), 3);
+    /// ```
+    pub fn solutions<'this, 'brancher, 'termination, B: Brancher, T: TerminationCondition>(
+        &'this mut self,
+        brancher: &'brancher mut B,
+        termination: &'termination mut T,
+    ) -> SolutionIterator<'this, 'brancher, 'termination, B, T> {
+        self.get_solution_iterator(brancher, termination)
+    }
+
     pub fn get_solution_iterator<
         'this,
         'brancher,
@@ -440,6 +821,135 @@ impl Solver {
         }
     }
 
+    /// Returns the assumptions which are responsible for `predicate` being true after propagating
+    /// `assumptions`; this is mainly intended for debugging explanations.
+    ///
+    /// Every assumption is decided in order and propagated, without any further search. Then the
+    /// reasons of the propagated literals are followed back from `predicate`, through the
+    /// explanations of the propagators, until only assumptions remain. Assignments at the root are
+    /// not part of the result, so the result is empty if `predicate` is true at the root. The
+    /// assumptions are returned as the [`Predicate`]s they represent.
+    ///
+    /// Returns [`None`] if the assumptions lead to a conflict, or if `predicate` is not true after
+    /// propagating them. The [`Solver`] is at the root again when this method returns.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::predicate;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let y = solver.new_bounded_integer(0, 10);
+    /// let z = solver.new_bounded_integer(0, 10);
+    /// solver
+    ///     .add_constraint(constraints::binary_less_than_or_equals(x, y))
+    ///     .post()
+    ///     .expect("no root-level conflict");
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let assumptions = [
+    ///     solver.get_literal(predicate![x >= 5]),
+    ///     solver.get_literal(predicate![z <= 3]),
+    /// ];
+    /// let explanation = solver.explain_predicate(&mut brancher, &assumptions, predicate![y >= 5]);
+    /// assert_eq!(Some(vec![predicate![x >= 5]]), explanation);
+    /// ```
+    pub fn explain_predicate(
+        &mut self,
+        brancher: &mut impl Brancher,
+        assumptions: &[Literal],
+        predicate: Predicate,
+    ) -> Option<Vec<Predicate>> {
+        if self.satisfaction_solver.get_state().is_inconsistent() {
+            return None;
+        }
+
+        let explanation = if self.satisfaction_solver.propagate_assumptions(assumptions)
+            && self.get_literal_value(self.get_literal(predicate)) == Some(true)
+        {
+            Some(self.satisfaction_solver.explain_predicate(predicate))
+        } else {
+            None
+        };
+
+        // Reset the state whenever we return a result
+        self.satisfaction_solver.restore_state_at_root(brancher);
+        self.satisfaction_solver.declare_ready();
+
+        explanation
+    }
+
+    /// Solves the current model in the [`Solver`] under the assumption that `objective_variable <=
+    /// bound` (and under the provided `assumptions`), which can be used to check whether a
+    /// solution exists which is better than an incumbent; to bound a maximised objective, provide
+    /// the objective scaled by `-1`.
+    ///
+    /// If no such solution exists, then the returned [`ObjectiveBoundResult::Unsatisfiable`]
+    /// contains a core of the assumptions (see [`UnsatisfiableUnderAssumptions::extract_core`]),
+    /// which shows whether the bound on the objective blocks the improvement and which of the other
+    /// assumptions are involved.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::ObjectiveBoundResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::predicate;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    /// let at_least_three = solver.new_literal();
+    /// solver
+    ///     .add_constraint(constraints::less_than_or_equals([x.scaled(-1)], -3))
+    ///     .implied_by(at_least_three)
+    ///     .expect("no root-level conflict");
+    ///
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// let result =
+    ///     solver.solve_and_get_core_on_unsat(&mut brancher, &mut Indefinite, x, 2, &[at_least_three]);
+    ///
+    /// let ObjectiveBoundResult::Unsatisfiable { blocking_core } = result else {
+    ///     panic!("x cannot be at most 2")
+    /// };
+    /// assert!(blocking_core.contains(&at_least_three));
+    /// assert!(blocking_core.contains(&solver.get_literal(predicate![x <= 2])));
+    /// ```
+    pub fn solve_and_get_core_on_unsat(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        bound: i32,
+        assumptions: &[Literal],
+    ) -> ObjectiveBoundResult {
+        let bound_literal = self
+            .satisfaction_solver
+            .get_literal(objective_variable.upper_bound_predicate(bound));
+        let assumptions = std::iter::once(bound_literal)
+            .chain(assumptions.iter().copied())
+            .collect::<Vec<_>>();
+
+        match self.satisfy_under_assumptions(brancher, termination, &assumptions) {
+            SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                ObjectiveBoundResult::Satisfiable(solution)
+            }
+            SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(
+                mut unsatisfiable,
+            ) => ObjectiveBoundResult::Unsatisfiable {
+                blocking_core: unsatisfiable.extract_core(),
+            },
+            SatisfactionResultUnderAssumptions::Unsatisfiable => {
+                ObjectiveBoundResult::Unsatisfiable {
+                    blocking_core: Box::default(),
+                }
+            }
+            SatisfactionResultUnderAssumptions::Unknown => ObjectiveBoundResult::Unknown,
+        }
+    }
+
     /// Solves the model currently in the [`Solver`] to optimality where the provided
     /// `objective_variable` is minimised (or is indicated to terminate by the provided
     /// [`TerminationCondition`]).
@@ -527,24 +1037,18 @@ impl Solver {
             let objective_bound_literal = self
                 .satisfaction_solver
                 .get_literal(objective_bound_predicate);
+            let improvement_literal = self.improvement_literal(
+                &objective_variable,
+                best_objective_value * objective_multiplier as i64,
+            );
 
-            if self
-                .strengthen(
-                    &objective_variable,
-                    best_objective_value * objective_multiplier as i64,
-                )
-                .is_err()
-            {
-                // Reset the state whenever we return a result
-                self.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = self
-                    .satisfaction_solver
-                    .conclude_proof_optimal(objective_bound_literal);
-
-                return OptimisationResult::Optimal(best_solution);
-            }
-
-            let solve_result = self.satisfaction_solver.solve(termination, brancher);
+            // The bound is imposed through an assumption such that the core which blocks any
+            // further improvement can be extracted once no better solution exists.
+            let solve_result = self.satisfaction_solver.solve_under_assumptions(
+                &[improvement_literal],
+                termination,
+                brancher,
+            );
             match solve_result {
                 CSPSolverExecutionFlag::Feasible => {
                     self.debug_bound_change(
@@ -560,14 +1064,20 @@ impl Solver {
                     );
                 }
                 CSPSolverExecutionFlag::Infeasible => {
-                    {
-                        // Reset the state whenever we return a result
-                        self.satisfaction_solver.restore_state_at_root(brancher);
-                        let _ = self
-                            .satisfaction_solver
-                            .conclude_proof_optimal(objective_bound_literal);
-                        return OptimisationResult::Optimal(best_solution);
-                    }
+                    let blocking_core = self.extract_blocking_core(brancher);
+
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+                    let _ = self
+                        .satisfaction_solver
+                        .add_clause([objective_bound_literal]);
+                    let _ = self
+                        .satisfaction_solver
+                        .conclude_proof_optimal(objective_bound_literal);
+                    return OptimisationResult::Optimal {
+                        solution: best_solution,
+                        blocking_core,
+                    };
                 }
                 CSPSolverExecutionFlag::Timeout => {
                     // Reset the state whenever we return a result
@@ -622,18 +1132,35 @@ impl Solver {
         ));
     }
 
-    /// Given the current objective value `best_objective_value`, it adds a constraint specifying
-    /// that the objective value should be at most `best_objective_value - 1`. Note that it is
-    /// assumed that we are always minimising the variable.
-    fn strengthen(
-        &mut self,
+    /// Returns the core of the assumptions of the previous solve, which was infeasible. The core is
+    /// empty if the solver is infeasible regardless of the assumptions.
+    fn extract_blocking_core(&mut self, brancher: &mut impl Brancher) -> Box<[Literal]> {
+        if !self
+            .satisfaction_solver
+            .state
+            .is_infeasible_under_assumptions()
+        {
+            return Box::default();
+        }
+
+        match self.satisfaction_solver.extract_clausal_core(brancher) {
+            CoreExtractionResult::Core(core) => core.into(),
+            CoreExtractionResult::ConflictingAssumption(_) => {
+                unreachable!("the assumptions never contain a literal together with its negation")
+            }
+        }
+    }
+
+    /// Returns the literal of the bound which requires the (minimised) `objective_variable` to
+    /// improve upon `best_objective_value`.
+    fn improvement_literal(
+        &self,
         objective_variable: &impl IntegerVariable,
         best_objective_value: i64,
-    ) -> Result<(), ConstraintOperationError> {
-        self.satisfaction_solver
-            .add_clause([self.satisfaction_solver.get_literal(
-                objective_variable.upper_bound_predicate((best_objective_value - 1) as i32),
-            )])
+    ) -> Literal {
+        self.satisfaction_solver.get_literal(
+            objective_variable.upper_bound_predicate((best_objective_value - 1) as i32),
+        )
     }
 
     fn debug_bound_change(
@@ -659,59 +1186,529 @@ impl Solver {
     }
 }
 
-/// Functions for adding new constraints to the solver.
+/// Functions for optimising using large neighbourhood search.
 impl Solver {
-    /// Add a constraint to the solver. This returns a [`ConstraintPoster`] which enables control
-    /// on whether to add the constraint as-is, or whether to (half) reify it.
-    ///
-    /// If none of the methods on [`ConstraintPoster`] are used, the constraint _is not_ actually
-    /// added to the solver. In this case, a warning is emitted.
+    /// Minimises the provided `objective_variable` using large neighbourhood search (LNS) \[1\]
+    /// (or until indicated to terminate by the provided [`TerminationCondition`]).
     ///
-    /// # Example
-    /// ```
-    /// # use pumpkin_solver::constraints;
-    /// # use pumpkin_solver::Solver;
-    /// let mut solver = Solver::default();
+    /// After an initial solution is found, every iteration fixes a random subset of `variables` to
+    /// their values in the best solution found so far, and searches for a better solution by only
+    /// changing the remaining (relaxed) variables. The [`LnsOptions`] determine how many variables
+    /// are relaxed, how many conflicts may be encountered in a single iteration, and how many
+    /// iterations are performed.
     ///
-    /// let a = solver.new_bounded_integer(0, 3);
-    /// let b = solver.new_bounded_integer(0, 3);
+    /// Unlike [`Solver::minimise`], this search is not complete; [`OptimisationResult::Optimal`]
+    /// is only returned when the solver happens to prove that no better solution exists.
+    /// Otherwise, the best solution which was found is returned as
+    /// [`OptimisationResult::Satisfiable`].
     ///
-    /// solver.add_constraint(constraints::equals([a, b], 0)).post();
-    /// ```
-    pub fn add_constraint<Constraint>(
+    /// # Bibliography
+    /// \[1\] P. Shaw, ‘Using constraint programming and local search methods to solve vehicle
+    /// routing problems’, in International Conference on Principles and Practice of Constraint
+    /// Programming, 1998, pp. 417–431.
+    pub fn minimise_with_lns(
         &mut self,
-        constraint: Constraint,
-    ) -> ConstraintPoster<'_, Constraint> {
-        ConstraintPoster::new(self, constraint)
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        variables: &[DomainId],
+        options: LnsOptions,
+    ) -> OptimisationResult {
+        self.lns_internal(
+            brancher,
+            termination,
+            objective_variable,
+            variables,
+            options,
+            false,
+        )
     }
 
-    /// Creates a clause from `literals` and adds it to the current formula.
-    ///
-    /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
-    /// returned. Subsequent calls to this method will always return an error, and no
-    /// modification of the solver will take place.
-    pub fn add_clause(
+    /// Maximises the provided `objective_variable` using large neighbourhood search; see
+    /// [`Solver::minimise_with_lns`].
+    pub fn maximise_with_lns(
         &mut self,
-        clause: impl IntoIterator<Item = Literal>,
-    ) -> Result<(), ConstraintOperationError> {
-        self.satisfaction_solver.add_clause(clause)
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        variables: &[DomainId],
+        options: LnsOptions,
+    ) -> OptimisationResult {
+        self.lns_internal(
+            brancher,
+            termination,
+            objective_variable.scaled(-1),
+            variables,
+            options,
+            true,
+        )
     }
 
-    /// Adds a propagator with a tag, which is used to identify inferences made by this propagator
-    /// in the proof log.
-    pub(crate) fn add_tagged_propagator(
+    /// The internal method which performs the large neighbourhood search; the argument
+    /// `is_maximising` serves the same purpose as in [`Solver::minimise_internal`].
+    fn lns_internal(
         &mut self,
-        propagator: impl Propagator + 'static,
-        tag: NonZero<u32>,
-    ) -> Result<(), ConstraintOperationError> {
-        self.satisfaction_solver
-            .add_propagator(propagator, Some(tag))
-    }
-
-    /// Post a new propagator to the solver. If unsatisfiability can be immediately determined
-    /// through propagation, this will return a [`ConstraintOperationError`].
-    ///
-    /// The caller should ensure the solver is in the root state before calling this, either
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+        objective_variable: impl IntegerVariable,
+        variables: &[DomainId],
+        options: LnsOptions,
+        is_maximising: bool,
+    ) -> OptimisationResult {
+        let objective_multiplier = if is_maximising { -1 } else { 1 };
+
+        let initial_solve = self.satisfaction_solver.solve(termination, brancher);
+        match initial_solve {
+            CSPSolverExecutionFlag::Feasible => {}
+            CSPSolverExecutionFlag::Infeasible => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                let _ = self.satisfaction_solver.conclude_proof_unsat();
+                return OptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                self.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unknown;
+            }
+        }
+        let mut best_objective_value = Default::default();
+        let mut best_solution = Solution::default();
+
+        self.update_best_solution_and_process(
+            objective_multiplier,
+            &objective_variable,
+            &mut best_objective_value,
+            &mut best_solution,
+            brancher,
+        );
+
+        let mut num_iterations = 0;
+        while !termination.should_stop()
+            && options
+                .max_iterations
+                .is_none_or(|max_iterations| num_iterations < max_iterations)
+        {
+            num_iterations += 1;
+            self.satisfaction_solver.restore_state_at_root(brancher);
+
+            let objective_bound_predicate = if is_maximising {
+                predicate![objective_variable <= best_objective_value as i32]
+            } else {
+                predicate![objective_variable >= best_objective_value as i32]
+            };
+
+            let objective_bound_literal = self
+                .satisfaction_solver
+                .get_literal(objective_bound_predicate);
+            let improvement_literal = self.improvement_literal(
+                &objective_variable,
+                best_objective_value * objective_multiplier as i64,
+            );
+
+            // The neighbourhood is defined by assuming that every variable which is not relaxed
+            // takes its value in the best solution; the bound on the objective is assumed as well,
+            // such that a core shows whether the neighbourhood is responsible for the infeasibility.
+            let fixed_variables = variables
+                .iter()
+                .filter(|_| {
+                    !self
+                        .satisfaction_solver
+                        .get_random_generator()
+                        .generate_bool(options.relaxation_probability)
+                })
+                .copied()
+                .collect::<Vec<_>>();
+            let assumptions = std::iter::once(improvement_literal)
+                .chain(fixed_variables.into_iter().map(|variable| {
+                    let value = best_solution.get_integer_value(variable);
+                    self.satisfaction_solver
+                        .get_literal(predicate![variable == value])
+                }))
+                .collect::<Vec<_>>();
+
+            self.satisfaction_solver
+                .set_conflict_budget(Some(options.conflict_budget));
+            let solve_result = self.satisfaction_solver.solve_under_assumptions(
+                &assumptions,
+                termination,
+                brancher,
+            );
+            self.satisfaction_solver.set_conflict_budget(None);
+
+            match solve_result {
+                CSPSolverExecutionFlag::Feasible => {
+                    self.debug_bound_change(
+                        &objective_variable,
+                        best_objective_value * objective_multiplier as i64,
+                    );
+                    self.update_best_solution_and_process(
+                        objective_multiplier,
+                        &objective_variable,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        brancher,
+                    );
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    let blocking_core = self.extract_blocking_core(brancher);
+                    if blocking_core
+                        .iter()
+                        .all(|&literal| literal == improvement_literal)
+                    {
+                        // No better solution exists, regardless of the neighbourhood
+                        self.satisfaction_solver.restore_state_at_root(brancher);
+                        let _ = self
+                            .satisfaction_solver
+                            .add_clause([objective_bound_literal]);
+                        let _ = self
+                            .satisfaction_solver
+                            .conclude_proof_optimal(objective_bound_literal);
+                        return OptimisationResult::Optimal {
+                            solution: best_solution,
+                            blocking_core,
+                        };
+                    }
+
+                    // There is no better solution in this neighbourhood, so we continue with the
+                    // next one
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Either the conflict budget of this neighbourhood is exhausted, or the
+                    // termination condition triggered, which is checked by the loop
+                }
+            }
+        }
+
+        // Reset the state whenever we return a result
+        self.satisfaction_solver.restore_state_at_root(brancher);
+        OptimisationResult::Satisfiable(best_solution)
+    }
+}
+
+/// Functions for optimising an objective which is stored in the solver.
+impl Solver {
+    /// Sets the objective which is optimised by [`Solver::optimise`] in the given `direction`.
+    ///
+    /// The best solution which is found by [`Solver::optimise`] is kept across calls, which allows
+    /// incremental optimisation: constraints can be added after optimising, after which the next
+    /// call continues from the previous best solution rather than starting the search for a
+    /// bound from scratch. Setting a new objective discards this state.
+    pub fn set_objective(
+        &mut self,
+        objective_variable: impl TransformableVariable<AffineView<DomainId>>,
+        direction: OptimisationDirection,
+    ) {
+        let variable = match direction {
+            OptimisationDirection::Minimise => objective_variable.scaled(1),
+            OptimisationDirection::Maximise => objective_variable.scaled(-1),
+        };
+
+        self.objective = Some(Objective {
+            variable,
+            direction,
+            incumbent: None,
+        });
+    }
+
+    /// Optimises the objective which was set using [`Solver::set_objective`] (or until indicated
+    /// to terminate by the provided [`TerminationCondition`]).
+    ///
+    /// If a previous call found a solution which still satisfies all constraints, then the search
+    /// only looks for solutions which improve upon it. When optimality is proven, the bound on the
+    /// objective is added to the solver; since constraints can only be added, this bound remains
+    /// valid for later calls.
+    ///
+    /// Panics if no objective has been set.
+    pub fn optimise(
+        &mut self,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+    ) -> OptimisationResult {
+        let mut objective = self
+            .objective
+            .take()
+            .expect("an objective should be set using `Solver::set_objective`");
+
+        let result = self.optimise_objective(&mut objective, brancher, termination);
+
+        self.objective = Some(objective);
+        result
+    }
+
+    fn optimise_objective(
+        &mut self,
+        objective: &mut Objective,
+        brancher: &mut impl Brancher,
+        termination: &mut impl TerminationCondition,
+    ) -> OptimisationResult {
+        let objective_multiplier = match objective.direction {
+            OptimisationDirection::Minimise => 1,
+            OptimisationDirection::Maximise => -1,
+        };
+
+        // Constraints which were added since the previous call could have excluded the incumbent;
+        // if variables were created since, then the incumbent does not assign them and cannot be
+        // verified, in which case it is discarded as well.
+        if objective.incumbent.as_ref().is_some_and(|(_, solution)| {
+            !self.satisfaction_solver.assigns_all_variables(solution)
+                || self.verify(solution).is_err()
+        }) {
+            objective.incumbent = None;
+        }
+
+        loop {
+            // The bound is imposed through an assumption such that it does not prevent later calls
+            // from finding solutions when the incumbent is excluded by new constraints.
+            let assumptions = objective
+                .incumbent
+                .as_ref()
+                .map(|&(best_objective_value, _)| {
+                    self.satisfaction_solver.get_literal(
+                        objective
+                            .variable
+                            .upper_bound_predicate((best_objective_value - 1) as i32),
+                    )
+                })
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            let solve_result = self.satisfaction_solver.solve_under_assumptions(
+                &assumptions,
+                termination,
+                brancher,
+            );
+
+            match solve_result {
+                CSPSolverExecutionFlag::Feasible => {
+                    let best_objective_value = self
+                        .satisfaction_solver
+                        .get_assigned_integer_value(&objective.variable)
+                        .expect("expected variable to be assigned")
+                        as i64;
+                    let best_solution: Solution =
+                        self.satisfaction_solver.get_solution_reference().into();
+
+                    self.internal_process_solution(
+                        &best_solution,
+                        brancher,
+                        Some(best_objective_value * objective_multiplier),
+                    );
+                    objective.incumbent = Some((best_objective_value, best_solution));
+
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    let blocking_core = self.extract_blocking_core(brancher);
+
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+
+                    let Some((best_objective_value, best_solution)) = &objective.incumbent else {
+                        let _ = self.satisfaction_solver.conclude_proof_unsat();
+                        return OptimisationResult::Unsatisfiable;
+                    };
+
+                    let objective_bound_literal = self.satisfaction_solver.get_literal(
+                        objective
+                            .variable
+                            .lower_bound_predicate(*best_objective_value as i32),
+                    );
+                    let _ = self
+                        .satisfaction_solver
+                        .add_clause([objective_bound_literal]);
+                    let _ = self
+                        .satisfaction_solver
+                        .conclude_proof_optimal(objective_bound_literal);
+
+                    return OptimisationResult::Optimal {
+                        solution: best_solution.clone(),
+                        blocking_core,
+                    };
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    self.satisfaction_solver.restore_state_at_root(brancher);
+
+                    return match &objective.incumbent {
+                        Some((_, best_solution)) => {
+                            OptimisationResult::Satisfiable(best_solution.clone())
+                        }
+                        None => OptimisationResult::Unknown,
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// Functions for adding new constraints to the solver.
+impl Solver {
+    /// Add a constraint to the solver. This returns a [`ConstraintPoster`] which enables control
+    /// on whether to add the constraint as-is, or whether to (half) reify it.
+    ///
+    /// If none of the methods on [`ConstraintPoster`] are used, the constraint _is not_ actually
+    /// added to the solver. In this case, a warning is emitted.
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let a = solver.new_bounded_integer(0, 3);
+    /// let b = solver.new_bounded_integer(0, 3);
+    ///
+    /// solver.add_constraint(constraints::equals([a, b], 0)).post();
+    /// ```
+    pub fn add_constraint<Constraint>(
+        &mut self,
+        constraint: Constraint,
+    ) -> ConstraintPoster<'_, Constraint> {
+        ConstraintPoster::new(self, constraint)
+    }
+
+    /// Creates a clause from `literals` and adds it to the current formula.
+    ///
+    /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
+    /// returned. Subsequent calls to this method will always return an error, and no
+    /// modification of the solver will take place.
+    pub fn add_clause(
+        &mut self,
+        clause: impl IntoIterator<Item = Literal>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.satisfaction_solver.add_clause(clause)
+    }
+
+    /// Reads a linear model in a simple line-based text format from `reader`, creating the
+    /// declared variables and posting the constraints; returns the created variables by name.
+    ///
+    /// Every line is one of the following (tokens are separated by whitespace):
+    /// - A variable declaration `name in lb..ub`, which creates a variable with the domain
+    ///   `[lb, ub]`. Variables have to be declared before they are used.
+    /// - A linear constraint `a_1 x_1 + ... + a_n x_n <comparison> rhs`, where `<comparison>` is
+    ///   one of `<=`, `>=` or `==`. A coefficient can be omitted, in which case it is 1, and terms
+    ///   can be separated by `-` instead of `+`.
+    /// - An empty line, or a comment starting with `%`.
+    ///
+    /// The whole text is parsed before the solver is modified, so a syntax error or a use of an
+    /// undeclared variable leaves the solver unchanged. If creating a variable or posting a
+    /// constraint fails, the variables and constraints of the preceding lines remain in the
+    /// solver.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let model = "x0 in 0..5\nx1 in 0..5\n3 x0 + 4 x1 <= 8\n";
+    /// let variables = solver.load_linear_text(model.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(solver.upper_bound(&variables["x0"]), 2);
+    /// assert_eq!(solver.upper_bound(&variables["x1"]), 2);
+    /// ```
+    pub fn load_linear_text(
+        &mut self,
+        reader: impl BufRead,
+    ) -> Result<HashMap<String, DomainId>, LinearTextError> {
+        linear_text::load_linear_text(self, reader)
+    }
+
+    /// Adds a soft constraint which may be violated at the cost of `penalty`; returns the variable
+    /// with the domain `[0, 1]` which indicates whether the constraint is violated.
+    ///
+    /// The constraint is half-reified: it is enforced when the indicator is 0, and unconstrained
+    /// otherwise. When minimising the total penalty (see [`Solver::soft_constraint_penalty`]), an
+    /// indicator is thus only 1 if the constraint is violated.
+    ///
+    /// Returns [`ConstraintOperationError::NonPositivePenalty`] if `penalty` is not positive.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::OptimisationResult;
+    /// # use pumpkin_solver::results::ProblemSolution;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// // Preferably x <= 2 (penalty 3), but also x >= 5 (penalty 1)
+    /// let _ = solver
+    ///     .add_soft_constraint(constraints::less_than_or_equals([x], 2), 3)
+    ///     .unwrap();
+    /// let _ = solver
+    ///     .add_soft_constraint(constraints::less_than_or_equals([x.scaled(-1)], -5), 1)
+    ///     .unwrap();
+    ///
+    /// let penalty = solver.soft_constraint_penalty().unwrap();
+    /// let mut brancher = solver.default_brancher_over_all_propositional_variables();
+    /// match solver.minimise(&mut brancher, &mut Indefinite, penalty) {
+    ///     OptimisationResult::Optimal { solution, .. } => {
+    ///         assert_eq!(solution.get_integer_value(penalty), 1);
+    ///         assert!(solution.get_integer_value(x) <= 2);
+    ///     }
+    ///     result => panic!("expected an optimal solution, got {result:?}"),
+    /// }
+    /// ```
+    pub fn add_soft_constraint(
+        &mut self,
+        constraint: impl Constraint,
+        penalty: i32,
+    ) -> Result<DomainId, ConstraintOperationError> {
+        if penalty <= 0 {
+            return Err(ConstraintOperationError::NonPositivePenalty { penalty });
+        }
+
+        let violated = self.new_bounded_integer(0, 1);
+        let holds = self.get_literal(predicate![violated <= 0]);
+        self.add_constraint(constraint).implied_by(holds)?;
+
+        self.soft_constraints.push((violated, penalty));
+        Ok(violated)
+    }
+
+    /// Creates a variable which is equal to the total penalty of the violated soft constraints
+    /// which have been added through [`Solver::add_soft_constraint`]; minimising it finds an
+    /// assignment which violates the soft constraints as little as possible.
+    ///
+    /// Returns [`ConstraintOperationError::PenaltyOverflow`] if the sum of the penalties does not
+    /// fit in an `i32`.
+    pub fn soft_constraint_penalty(&mut self) -> Result<DomainId, ConstraintOperationError> {
+        let total_penalty = self
+            .soft_constraints
+            .iter()
+            .try_fold(0_i32, |total, &(_, penalty)| total.checked_add(penalty))
+            .ok_or(ConstraintOperationError::PenaltyOverflow)?;
+
+        let objective = self.new_bounded_integer(0, total_penalty);
+        let terms = self
+            .soft_constraints
+            .iter()
+            .map(|&(violated, penalty)| violated.scaled(penalty))
+            .chain(std::iter::once(objective.scaled(-1)))
+            .collect::<Vec<_>>();
+        self.add_constraint(constraints::equals(terms, 0)).post()?;
+
+        Ok(objective)
+    }
+
+    /// Adds a propagator with a tag, which is used to identify inferences made by this propagator
+    /// in the proof log.
+    pub(crate) fn add_tagged_propagator(
+        &mut self,
+        propagator: impl Propagator + 'static,
+        tag: NonZero<u32>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.satisfaction_solver
+            .add_propagator(propagator, Some(tag))
+    }
+
+    /// Post a new propagator to the solver. If unsatisfiability can be immediately determined
+    /// through propagation, this will return a [`ConstraintOperationError`].
+    ///
+    /// The caller should ensure the solver is in the root state before calling this, either
     /// because no call to [`Self::solve()`] has been made, or because
     /// [`Self::restore_state_at_root()`] was called.
     ///
@@ -736,6 +1733,65 @@ impl Solver {
         self.satisfaction_solver
             .default_brancher_over_all_propositional_variables()
     }
+
+    /// Provides a (partial) assignment of integer variables which the search should try first;
+    /// this can be used to seed the search with a known or expected solution.
+    ///
+    /// No constraints are posted; the assignment only biases the value selection of the brancher
+    /// returned by [`Solver::default_brancher_over_all_propositional_variables`]. Note that this
+    /// method should therefore be called before creating that brancher. Any previously provided
+    /// assignment is replaced.
+    pub fn warm_start(&mut self, assignment: &[(DomainId, i32)]) {
+        self.satisfaction_solver.warm_start(assignment)
+    }
+
+    /// Returns the activity of every integer variable according to the variable selector of the
+    /// provided brancher, which was created through
+    /// [`Solver::default_brancher_over_all_propositional_variables`]. The activities indicate how
+    /// often the variables appeared in recent conflicts, and can be provided to
+    /// [`Solver::import_activities`] to warm-start the search on a similar model.
+    pub fn export_activities(&self, brancher: &DefaultBrancher) -> Vec<(DomainId, f64)> {
+        self.satisfaction_solver.export_activities(brancher)
+    }
+
+    /// Provides the activities of integer variables (e.g. as returned by
+    /// [`Solver::export_activities`]) which the brancher returned by
+    /// [`Solver::default_brancher_over_all_propositional_variables`] starts with; variables with a
+    /// higher activity are branched on first. Note that this method should therefore be called
+    /// before creating that brancher. Any previously provided activities are replaced.
+    pub fn import_activities(&mut self, activities: &[(DomainId, f64)]) {
+        self.satisfaction_solver.import_activities(activities)
+    }
+
+    /// Makes the provided predicate the first decision of the next call to one of the solving
+    /// methods, after which the brancher takes over; this can be used to guide the search.
+    ///
+    /// In contrast to the assumptions provided to [`Solver::satisfy_under_assumptions`], the
+    /// predicate is a normal decision which is undone when backtracking. The search thus only
+    /// returns [`SatisfactionResult::Unsatisfiable`] if the problem has no solutions at all, and
+    /// it can return a solution in which the predicate does not hold.
+    pub fn branch_on(&mut self, predicate: Predicate) {
+        self.satisfaction_solver.branch_on(predicate)
+    }
+
+    /// Makes the next call to one of the solving methods restart at the first opportunity,
+    /// regardless of the conditions of the restart strategy; this can be used to restart based on
+    /// external signals, e.g. when coordinating with other solvers.
+    ///
+    /// Has no effect if restarts are disabled through [`RestartOptions`].
+    pub fn force_restart(&mut self) {
+        self.satisfaction_solver.force_restart()
+    }
+
+    /// Returns a handle which can be used to cancel an ongoing solve, e.g. from another thread.
+    ///
+    /// After [`InterruptHandle::interrupt`] has been called, the solving methods (such as
+    /// [`Solver::satisfy`] and [`Solver::minimise`]) return as if their [`TerminationCondition`]
+    /// was triggered. The handle is reset whenever a new search starts, so an interrupt does not
+    /// affect later calls to the solving methods.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.satisfaction_solver.interrupt_handle()
+    }
 }
 
 /// Proof logging methods
@@ -775,3 +1831,877 @@ pub type DefaultBrancher = IndependentVariableValueBrancher<
         PhaseSaving<PropositionalVariable, bool>,
     >,
 >;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::basic_types::PropagationStatusCP;
+    use crate::basic_types::PropositionalConjunction;
+    use crate::branching::InDomainMax;
+    use crate::branching::InDomainMin;
+    use crate::branching::InputOrder;
+    use crate::branching::RecordingBrancher;
+    use crate::branching::SelectionContext;
+    use crate::conjunction;
+    use crate::constraints;
+    use crate::engine::domain_events::DomainEvents;
+    use crate::engine::propagation::LocalId;
+    use crate::engine::propagation::PropagationContextMut;
+    use crate::engine::propagation::PropagatorInitialisationContext;
+    use crate::engine::propagation::ReadDomains;
+    use crate::termination::Indefinite;
+
+    /// Creates the problem of minimising `\sum costs_i * x_i` such that `\sum x_i >= 17` where
+    /// `x_i \in [0, 5]`; the optimal objective value is 55.
+    fn create_covering_problem(solver: &mut Solver) -> (Vec<DomainId>, DomainId) {
+        let costs = [3, 5, 2, 7, 4, 6];
+        let variables = costs
+            .iter()
+            .map(|_| solver.new_bounded_integer(0, 5))
+            .collect::<Vec<_>>();
+        let objective = solver.new_bounded_integer(0, 135);
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                variables
+                    .iter()
+                    .map(|variable| variable.scaled(-1))
+                    .collect::<Vec<_>>(),
+                -17,
+            ))
+            .post();
+        let _ = solver
+            .add_constraint(constraints::equals(
+                variables
+                    .iter()
+                    .zip(costs)
+                    .map(|(variable, cost)| variable.scaled(cost))
+                    .chain(std::iter::once(objective.scaled(-1)))
+                    .collect::<Vec<_>>(),
+                0,
+            ))
+            .post();
+
+        (variables, objective)
+    }
+
+    #[test]
+    fn blocking_core_of_minimisation_contains_the_objective_bound() {
+        let mut solver = Solver::default();
+        let (_, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let result = solver.minimise(&mut brancher, &mut Indefinite, objective);
+        let OptimisationResult::Optimal {
+            solution,
+            blocking_core,
+        } = result
+        else {
+            panic!("expected an optimal solution, got {result:?}");
+        };
+
+        assert_eq!(55, solution.get_integer_value(objective));
+        assert_eq!(
+            &[solver.get_literal(predicate![objective <= 54])],
+            blocking_core.as_ref()
+        );
+    }
+
+    #[test]
+    fn blocking_core_of_maximisation_contains_the_objective_bound() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 7))
+            .post();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let result = solver.maximise(&mut brancher, &mut Indefinite, x);
+        let OptimisationResult::Optimal { blocking_core, .. } = result else {
+            panic!("expected an optimal solution, got {result:?}");
+        };
+
+        assert_eq!(
+            &[solver.get_literal(predicate![x >= 8])],
+            blocking_core.as_ref()
+        );
+    }
+
+    #[test]
+    fn blocking_core_of_stored_objective_contains_the_objective_bound() {
+        let mut solver = Solver::default();
+        let (_, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.set_objective(objective, OptimisationDirection::Minimise);
+
+        let result = solver.optimise(&mut brancher, &mut Indefinite);
+        let OptimisationResult::Optimal { blocking_core, .. } = result else {
+            panic!("expected an optimal solution, got {result:?}");
+        };
+
+        assert_eq!(
+            &[solver.get_literal(predicate![objective <= 54])],
+            blocking_core.as_ref()
+        );
+    }
+
+    #[test]
+    fn core_of_unsatisfiable_objective_bound_contains_the_bound() {
+        let mut solver = Solver::default();
+        let (_, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let result =
+            solver.solve_and_get_core_on_unsat(&mut brancher, &mut Indefinite, objective, 54, &[]);
+        match result {
+            ObjectiveBoundResult::Unsatisfiable { blocking_core } => assert_eq!(
+                vec![solver.get_literal(predicate![objective <= 54])],
+                blocking_core.into_vec()
+            ),
+            result => panic!("expected the bound to be unsatisfiable, got {result:?}"),
+        }
+
+        let result =
+            solver.solve_and_get_core_on_unsat(&mut brancher, &mut Indefinite, objective, 55, &[]);
+        match result {
+            ObjectiveBoundResult::Satisfiable(solution) => {
+                assert_eq!(55, solution.get_integer_value(objective))
+            }
+            result => panic!("expected the bound to be satisfiable, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn core_of_unsatisfiable_model_does_not_contain_an_unneeded_bound() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+        let y = solver.new_bounded_integer(0, 1);
+        let z = solver.new_bounded_integer(0, 1);
+        for (lhs, rhs) in [(x, y), (y, z), (x, z)] {
+            let _ = solver
+                .add_constraint(constraints::binary_not_equals(lhs, rhs))
+                .post();
+        }
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        // Three 0/1 variables cannot be pairwise different, regardless of the bound on x
+        let result = solver.solve_and_get_core_on_unsat(&mut brancher, &mut Indefinite, x, 1, &[]);
+        match result {
+            ObjectiveBoundResult::Unsatisfiable { blocking_core } => {
+                assert!(blocking_core.is_empty())
+            }
+            result => panic!("expected the model to be unsatisfiable, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn blocking_core_of_lns_contains_the_objective_bound() {
+        let mut solver = Solver::default();
+        let (variables, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        // Relaxing every variable makes the search complete, so optimality is proven.
+        let options = LnsOptions {
+            relaxation_probability: 1.0,
+            conflict_budget: 10_000,
+            max_iterations: None,
+        };
+        let result = solver.minimise_with_lns(
+            &mut brancher,
+            &mut Indefinite,
+            objective,
+            &variables,
+            options,
+        );
+        let OptimisationResult::Optimal {
+            solution,
+            blocking_core,
+        } = result
+        else {
+            panic!("expected an optimal solution, got {result:?}");
+        };
+
+        assert_eq!(55, solution.get_integer_value(objective));
+        assert_eq!(
+            &[solver.get_literal(predicate![objective <= 54])],
+            blocking_core.as_ref()
+        );
+    }
+
+    #[test]
+    fn lns_reaches_the_optimum_of_a_small_problem() {
+        let mut solver = Solver::default();
+        let (variables, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let options = LnsOptions {
+            relaxation_probability: 0.5,
+            conflict_budget: 100,
+            max_iterations: Some(50),
+        };
+        let result = solver.minimise_with_lns(
+            &mut brancher,
+            &mut Indefinite,
+            objective,
+            &variables,
+            options,
+        );
+
+        let solution = match result {
+            OptimisationResult::Optimal { solution, .. }
+            | OptimisationResult::Satisfiable(solution) => solution,
+            result => panic!("expected a solution, got {result:?}"),
+        };
+        assert_eq!(55, solution.get_integer_value(objective));
+    }
+
+    #[test]
+    fn lns_stops_after_the_maximum_number_of_iterations() {
+        let mut solver = Solver::default();
+        let (variables, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        // Without relaxing any variable, the neighbourhoods never contain a better solution.
+        let options = LnsOptions {
+            relaxation_probability: 0.0,
+            conflict_budget: 100,
+            max_iterations: Some(5),
+        };
+        let result = solver.minimise_with_lns(
+            &mut brancher,
+            &mut Indefinite,
+            objective,
+            &variables,
+            options,
+        );
+
+        assert!(matches!(result, OptimisationResult::Satisfiable(_)));
+    }
+
+    #[test]
+    fn reoptimising_continues_from_the_previous_bound() {
+        let mut solver = Solver::default();
+        let (variables, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.set_objective(objective, OptimisationDirection::Minimise);
+
+        let result = solver.optimise(&mut brancher, &mut Indefinite);
+        assert!(matches!(
+            result,
+            OptimisationResult::Optimal { ref solution, .. } if solution.get_integer_value(objective) == 55
+        ));
+
+        let found_objective_values = Rc::new(RefCell::new(vec![]));
+        let values = Rc::clone(&found_objective_values);
+        solver.with_solution_callback(move |arguments| {
+            values
+                .borrow_mut()
+                .push(arguments.solution.get_integer_value(objective))
+        });
+
+        // The optimal solution uses five units of the cheapest variable; excluding it makes the
+        // incumbent infeasible.
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([variables[2]], 4))
+            .post();
+        assert_eq!(55, solver.lower_bound(&objective));
+
+        let result = solver.optimise(&mut brancher, &mut Indefinite);
+        assert!(matches!(
+            result,
+            OptimisationResult::Optimal { ref solution, .. } if solution.get_integer_value(objective) == 58
+        ));
+        assert!(found_objective_values
+            .borrow()
+            .iter()
+            .all(|&value| value >= 55));
+    }
+
+    #[test]
+    fn reoptimising_after_constraining_a_new_variable_discards_the_incumbent() {
+        let mut solver = Solver::default();
+        let (variables, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.set_objective(objective, OptimisationDirection::Minimise);
+
+        let _ = solver.optimise(&mut brancher, &mut Indefinite);
+
+        // The incumbent does not assign the new variable; since the new variable is at least 0,
+        // the constraint excludes the incumbent.
+        let slack = solver.new_bounded_integer(0, 5);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                vec![variables[2].scaled(1), slack.scaled(1)],
+                4,
+            ))
+            .post();
+
+        let result = solver.optimise(&mut brancher, &mut Indefinite);
+        assert!(matches!(
+            result,
+            OptimisationResult::Optimal { ref solution, .. } if solution.get_integer_value(objective) == 58
+        ));
+    }
+
+    #[test]
+    fn reoptimising_after_adding_a_unit_clause_verifies_the_incumbent() {
+        let mut solver = Solver::default();
+        let (variables, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.set_objective(objective, OptimisationDirection::Minimise);
+
+        let _ = solver.optimise(&mut brancher, &mut Indefinite);
+
+        let literal = solver.get_literal(predicate![variables[2] <= 4]);
+        let _ = solver.add_clause([literal]);
+
+        let result = solver.optimise(&mut brancher, &mut Indefinite);
+        assert!(matches!(
+            result,
+            OptimisationResult::Optimal { ref solution, .. } if solution.get_integer_value(objective) == 58
+        ));
+    }
+
+    #[test]
+    fn reoptimising_with_a_feasible_incumbent_does_not_search_for_new_solutions() {
+        let mut solver = Solver::default();
+        let (variables, objective) = create_covering_problem(&mut solver);
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.set_objective(objective, OptimisationDirection::Minimise);
+
+        let _ = solver.optimise(&mut brancher, &mut Indefinite);
+
+        let num_solutions = Rc::new(RefCell::new(0));
+        let counter = Rc::clone(&num_solutions);
+        solver.with_solution_callback(move |_| *counter.borrow_mut() += 1);
+
+        // The most expensive variable is not used by the optimal solution.
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([variables[3]], 0))
+            .post();
+
+        let result = solver.optimise(&mut brancher, &mut Indefinite);
+        assert!(matches!(
+            result,
+            OptimisationResult::Optimal { ref solution, .. } if solution.get_integer_value(objective) == 55
+        ));
+        assert_eq!(0, *num_solutions.borrow());
+    }
+
+    #[test]
+    fn optimise_maximises_the_objective() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(2), y.scaled(1)],
+                13,
+            ))
+            .post();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.set_objective(x, OptimisationDirection::Maximise);
+
+        let result = solver.optimise(&mut brancher, &mut Indefinite);
+        assert!(matches!(
+            result,
+            OptimisationResult::Optimal { ref solution, .. } if solution.get_integer_value(x) == 6
+        ));
+    }
+
+    #[test]
+    fn pigeonhole_is_exported_as_opb() {
+        let mut solver = Solver::default();
+        // Three pigeons and two holes, where `in_hole[p][h]` is 1 if pigeon `p` is in hole `h`.
+        let in_hole = (0..3)
+            .map(|_| {
+                (0..2)
+                    .map(|_| solver.new_bounded_integer(0, 1))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for pigeon in in_hole.iter() {
+            let _ = solver
+                .add_constraint(constraints::less_than_or_equals(
+                    pigeon
+                        .iter()
+                        .map(|variable| variable.scaled(-1))
+                        .collect::<Vec<_>>(),
+                    -1,
+                ))
+                .post();
+        }
+        for hole in 0..2 {
+            let _ = solver
+                .add_constraint(constraints::less_than_or_equals(
+                    in_hole
+                        .iter()
+                        .map(|pigeon| pigeon[hole])
+                        .collect::<Vec<_>>(),
+                    1,
+                ))
+                .post();
+        }
+        let variables = in_hole
+            .iter()
+            .map(|pigeon| {
+                pigeon
+                    .iter()
+                    .map(|&variable| {
+                        solver
+                            .get_literal(predicate![variable >= 1])
+                            .get_propositional_variable()
+                            .get_index()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::new();
+        let result = solver.export_opb(&mut output);
+        assert!(result.is_ok());
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        let header = lines.next().unwrap();
+        assert!(header.starts_with("* #variable= "));
+        let num_constraints = header
+            .split("#constraint= ")
+            .nth(1)
+            .and_then(|num_constraints| num_constraints.parse::<usize>().ok())
+            .unwrap();
+
+        let constraints = lines.collect::<Vec<_>>();
+        assert_eq!(num_constraints, constraints.len());
+        assert!(constraints
+            .iter()
+            .all(|constraint| constraint.contains(" >= ") && constraint.ends_with(" ;")));
+        assert!(constraints
+            .contains(&format!("+1 x{} +1 x{} >= 1 ;", variables[0][0], variables[0][1]).as_str()));
+        assert!(constraints.contains(
+            &format!(
+                "-1 x{} -1 x{} -1 x{} >= -1 ;",
+                variables[0][1], variables[1][1], variables[2][1]
+            )
+            .as_str()
+        ));
+    }
+
+    #[test]
+    fn linear_inequality_over_non_binary_variables_cannot_be_exported_as_opb() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 5);
+        let y = solver.new_bounded_integer(0, 1);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 3))
+            .post();
+
+        let mut output = Vec::new();
+        let result = solver.export_opb(&mut output);
+
+        assert_eq!(std::io::ErrorKind::InvalidInput, result.unwrap_err().kind());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn linear_equality_is_exported_as_opb_equality() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+        let y = solver.new_bounded_integer(0, 1);
+        let z = solver.new_bounded_integer(0, 1);
+        let _ = solver
+            .add_constraint(constraints::equals(
+                [x.scaled(1), y.scaled(2), z.scaled(1)],
+                2,
+            ))
+            .post();
+        let [x, y, z] = [x, y, z].map(|variable| {
+            solver
+                .get_literal(predicate![variable >= 1])
+                .get_propositional_variable()
+                .get_index()
+        });
+
+        let mut output = Vec::new();
+        let result = solver.export_opb(&mut output);
+        assert!(result.is_ok());
+
+        let output = String::from_utf8(output).unwrap();
+        let constraints = output.lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(vec![format!("-1 x{x} -2 x{y} -1 x{z} = -2 ;")], constraints);
+    }
+
+    #[test]
+    fn model_with_other_constraints_cannot_be_exported_as_opb() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+        let y = solver.new_bounded_integer(0, 1);
+        let _ = solver
+            .add_constraint(constraints::binary_not_equals(x, y))
+            .post();
+
+        let mut output = Vec::new();
+        let result = solver.export_opb(&mut output);
+
+        assert_eq!(std::io::ErrorKind::InvalidInput, result.unwrap_err().kind());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn root_level_contradiction_is_unsatisfiable() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x], 3))
+            .post();
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x.scaled(-1)], -5))
+            .post();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    }
+
+    #[test]
+    fn contradiction_learned_during_search_is_unsatisfiable() {
+        let mut solver = Solver::default();
+        // Three pigeons and two holes; the infeasibility is only derived at the root after
+        // conflict analysis.
+        let pigeons = (0..3)
+            .map(|_| solver.new_literals().take(2).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for pigeon in pigeons.iter() {
+            let _ = solver.add_clause(pigeon.iter().copied());
+        }
+        for hole in 0..2 {
+            for (first, pigeon) in pigeons.iter().enumerate() {
+                for other_pigeon in pigeons.iter().skip(first + 1) {
+                    let _ = solver.add_clause([!pigeon[hole], !other_pigeon[hole]]);
+                }
+            }
+        }
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    }
+
+    /// A propagator which reports a conflict explained by a single predicate when the lower bound
+    /// of its variable exceeds the bound, rather than propagating the bound.
+    struct ConflictingAboveBoundPropagator {
+        variable: DomainId,
+        bound: i32,
+    }
+
+    impl Propagator for ConflictingAboveBoundPropagator {
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            let _ = context.register(self.variable, DomainEvents::ANY_INT, LocalId::from(0));
+
+            Ok(())
+        }
+
+        fn priority(&self) -> u32 {
+            0
+        }
+
+        fn name(&self) -> &str {
+            "ConflictingAboveBound"
+        }
+
+        fn debug_propagate_from_scratch(
+            &self,
+            context: PropagationContextMut,
+        ) -> PropagationStatusCP {
+            if context.lower_bound(&self.variable) > self.bound {
+                return Err(conjunction!([self.variable >= self.bound + 1]).into());
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn conflict_explained_by_single_predicate_during_search_is_learned_at_the_root() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_constraint(ConflictingAboveBoundPropagator {
+                variable: x,
+                bound: 2,
+            })
+            .post();
+
+        let mut brancher =
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x]), InDomainMax);
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                assert_eq!(2, solution.get_integer_value(x));
+            }
+            _ => panic!("expected a solution"),
+        }
+        assert_eq!(2, solver.upper_bound(&x));
+    }
+
+    /// A propagator which is woken up by every change to its variables, but never propagates.
+    struct IdlePropagator {
+        variables: Box<[DomainId]>,
+    }
+
+    impl Propagator for IdlePropagator {
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            for (index, variable) in self.variables.iter().enumerate() {
+                let _ = context.register(
+                    *variable,
+                    DomainEvents::ANY_INT,
+                    LocalId::from(index as u32),
+                );
+            }
+
+            Ok(())
+        }
+
+        fn priority(&self) -> u32 {
+            0
+        }
+
+        fn name(&self) -> &str {
+            "Idle"
+        }
+
+        fn debug_propagate_from_scratch(&self, _: PropagationContextMut) -> PropagationStatusCP {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn propagator_woken_without_effect_has_high_thrashing_ratio() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_constraint(IdlePropagator {
+                variables: [x, y].into(),
+            })
+            .post();
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+
+        let (name, statistics) = solver.propagator_stats().next().unwrap();
+        assert_eq!("Idle", name);
+        assert!(statistics.num_calls() > 1);
+        assert_eq!(
+            statistics.num_calls(),
+            statistics.num_calls_without_effect()
+        );
+        assert_eq!(1.0, statistics.thrashing_ratio());
+    }
+
+    #[test]
+    fn propagator_which_propagates_has_lower_thrashing_ratio() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 5))
+            .post();
+
+        let (_, statistics) = solver.propagator_stats().next().unwrap();
+        assert_eq!(1, statistics.num_calls());
+        assert_eq!(0.0, statistics.thrashing_ratio());
+
+        solver.reset_statistics();
+        let (_, statistics) = solver.propagator_stats().next().unwrap();
+        assert_eq!(0, statistics.num_calls());
+    }
+
+    #[test]
+    fn current_value_is_only_returned_for_fixed_variables() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(4, 10);
+
+        // x + y <= 4 fixes y to 4 and x to 0, while z remains unfixed
+        let z = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 4))
+            .post();
+
+        assert_eq!(Some(0), solver.current_value(&x));
+        assert_eq!(Some(4), solver.current_value(&y));
+        assert_eq!(None, solver.current_value(&z));
+        assert_eq!(Some(-4), solver.current_value(&y.scaled(-1)));
+    }
+
+    #[test]
+    fn variable_created_after_search_started_becomes_selectable() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let mut brancher = RecordingBrancher::new(IndependentVariableValueBrancher::new(
+            InputOrder::new(&[x]).including_new_variables(),
+            InDomainMin,
+        ));
+
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+        assert!(brancher.new_variables.is_empty());
+
+        let y = solver.new_bounded_integer(3, 10);
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                assert_eq!(3, solution.get_integer_value(y));
+            }
+            result => panic!("expected a solution, got {result:?}"),
+        }
+        assert_eq!(vec![y], brancher.new_variables);
+    }
+
+    #[test]
+    fn minimising_the_penalty_violates_the_cheapest_soft_constraints() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        // The soft constraints x + y >= 8, x <= 2 and y <= 3 cannot all hold; violating `y <= 3`
+        // is cheaper than violating either of the others.
+        let sum = solver
+            .add_soft_constraint(
+                constraints::less_than_or_equals([x.scaled(-1), y.scaled(-1)], -8),
+                5,
+            )
+            .unwrap();
+        let x_small = solver
+            .add_soft_constraint(constraints::less_than_or_equals([x], 2), 4)
+            .unwrap();
+        let y_small = solver
+            .add_soft_constraint(constraints::less_than_or_equals([y], 3), 2)
+            .unwrap();
+
+        let penalty = solver.soft_constraint_penalty().unwrap();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        match solver.minimise(&mut brancher, &mut Indefinite, penalty) {
+            OptimisationResult::Optimal { solution, .. } => {
+                assert_eq!(2, solution.get_integer_value(penalty));
+                assert_eq!(0, solution.get_integer_value(sum));
+                assert_eq!(0, solution.get_integer_value(x_small));
+                assert_eq!(1, solution.get_integer_value(y_small));
+                assert!(solution.get_integer_value(x) + solution.get_integer_value(y) >= 8);
+                assert!(solution.get_integer_value(x) <= 2);
+            }
+            result => panic!("expected an optimal solution, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn soft_constraint_with_non_positive_penalty_is_rejected() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let result = solver.add_soft_constraint(constraints::less_than_or_equals([x], 2), 0);
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::NonPositivePenalty { penalty: 0 })
+        ));
+    }
+
+    #[test]
+    fn overflowing_total_penalty_is_reported() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_soft_constraint(constraints::less_than_or_equals([x], 2), i32::MAX)
+            .unwrap();
+        let _ = solver
+            .add_soft_constraint(constraints::less_than_or_equals([x.scaled(-1)], -5), 1)
+            .unwrap();
+
+        assert!(matches!(
+            solver.soft_constraint_penalty(),
+            Err(ConstraintOperationError::PenaltyOverflow)
+        ));
+    }
+
+    #[test]
+    fn dumped_state_contains_decision_level_and_propagator_count() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals([x, y], 5))
+            .post();
+        let _ = solver
+            .add_constraint(constraints::not_equals([x, y], 3))
+            .post();
+
+        let dump = solver.dump_state();
+        assert!(dump.contains("decision level: 0"), "{dump}");
+        assert!(dump.contains("propagators: 2"), "{dump}");
+    }
+
+    #[test]
+    fn forced_first_decision_is_respected_by_the_solution() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+        let _ = solver
+            .add_constraint(constraints::less_than_or_equals(
+                [x.scaled(1), y.scaled(1)],
+                10,
+            ))
+            .post();
+
+        solver.branch_on(predicate![x == 7]);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                assert_eq!(7, solution.get_integer_value(x));
+            }
+            result => panic!("expected a solution, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn forced_first_decision_is_undone_when_it_leads_to_a_conflict() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let a = solver.new_bounded_integer(0, 5);
+        let b = solver.new_bounded_integer(0, 5);
+        // The value of `x` has to be even, which is not detected at the root
+        let _ = solver
+            .add_constraint(constraints::equals(
+                [a.scaled(2), b.scaled(2), x.scaled(-1)],
+                0,
+            ))
+            .post();
+
+        solver.branch_on(predicate![x == 7]);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                assert_ne!(7, solution.get_integer_value(x));
+            }
+            result => panic!("expected a solution, got {result:?}"),
+        }
+    }
+}
@@ -16,4 +16,30 @@ pub enum ConstraintOperationError {
     /// Error which indicate that adding a propagator led to infeasibility at the root.
     #[error("Adding the constraint failed because it is infeasible at the root")]
     InfeasiblePropagator,
+    /// Error which indicates that the bounds of a term in a linear constraint cannot be
+    /// represented by an `i32` after applying its coefficient; `term` is the index of the
+    /// offending term.
+    #[error("Adding the constraint failed because the bounds of term {term} overflow an i32")]
+    CoefficientOverflow { term: usize },
+    /// Error which indicates that a constraint is defined over a variable which was not created by
+    /// this [`Solver`]; `term` is the index of the offending variable in the arguments of the
+    /// constraint (for a linear constraint, the index of the offending term).
+    #[error("Adding the constraint failed because variable {term} does not belong to this solver")]
+    UnknownVariable { term: usize },
+    /// Error which indicates that a constraint which is only defined over a non-empty collection
+    /// (e.g. the maximum of an array) was created over an empty collection.
+    #[error("Adding the constraint failed because it is defined over an empty collection")]
+    EmptyConstraint,
+    /// Error which indicates that a constraint over a matrix (e.g. the two-dimensional element
+    /// constraint) was created over a matrix whose rows do not all have the same length.
+    #[error("Adding the constraint failed because the rows of its matrix differ in length")]
+    NonRectangularMatrix,
+    /// Error which indicates that a soft constraint was added with a penalty which is not
+    /// positive; see [`Solver::add_soft_constraint`].
+    #[error("Adding the soft constraint failed because its penalty {penalty} is not positive")]
+    NonPositivePenalty { penalty: i32 },
+    /// Error which indicates that the sum of the penalties of the soft constraints cannot be
+    /// represented by an `i32`; see [`Solver::soft_constraint_penalty`].
+    #[error("Creating the total penalty failed because the sum of the penalties overflows an i32")]
+    PenaltyOverflow,
 }
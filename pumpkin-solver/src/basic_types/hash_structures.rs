@@ -1,4 +1,7 @@
 use fnv::FnvBuildHasher;
 
+// The hash structures use the FNV hasher rather than the randomly seeded default hasher of the
+// standard library. Besides being faster for small keys, this ensures that the iteration order of
+// these structures (and therefore the behaviour of the solver) is the same across runs.
 pub(crate) type HashMap<K, V, Hasher = FnvBuildHasher> = std::collections::HashMap<K, V, Hasher>;
 pub(crate) type HashSet<K, Hasher = FnvBuildHasher> = std::collections::HashSet<K, Hasher>;
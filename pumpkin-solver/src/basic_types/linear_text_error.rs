@@ -0,0 +1,38 @@
+use thiserror::Error;
+
+use super::ConstraintOperationError;
+use super::VariableCreationError;
+#[cfg(doc)]
+use crate::Solver;
+
+/// Errors related to loading a model in the linear text format using
+/// [`Solver::load_linear_text`]. Every error other than [`LinearTextError::Io`] contains the
+/// (1-based) number of the line on which it occurred.
+#[derive(Error, Debug)]
+pub enum LinearTextError {
+    /// Error which indicates that reading from the source failed.
+    #[error("failed to read the model")]
+    Io(#[from] std::io::Error),
+    /// Error which indicates that a line is neither a variable declaration nor a linear
+    /// constraint.
+    #[error("line {line}: {message}")]
+    Syntax { line: usize, message: Box<str> },
+    /// Error which indicates that a constraint uses a variable before it is declared.
+    #[error("line {line}: the variable '{name}' is not declared")]
+    UndeclaredVariable { line: usize, name: Box<str> },
+    /// Error which indicates that a variable is declared more than once.
+    #[error("line {line}: the variable '{name}' is already declared")]
+    DuplicateVariable { line: usize, name: Box<str> },
+    /// Error which indicates that the domain of a declared variable could not be created.
+    #[error("line {line}: {error}")]
+    VariableCreation {
+        line: usize,
+        error: VariableCreationError,
+    },
+    /// Error which indicates that posting a constraint failed.
+    #[error("line {line}: {error}")]
+    Constraint {
+        line: usize,
+        error: ConstraintOperationError,
+    },
+}
@@ -7,14 +7,18 @@ mod function;
 mod hash_structures;
 mod key_value_heap;
 mod keyed_vec;
+mod linear_text_error;
 pub(crate) mod moving_averages;
 mod propagation_status_cp;
 mod propagation_status_cp_one_step;
 mod propositional_conjunction;
 mod random;
+mod restart_option_error;
 pub(crate) mod sequence_generators;
 mod solution;
 mod trail;
+mod variable_creation_error;
+mod violated_constraint;
 mod weighted_literal;
 
 pub(crate) use clause_reference::ClauseReference;
@@ -26,13 +30,17 @@ pub use function::Function;
 pub(crate) use hash_structures::*;
 pub(crate) use key_value_heap::KeyValueHeap;
 pub use keyed_vec::*;
+pub use linear_text_error::LinearTextError;
 pub(crate) use propagation_status_cp::Inconsistency;
 pub(crate) use propagation_status_cp::PropagationStatusCP;
 pub(crate) use propagation_status_cp_one_step::PropagationStatusOneStepCP;
 pub use propositional_conjunction::PropositionalConjunction;
 pub use random::*;
+pub use restart_option_error::RestartOptionError;
 pub use solution::ProblemSolution;
 pub use solution::Solution;
 pub use solution::SolutionReference;
 pub(crate) use trail::Trail;
+pub use variable_creation_error::VariableCreationError;
+pub use violated_constraint::ViolatedConstraint;
 pub(crate) use weighted_literal::WeightedLiteral;
@@ -28,6 +28,10 @@ impl MovingAverage for CumulativeMovingAverage {
         }
     }
 
+    fn is_initialised(&self) -> bool {
+        self.num_terms > 0
+    }
+
     fn adapt(&mut self, _interval_length: u64) {
         // do nothing
     }
@@ -54,6 +58,20 @@ mod tests {
         assert!(empty_sum.value() == 0.0);
     }
 
+    #[test]
+    fn test_empty_is_not_initialised() {
+        let empty_sum = CumulativeMovingAverage::default();
+        assert!(!empty_sum.is_initialised());
+    }
+
+    #[test]
+    fn test_zero_average_is_initialised() {
+        let mut zero_average = CumulativeMovingAverage::default();
+        zero_average.add_term(0);
+        assert!(zero_average.is_initialised());
+        assert!(zero_average.value() == 0.0);
+    }
+
     #[test]
     fn test_simple1() {
         let mut constant_average = CumulativeMovingAverage::default();
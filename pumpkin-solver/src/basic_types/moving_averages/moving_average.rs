@@ -6,6 +6,10 @@ pub(crate) trait MovingAverage: Debug {
     /// Returns the moving average value; in case there are no terms, the convention is to return 0
     fn value(&self) -> f64;
 
+    /// Returns whether the moving average contains any terms; this distinguishes an average of 0
+    /// from an average over no terms, for which [`MovingAverage::value`] also returns 0.
+    fn is_initialised(&self) -> bool;
+
     /// Adapts the internal data structures to take into account the given interval length; this
     /// makes sense for moving averages that consider the k previous points, e.g., windowed moving
     /// average
@@ -47,6 +47,10 @@ impl MovingAverage for WindowedMovingAverage {
         }
     }
 
+    fn is_initialised(&self) -> bool {
+        !self.values_in_window.is_empty()
+    }
+
     fn adapt(&mut self, interval_length: u64) {
         pumpkin_assert_simple!(interval_length > 0);
 
@@ -88,6 +92,20 @@ mod tests {
         assert!(empty_sum.value() == 0.0);
     }
 
+    #[test]
+    fn test_empty_is_not_initialised() {
+        let empty_sum = WindowedMovingAverage::new(10);
+        assert!(!empty_sum.is_initialised());
+    }
+
+    #[test]
+    fn test_zero_average_is_initialised() {
+        let mut zero_average = WindowedMovingAverage::new(10);
+        zero_average.add_term(0);
+        assert!(zero_average.is_initialised());
+        assert!(zero_average.value() == 0.0);
+    }
+
     #[test]
     fn test_simple1() {
         let mut constant_average = WindowedMovingAverage::new(2);
@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+#[cfg(doc)]
+use crate::options::RestartOptions;
+#[cfg(doc)]
+use crate::options::SequenceGeneratorType;
+
+/// Errors related to inconsistent [`RestartOptions`]; see [`RestartOptions::validate`].
+#[derive(Error, Debug, Copy, Clone, PartialEq)]
+pub enum RestartOptionError {
+    /// Error which indicates that [`SequenceGeneratorType::Geometric`] is used without providing
+    /// [`RestartOptions::geometric_coef`].
+    #[error("The geometric restart sequence is used but no geometric coefficient is provided")]
+    MissingGeometricCoefficient,
+    /// Error which indicates that [`RestartOptions::geometric_coef`] is not a finite value of at
+    /// least 1, which would cause the restart intervals to shrink.
+    #[error("The geometric coefficient {0} should be a finite value of at least 1")]
+    InvalidGeometricCoefficient(f64),
+    /// Error which indicates that [`RestartOptions::base_interval`] is 0.
+    #[error("The base interval of the restart sequence should be positive")]
+    ZeroBaseInterval,
+    /// Error which indicates that [`RestartOptions::num_assigned_window`] is 0.
+    #[error("The window for the number of assigned variables should be positive")]
+    ZeroNumAssignedWindow,
+    /// Error which indicates that [`RestartOptions::lbd_coef`] is not a positive finite value.
+    #[error("The LBD coefficient {0} should be a positive finite value")]
+    InvalidLbdCoefficient(f64),
+    /// Error which indicates that [`RestartOptions::num_assigned_coef`] is not a positive finite
+    /// value.
+    #[error("The coefficient for the number of assigned variables {0} should be a positive finite value")]
+    InvalidNumAssignedCoefficient(f64),
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[cfg(doc)]
+use crate::options::SolverOptions;
+#[cfg(doc)]
+use crate::Solver;
+
+/// Errors related to creating variables in the [`Solver`].
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VariableCreationError {
+    /// Error which indicates that the domain of the variable contains more values than allowed by
+    /// [`SolverOptions::max_domain_size`].
+    #[error("The domain of size {size} exceeds the maximum domain size of {max_domain_size}")]
+    DomainTooLarge { size: u64, max_domain_size: u32 },
+}
@@ -0,0 +1,21 @@
+use std::num::NonZero;
+
+#[cfg(doc)]
+use crate::basic_types::Solution;
+use crate::engine::variables::Literal;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A constraint which is violated by a [`Solution`]; see [`Solver::verify`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ViolatedConstraint {
+    /// A clause of which none of the [`Literal`]s is true in the solution.
+    Clause(Vec<Literal>),
+    /// A constraint enforced by a propagator which reports a conflict for the solution. It
+    /// provides the name of the propagator and the tag with which the constraint was added (if
+    /// any).
+    Propagator {
+        name: String,
+        tag: Option<NonZero<u32>>,
+    },
+}
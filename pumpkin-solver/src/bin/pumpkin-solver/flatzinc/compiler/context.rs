@@ -4,6 +4,7 @@ use std::collections::HashSet;
 use std::rc::Rc;
 
 use log::warn;
+use pumpkin_solver::predicate;
 use pumpkin_solver::variables::DomainId;
 use pumpkin_solver::variables::Literal;
 use pumpkin_solver::Solver;
@@ -50,6 +51,9 @@ pub(crate) struct CompilationContext<'a> {
     pub(crate) constant_domain_ids: HashMap<i32, DomainId>,
     /// A mapping from integer variable array identifiers to slices of domain ids.
     pub(crate) integer_variable_arrays: HashMap<Rc<str>, Rc<[DomainId]>>,
+    /// Only instantiate a single 0/1 integer variable for every literal which is used as an
+    /// integer variable.
+    pub(crate) literal_domain_ids: HashMap<Literal, DomainId>,
 
     /// All set parameters.
     pub(crate) set_constants: HashMap<Rc<str>, Set>,
@@ -89,6 +93,7 @@ impl CompilationContext<'_> {
             integer_equivalences: Default::default(),
             constant_domain_ids: Default::default(),
             integer_variable_arrays: Default::default(),
+            literal_domain_ids: Default::default(),
 
             set_constants: Default::default(),
         }
@@ -314,6 +319,15 @@ impl CompilationContext<'_> {
         &mut self,
         identifier: &str,
     ) -> Result<DomainId, FlatZincError> {
+        // Boolean variables can occur in integer constraints, in which case they are treated as
+        // 0/1 integer variables
+        if !self.integer_equivalences.is_defined(identifier)
+            && self.literal_equivalences.is_defined(identifier)
+        {
+            let literal = self.resolve_bool_variable_from_identifier(identifier)?;
+            return Ok(self.resolve_literal_as_integer(literal));
+        }
+
         if let Some(domain_id) = self
             .integer_variable_map
             .get(&self.integer_equivalences.representative(identifier))
@@ -335,6 +349,21 @@ impl CompilationContext<'_> {
         }
     }
 
+    /// Returns a 0/1 integer variable which is 1 if and only if `literal` is true.
+    fn resolve_literal_as_integer(&mut self, literal: Literal) -> DomainId {
+        *self.literal_domain_ids.entry(literal).or_insert_with(|| {
+            let domain_id = self.solver.new_bounded_integer(0, 1);
+            let equals_one = self.solver.get_literal(predicate![domain_id == 1]);
+
+            // If these clauses make the model infeasible, then posting the constraint in which the
+            // variable occurs will report it
+            let _ = self.solver.add_clause([!literal, equals_one]);
+            let _ = self.solver.add_clause([!equals_one, literal]);
+
+            domain_id
+        })
+    }
+
     pub(crate) fn resolve_integer_variable_array(
         &mut self,
         expr: &flatzinc::Expr,
@@ -164,7 +164,9 @@ pub(crate) fn run(
                 constraints::absolute,
             )?,
 
-            "pumpkin_all_different" => compile_all_different(context, exprs, annos)?,
+            "pumpkin_all_different" | "fzn_all_different_int" | "all_different_int" => {
+                compile_all_different(context, exprs, annos)?
+            }
 
             "array_bool_and" => compile_array_bool_and(context, exprs)?,
             "array_bool_element" => {
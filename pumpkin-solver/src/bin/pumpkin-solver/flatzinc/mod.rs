@@ -106,7 +106,10 @@ pub(crate) fn solve(
         };
 
         match result {
-            OptimisationResult::Optimal(optimal_solution) => {
+            OptimisationResult::Optimal {
+                solution: optimal_solution,
+                ..
+            } => {
                 let optimal_objective_value =
                     optimal_solution.get_integer_value(*objective_function.get_domain());
                 let objective_bound_literal = solver.get_literal(get_bound_predicate(
@@ -224,6 +224,13 @@ struct Args {
     #[arg(long = "restart-geometric-coef", verbatim_doc_comment)]
     restart_geometric_coef: Option<f64>,
 
+    /// After this many consecutive blocked restarts, the solver perturbs the saved phases of the
+    /// brancher instead of continuing to block restarts, which diversifies the search.
+    ///
+    /// Possible values: u64 (Optional)
+    #[arg(long = "restart-perturb-after-blocked", verbatim_doc_comment)]
+    restart_perturb_after_blocked: Option<u64>,
+
     /// The time budget for the solver, given in milliseconds.
     ///
     /// Possible values: u64 (Optional)
@@ -510,11 +517,18 @@ fn run() -> PumpkinResult<()> {
             num_assigned_window: args.restart_num_assigned_window,
             geometric_coef: args.restart_geometric_coef,
             no_restarts: args.no_restarts,
+            perturb_after_blocked: args.restart_perturb_after_blocked,
         },
         proof_log,
         learning_clause_minimisation: !args.no_learning_clause_minimisation,
         random_generator: SmallRng::seed_from_u64(args.random_seed),
+        max_domain_size: None,
+        log_decisions: false,
+        search_tree_log: SearchTreeLog::default(),
+        cache_domain_descriptions: false,
+        chronological_backtracking_threshold: None,
     };
+    solver_options.restart_options.validate()?;
 
     let time_limit = args.time_limit.map(Duration::from_millis);
     let instance_path = args
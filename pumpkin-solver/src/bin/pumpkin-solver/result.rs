@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use pumpkin_solver::RestartOptionError;
 use thiserror::Error;
 
 use crate::flatzinc::error::FlatZincError;
@@ -26,6 +27,8 @@ pub(crate) enum PumpkinError {
     InvalidDimacs(#[from] DimacsParseError),
     #[error("Failed to run flatzinc model, more details: {0}")]
     FlatZinc(#[from] FlatZincError),
+    #[error("The restart options are invalid, more details: {0}")]
+    InvalidRestartOptions(#[from] RestartOptionError),
     #[error("Proof generation for {0} is not supported.")]
     ProofGenerationNotSupported(String),
 }
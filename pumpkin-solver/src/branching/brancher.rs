@@ -14,6 +14,8 @@ use crate::engine::predicates::predicate::Predicate;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 #[cfg(doc)]
+use crate::engine::RestartOptions;
+#[cfg(doc)]
 use crate::results::solution_iterator::SolutionIterator;
 
 /// A trait for definining a branching strategy (oftentimes utilising a [`VariableSelector`] and a
@@ -54,6 +56,11 @@ pub trait Brancher {
     /// backtracking operation by the solver.
     fn on_unassign_integer(&mut self, _variable: DomainId, _value: i32) {}
 
+    /// A function which is called once the solver has backtracked to `backtrack_level`; it is
+    /// called after all of the calls to [`Brancher::on_unassign_literal`] and
+    /// [`Brancher::on_unassign_integer`] for the backtracking operation have been made.
+    fn on_backtrack(&mut self, _backtrack_level: usize) {}
+
     /// A function which is called when a [`Literal`] appears in a conflict during conflict
     /// analysis.
     fn on_appearance_in_conflict_literal(&mut self, _literal: Literal) {}
@@ -62,6 +69,14 @@ pub trait Brancher {
     /// analysis.
     fn on_appearance_in_conflict_integer(&mut self, _variable: DomainId) {}
 
+    /// This method is called when an integer variable has been created after the search with this
+    /// [`Brancher`] has started; it is called at the start of the next search, and allows the
+    /// [`Brancher`] to include the variable in its heuristics.
+    ///
+    /// Note that this method can be called for a variable which is already known to the
+    /// [`Brancher`].
+    fn on_new_variable(&mut self, _variable: DomainId) {}
+
     /// This method is called when a solution is found; this will either be called when a new
     /// incumbent solution is found (i.e. a solution with a better objective value than previously
     /// known) or when a new solution is found when iterating over solutions using
@@ -71,6 +86,11 @@ pub trait Brancher {
     /// This method is called whenever a restart is performed.
     fn on_restart(&mut self) {}
 
+    /// This method is called when the solver perturbs the search instead of restarting (see
+    /// [`RestartOptions::perturb_after_blocked`]); the [`Brancher`] can use it to diversify its
+    /// decisions, e.g. by flipping every saved phase with probability `flip_probability`.
+    fn on_perturbation(&mut self, _context: &mut SelectionContext, _flip_probability: f64) {}
+
     /// This method returns whether a restart is *currently* pointless for the [`Brancher`].
     ///
     /// For example, if a [`Brancher`] is using a static search strategy then a restart is
@@ -85,3 +105,86 @@ pub trait Brancher {
         true
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Brancher;
+    use crate::basic_types::SolutionReference;
+    use crate::branching::SelectionContext;
+    use crate::engine::predicates::predicate::Predicate;
+    use crate::engine::variables::DomainId;
+    use crate::engine::variables::Literal;
+
+    /// A [`Brancher`] which forwards all calls to `brancher`, and records the calls to
+    /// [`Brancher::on_backtrack`], [`Brancher::on_conflict`] and [`Brancher::on_new_variable`].
+    pub(crate) struct RecordingBrancher<B> {
+        pub(crate) brancher: B,
+        pub(crate) backtrack_levels: Vec<usize>,
+        pub(crate) num_conflicts: u64,
+        pub(crate) new_variables: Vec<DomainId>,
+    }
+
+    impl<B> RecordingBrancher<B> {
+        pub(crate) fn new(brancher: B) -> Self {
+            RecordingBrancher {
+                brancher,
+                backtrack_levels: vec![],
+                num_conflicts: 0,
+                new_variables: vec![],
+            }
+        }
+    }
+
+    impl<B: Brancher> Brancher for RecordingBrancher<B> {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            self.brancher.next_decision(context)
+        }
+
+        fn on_conflict(&mut self) {
+            self.num_conflicts += 1;
+            self.brancher.on_conflict()
+        }
+
+        fn on_unassign_literal(&mut self, literal: Literal) {
+            self.brancher.on_unassign_literal(literal)
+        }
+
+        fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+            self.brancher.on_unassign_integer(variable, value)
+        }
+
+        fn on_backtrack(&mut self, backtrack_level: usize) {
+            self.backtrack_levels.push(backtrack_level);
+            self.brancher.on_backtrack(backtrack_level)
+        }
+
+        fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
+            self.brancher.on_appearance_in_conflict_literal(literal)
+        }
+
+        fn on_appearance_in_conflict_integer(&mut self, variable: DomainId) {
+            self.brancher.on_appearance_in_conflict_integer(variable)
+        }
+
+        fn on_new_variable(&mut self, variable: DomainId) {
+            self.new_variables.push(variable);
+            self.brancher.on_new_variable(variable)
+        }
+
+        fn on_solution(&mut self, solution: SolutionReference) {
+            self.brancher.on_solution(solution)
+        }
+
+        fn on_restart(&mut self) {
+            self.brancher.on_restart()
+        }
+
+        fn on_perturbation(&mut self, context: &mut SelectionContext, flip_probability: f64) {
+            self.brancher.on_perturbation(context, flip_probability)
+        }
+
+        fn is_restart_pointless(&mut self) -> bool {
+            self.brancher.is_restart_pointless()
+        }
+    }
+}
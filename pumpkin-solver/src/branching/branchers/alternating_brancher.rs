@@ -103,6 +103,11 @@ impl<OtherBrancher: Brancher> Brancher for AlternatingBrancher<OtherBrancher> {
             .on_appearance_in_conflict_integer(variable)
     }
 
+    fn on_new_variable(&mut self, variable: DomainId) {
+        self.other_brancher.on_new_variable(variable);
+        self.default_brancher.on_new_variable(variable)
+    }
+
     fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
         self.other_brancher
             .on_appearance_in_conflict_literal(literal);
@@ -152,6 +157,18 @@ impl<OtherBrancher: Brancher> Brancher for AlternatingBrancher<OtherBrancher> {
         self.default_brancher.on_unassign_literal(literal)
     }
 
+    fn on_backtrack(&mut self, backtrack_level: usize) {
+        self.other_brancher.on_backtrack(backtrack_level);
+        self.default_brancher.on_backtrack(backtrack_level)
+    }
+
+    fn on_perturbation(&mut self, context: &mut SelectionContext, flip_probability: f64) {
+        self.other_brancher
+            .on_perturbation(context, flip_probability);
+        self.default_brancher
+            .on_perturbation(context, flip_probability)
+    }
+
     fn on_restart(&mut self) {
         if self.strategy == AlternatingStrategy::EveryRestart {
             // We have considered a restart and we should switch
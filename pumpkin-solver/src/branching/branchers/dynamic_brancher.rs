@@ -89,6 +89,12 @@ impl Brancher for DynamicBrancher {
             .for_each(|brancher| brancher.on_unassign_integer(variable, value));
     }
 
+    fn on_backtrack(&mut self, backtrack_level: usize) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_backtrack(backtrack_level));
+    }
+
     fn on_appearance_in_conflict_literal(&mut self, literal: Literal) {
         self.branchers
             .iter_mut()
@@ -101,6 +107,12 @@ impl Brancher for DynamicBrancher {
             .for_each(|brancher| brancher.on_appearance_in_conflict_integer(variable));
     }
 
+    fn on_new_variable(&mut self, variable: DomainId) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_new_variable(variable));
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.brancher_index = 0;
         self.branchers
@@ -108,6 +120,12 @@ impl Brancher for DynamicBrancher {
             .for_each(|brancher| brancher.on_solution(solution));
     }
 
+    fn on_perturbation(&mut self, context: &mut SelectionContext, flip_probability: f64) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_perturbation(context, flip_probability));
+    }
+
     fn is_restart_pointless(&mut self) -> bool {
         // We return whether all of the branchers up and until this one are static; if this is not
         // the case then restarting could be useful!
@@ -89,10 +89,19 @@ where
             .on_appearance_in_conflict_integer(variable)
     }
 
+    fn on_new_variable(&mut self, variable: DomainId) {
+        self.variable_selector.on_new_variable(variable)
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.value_selector.on_solution(solution);
     }
 
+    fn on_perturbation(&mut self, context: &mut SelectionContext, flip_probability: f64) {
+        self.value_selector
+            .on_perturbation(context, flip_probability);
+    }
+
     fn is_restart_pointless(&mut self) -> bool {
         self.variable_selector.is_restart_pointless() && self.value_selector.is_restart_pointless()
     }
@@ -81,6 +81,8 @@ pub mod tie_breaking;
 pub mod value_selection;
 pub mod variable_selection;
 
+#[cfg(test)]
+pub(crate) use brancher::tests::RecordingBrancher;
 pub use brancher::Brancher;
 pub use selection_context::SelectionContext;
 pub use tie_breaking::*;
@@ -40,6 +40,10 @@ impl<Var> ValueSelector<Var> for DynamicValueSelector<Var> {
         self.selector.on_solution(solution)
     }
 
+    fn on_perturbation(&mut self, context: &mut SelectionContext, flip_probability: f64) {
+        self.selector.on_perturbation(context, flip_probability)
+    }
+
     fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
         self.selector.on_unassign_integer(variable, value)
     }
@@ -146,6 +146,16 @@ impl ValueSelector<PropositionalVariable> for PhaseSaving<PropositionalVariable,
         self.update(lit.get_propositional_variable(), lit.is_positive())
     }
 
+    fn on_perturbation(&mut self, context: &mut SelectionContext, flip_probability: f64) {
+        for saved_value in self.saved_values.iter_mut() {
+            if let StoredValue::Regular(value) = saved_value {
+                if context.random().generate_bool(flip_probability) {
+                    *value = !*value;
+                }
+            }
+        }
+    }
+
     fn is_restart_pointless(&mut self) -> bool {
         false
     }
@@ -221,4 +231,41 @@ mod tests {
 
         assert_eq!(selected, Predicate::Literal(Literal::new(variable, false)));
     }
+
+    #[test]
+    fn perturbation_flips_regular_values_only() {
+        let (assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(0, 0, None);
+        // One draw for each regular value, in the order of the variable indices
+        let mut test_rng = TestRandom {
+            usizes: vec![],
+            bools: vec![false, true],
+        };
+        let mut context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+        let variables = (0..3)
+            .map(PropositionalVariable::create_from_index)
+            .collect::<Vec<_>>();
+
+        let mut phase_saving = PhaseSaving::new(&variables);
+        phase_saving.freeze(variables[1], true);
+
+        phase_saving.on_perturbation(&mut context, 0.5);
+
+        let values = variables
+            .iter()
+            .map(|&variable| phase_saving.saved_values[variable].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                StoredValue::Regular(false),
+                StoredValue::Frozen(true),
+                StoredValue::Regular(true),
+            ],
+            values
+        );
+    }
 }
@@ -121,6 +121,11 @@ where
         self.backup_selector.on_solution(solution)
     }
 
+    fn on_perturbation(&mut self, context: &mut SelectionContext, flip_probability: f64) {
+        self.backup_selector
+            .on_perturbation(context, flip_probability)
+    }
+
     fn is_restart_pointless(&mut self) -> bool {
         self.backup_selector.is_restart_pointless()
     }
@@ -37,6 +37,11 @@ pub trait ValueSelector<Var> {
     /// optimisation problem.
     fn on_solution(&mut self, _solution: SolutionReference) {}
 
+    /// This method is called when the solver perturbs the search instead of restarting; a
+    /// [`ValueSelector`] which saves values can flip each of them with probability
+    /// `flip_probability` to diversify the search.
+    fn on_perturbation(&mut self, _context: &mut SelectionContext, _flip_probability: f64) {}
+
     /// This method returns whether a restart is *currently* pointless for the [`ValueSelector`].
     ///
     /// For example, if a [`ValueSelector`] is using a static strategy (e.g. [`InDomainMin`]) then a
@@ -16,6 +16,7 @@ use crate::pumpkin_assert_eq_simple;
 /// using the method [`AntiFirstFail::with_tie_breaker`].
 pub struct AntiFirstFail<Var, TieBreaking> {
     variables: Vec<Var>,
+    includes_new_variables: bool,
     tie_breaker: TieBreaking,
 }
 
@@ -32,6 +33,7 @@ impl<Var: Clone + 'static> AntiFirstFail<Var, InOrderTieBreaker<Var, i32>> {
         }
         Self {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker: InOrderTieBreaker::new(Direction::Maximum),
         }
     }
@@ -48,17 +50,30 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> AntiFirstFail<Var,
             warn!("The AntiFirstFail variable selector was not provided with any variables");
             return AntiFirstFail {
                 variables: vec![],
+                includes_new_variables: false,
                 tie_breaker,
             };
         }
 
         Self {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker,
         }
     }
 }
 
+impl<Var, TieBreaking> AntiFirstFail<Var, TieBreaking> {
+    /// Makes this selector also select from the integer variables which are created after the
+    /// search has started (see [`VariableSelector::on_new_variable`]); this is intended for a
+    /// selector over all variables of the solver. By default, only the provided variables are
+    /// selected from.
+    pub fn including_new_variables(mut self) -> Self {
+        self.includes_new_variables = true;
+        self
+    }
+}
+
 impl<TieBreaking: TieBreaker<DomainId, i32>> VariableSelector<DomainId>
     for AntiFirstFail<DomainId, TieBreaking>
 {
@@ -72,6 +87,12 @@ impl<TieBreaking: TieBreaker<DomainId, i32>> VariableSelector<DomainId>
             });
         self.tie_breaker.select()
     }
+
+    fn on_new_variable(&mut self, variable: DomainId) {
+        if self.includes_new_variables && !self.variables.contains(&variable) {
+            self.variables.push(variable);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -50,6 +50,10 @@ impl<Var> VariableSelector<Var> for DynamicVariableSelector<Var> {
         self.selector.on_unassign_literal(literal)
     }
 
+    fn on_new_variable(&mut self, variable: DomainId) {
+        self.selector.on_new_variable(variable)
+    }
+
     fn is_restart_pointless(&mut self) -> bool {
         self.selector.is_restart_pointless()
     }
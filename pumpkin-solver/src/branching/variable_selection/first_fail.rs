@@ -16,6 +16,7 @@ use crate::pumpkin_assert_eq_simple;
 /// the method [`FirstFail::with_tie_breaker`].
 pub struct FirstFail<Var, TieBreaking> {
     variables: Vec<Var>,
+    includes_new_variables: bool,
     tie_breaker: TieBreaking,
 }
 
@@ -32,6 +33,7 @@ impl<Var: Clone> FirstFail<Var, InOrderTieBreaker<Var, i32>> {
         }
         Self {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker: InOrderTieBreaker::new(Direction::Minimum),
         }
     }
@@ -48,17 +50,30 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> FirstFail<Var, Tie
             warn!("The FirstFail variable selector was not provided with any variables");
             return FirstFail {
                 variables: vec![],
+                includes_new_variables: false,
                 tie_breaker,
             };
         }
 
         Self {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker,
         }
     }
 }
 
+impl<Var, TieBreaking> FirstFail<Var, TieBreaking> {
+    /// Makes this selector also select from the integer variables which are created after the
+    /// search has started (see [`VariableSelector::on_new_variable`]); this is intended for a
+    /// selector over all variables of the solver. By default, only the provided variables are
+    /// selected from.
+    pub fn including_new_variables(mut self) -> Self {
+        self.includes_new_variables = true;
+        self
+    }
+}
+
 impl<TieBreaking> VariableSelector<DomainId> for FirstFail<DomainId, TieBreaking>
 where
     TieBreaking: TieBreaker<DomainId, i32>,
@@ -73,6 +88,12 @@ where
             });
         self.tie_breaker.select()
     }
+
+    fn on_new_variable(&mut self, variable: DomainId) {
+        if self.includes_new_variables && !self.variables.contains(&variable) {
+            self.variables.push(variable);
+        }
+    }
 }
 
 #[cfg(test)]
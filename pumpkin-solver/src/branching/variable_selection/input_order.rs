@@ -10,6 +10,7 @@ use crate::engine::variables::PropositionalVariable;
 #[derive(Debug)]
 pub struct InputOrder<Var> {
     variables: Vec<Var>,
+    includes_new_variables: bool,
 }
 
 impl<Var: Copy> InputOrder<Var> {
@@ -19,8 +20,18 @@ impl<Var: Copy> InputOrder<Var> {
         }
         InputOrder {
             variables: variables.to_vec(),
+            includes_new_variables: false,
         }
     }
+
+    /// Makes this selector also select from the integer variables which are created after the
+    /// search has started (see [`VariableSelector::on_new_variable`]); this is intended for a
+    /// selector over all variables of the solver. By default, only the provided variables are
+    /// selected from.
+    pub fn including_new_variables(mut self) -> Self {
+        self.includes_new_variables = true;
+        self
+    }
 }
 
 impl VariableSelector<DomainId> for InputOrder<DomainId> {
@@ -30,6 +41,12 @@ impl VariableSelector<DomainId> for InputOrder<DomainId> {
             .find(|variable| !context.is_integer_fixed(**variable))
             .copied()
     }
+
+    fn on_new_variable(&mut self, variable: DomainId) {
+        if self.includes_new_variables && !self.variables.contains(&variable) {
+            self.variables.push(variable);
+        }
+    }
 }
 
 impl VariableSelector<PropositionalVariable> for InputOrder<PropositionalVariable> {
@@ -97,4 +114,39 @@ mod tests {
         let selected = strategy.select_variable(&context);
         assert!(selected.is_none());
     }
+
+    #[test]
+    fn new_variable_is_selected_after_the_provided_ones() {
+        let (mut assignments_integer, assignments_propositional) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (5, 20)]));
+        let mut test_rng = TestRandom::default();
+        let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = InputOrder::new(&integer_variables[..1]).including_new_variables();
+
+        strategy.on_new_variable(integer_variables[1]);
+        strategy.on_new_variable(integer_variables[1]);
+        assert_eq!(integer_variables, strategy.variables);
+
+        let _ = assignments_integer.make_assignment(integer_variables[0], 0, None);
+        let context = SelectionContext::new(
+            &assignments_integer,
+            &assignments_propositional,
+            &mut test_rng,
+        );
+
+        assert_eq!(
+            Some(integer_variables[1]),
+            strategy.select_variable(&context)
+        );
+    }
+    #[test]
+    fn new_variable_is_ignored_by_default() {
+        let (assignments_integer, _) =
+            SelectionContext::create_for_testing(2, 0, Some(vec![(0, 10), (5, 20)]));
+        let integer_variables = assignments_integer.get_domains().collect::<Vec<_>>();
+        let mut strategy = InputOrder::new(&integer_variables[..1]);
+
+        strategy.on_new_variable(integer_variables[1]);
+        assert_eq!(integer_variables[..1], strategy.variables);
+    }
 }
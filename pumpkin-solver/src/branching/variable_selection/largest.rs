@@ -15,6 +15,7 @@ use crate::pumpkin_assert_eq_simple;
 /// method [`Largest::with_tie_breaker`].
 pub struct Largest<Var, TieBreaking> {
     variables: Vec<Var>,
+    includes_new_variables: bool,
     tie_breaker: TieBreaking,
 }
 
@@ -30,11 +31,13 @@ impl<Var: Clone + 'static> Largest<Var, InOrderTieBreaker<Var, i32>> {
             warn!("The Largest variable selector was not provided with any variables");
             return Largest {
                 variables: vec![],
+                includes_new_variables: false,
                 tie_breaker: InOrderTieBreaker::new(Direction::Maximum),
             };
         }
         Self {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker: InOrderTieBreaker::new(Direction::Maximum),
         }
     }
@@ -51,17 +54,30 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> Largest<Var, TieBr
             warn!("The Largest variable selector was not provided with any variables");
             return Largest {
                 variables: vec![],
+                includes_new_variables: false,
                 tie_breaker,
             };
         }
 
         Self {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker,
         }
     }
 }
 
+impl<Var, TieBreaking> Largest<Var, TieBreaking> {
+    /// Makes this selector also select from the integer variables which are created after the
+    /// search has started (see [`VariableSelector::on_new_variable`]); this is intended for a
+    /// selector over all variables of the solver. By default, only the provided variables are
+    /// selected from.
+    pub fn including_new_variables(mut self) -> Self {
+        self.includes_new_variables = true;
+        self
+    }
+}
+
 impl<TieBreaking> VariableSelector<DomainId> for Largest<DomainId, TieBreaking>
 where
     TieBreaking: TieBreaker<DomainId, i32>,
@@ -76,6 +92,12 @@ where
             });
         self.tie_breaker.select()
     }
+
+    fn on_new_variable(&mut self, variable: DomainId) {
+        if self.includes_new_variables && !self.variables.contains(&variable) {
+            self.variables.push(variable);
+        }
+    }
 }
 
 #[cfg(test)]
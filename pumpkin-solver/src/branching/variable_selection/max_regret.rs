@@ -20,6 +20,7 @@ use crate::pumpkin_assert_simple;
 /// the method [`MaxRegret::with_tie_breaker`].
 pub struct MaxRegret<Var, TieBreaking> {
     variables: Vec<Var>,
+    includes_new_variables: bool,
     tie_breaker: TieBreaking,
 }
 
@@ -35,11 +36,13 @@ impl<Var: Clone + 'static> MaxRegret<Var, InOrderTieBreaker<Var, i32>> {
             warn!("The MaxRegret variable selector was not provided with any variables");
             return MaxRegret {
                 variables: vec![],
+                includes_new_variables: false,
                 tie_breaker: InOrderTieBreaker::new(Direction::Maximum),
             };
         }
         MaxRegret {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker: InOrderTieBreaker::new(Direction::Maximum),
         }
     }
@@ -56,17 +59,30 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> MaxRegret<Var, Tie
             warn!("The MaxRegret variable selector was not provided with any variables");
             return MaxRegret {
                 variables: vec![],
+                includes_new_variables: false,
                 tie_breaker,
             };
         }
 
         Self {
             variables: variables.to_vec(),
+            includes_new_variables: false,
             tie_breaker,
         }
     }
 }
 
+impl<Var, TieBreaking> MaxRegret<Var, TieBreaking> {
+    /// Makes this selector also select from the integer variables which are created after the
+    /// search has started (see [`VariableSelector::on_new_variable`]); this is intended for a
+    /// selector over all variables of the solver. By default, only the provided variables are
+    /// selected from.
+    pub fn including_new_variables(mut self) -> Self {
+        self.includes_new_variables = true;
+        self
+    }
+}
+
 impl<TieBreaking> VariableSelector<DomainId> for MaxRegret<DomainId, TieBreaking>
 where
     TieBreaking: TieBreaker<DomainId, i32>,
@@ -90,6 +106,12 @@ where
             });
         self.tie_breaker.select()
     }
+
+    fn on_new_variable(&mut self, variable: DomainId) {
+        if self.includes_new_variables && !self.variables.contains(&variable) {
+            self.variables.push(variable);
+        }
+    }
 }
 
 #[cfg(test)]
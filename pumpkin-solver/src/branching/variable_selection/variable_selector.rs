@@ -41,6 +41,12 @@ pub trait VariableSelector<Var> {
     /// A function which is called when a variable appears in a conflict during conflict analysis.
     fn on_appearance_in_conflict_integer(&mut self, _variable: DomainId) {}
 
+    /// A function which is called when a [`DomainId`] has been created after the search has
+    /// started; a [`VariableSelector`] over all variables can add it to the variables which it
+    /// selects from, if it is not already one of them. A [`VariableSelector`] over a subset of
+    /// the variables should ignore it, since the variable is not part of that subset.
+    fn on_new_variable(&mut self, _variable: DomainId) {}
+
     /// This method returns whether a restart is *currently* pointless for the [`VariableSelector`].
     ///
     /// For example, if a [`VariableSelector`] is using a static strategy (e.g. [`Smallest`]) then a
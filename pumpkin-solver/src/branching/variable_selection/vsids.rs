@@ -130,6 +130,16 @@ impl<Var: StorageKey + Clone + Copy> Vsids<Var> {
         result
     }
 
+    /// Returns the activity of the provided variable, which is the initial value if the variable
+    /// has not (yet) been encountered.
+    pub fn activity(&self, variable: Var) -> f64 {
+        if variable.index() < self.heap.len() {
+            *self.heap.get_value(variable)
+        } else {
+            DEFAULT_VSIDS_VALUE
+        }
+    }
+
     /// Bumps the activity of a variable after it has been encountered during a conflict by
     /// [`Vsids::increment`]
     fn bump_activity(&mut self, variable: Var) {
@@ -189,6 +199,10 @@ impl VariableSelector<DomainId> for Vsids<DomainId> {
         self.bump_activity(variable)
     }
 
+    fn on_new_variable(&mut self, variable: DomainId) {
+        self.restore(variable)
+    }
+
     fn is_restart_pointless(&mut self) -> bool {
         false
     }
@@ -0,0 +1,167 @@
+use std::num::NonZero;
+
+use crate::constraints::check_variables_exist;
+use crate::constraints::Constraint;
+use crate::constraints::NegatableConstraint;
+use crate::propagators::absolute_value::AbsoluteValuePropagator;
+use crate::propagators::absolute_value_not_equal::AbsoluteValueNotEqualPropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [`NegatableConstraint`] `|signed| = absolute`.
+///
+/// Its negation is `|signed| != absolute`, which means that the constraint can be reified as
+/// `r <-> |signed| = absolute`.
+pub fn absolute<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static>(
+    signed: VA,
+    absolute: VB,
+) -> impl NegatableConstraint {
+    AbsoluteValue { signed, absolute }
+}
+
+struct AbsoluteValue<VA, VB> {
+    signed: VA,
+    absolute: VB,
+}
+
+impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> Constraint
+    for AbsoluteValue<VA, VB>
+{
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(
+            solver,
+            [self.signed.flatten().id, self.absolute.flatten().id],
+        )?;
+        AbsoluteValuePropagator::new(self.signed, self.absolute).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(
+            solver,
+            [self.signed.flatten().id, self.absolute.flatten().id],
+        )?;
+        AbsoluteValuePropagator::new(self.signed, self.absolute).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )
+    }
+}
+
+impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> NegatableConstraint
+    for AbsoluteValue<VA, VB>
+{
+    type NegatedConstraint = NotAbsoluteValue<VA, VB>;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        NotAbsoluteValue {
+            signed: self.signed.clone(),
+            absolute: self.absolute.clone(),
+        }
+    }
+}
+
+struct NotAbsoluteValue<VA, VB> {
+    signed: VA,
+    absolute: VB,
+}
+
+impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> Constraint
+    for NotAbsoluteValue<VA, VB>
+{
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(
+            solver,
+            [self.signed.flatten().id, self.absolute.flatten().id],
+        )?;
+        AbsoluteValueNotEqualPropagator::new(self.signed, self.absolute).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(
+            solver,
+            [self.signed.flatten().id, self.absolute.flatten().id],
+        )?;
+        AbsoluteValueNotEqualPropagator::new(self.signed, self.absolute).implied_by(
+            solver,
+            reification_literal,
+            tag,
+        )
+    }
+}
+
+impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> NegatableConstraint
+    for NotAbsoluteValue<VA, VB>
+{
+    type NegatedConstraint = AbsoluteValue<VA, VB>;
+
+    fn negation(&self) -> Self::NegatedConstraint {
+        AbsoluteValue {
+            signed: self.signed.clone(),
+            absolute: self.absolute.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::less_than_or_equals;
+    use crate::variables::TransformableVariable;
+
+    #[test]
+    fn reification_literal_is_false_if_absolute_cannot_equal_the_absolute_value() {
+        let mut solver = Solver::default();
+        let signed = solver.new_bounded_integer(-3, 2);
+        let abs = solver.new_bounded_integer(0, 10);
+        let reification_literal = solver.new_literal();
+
+        let result = solver
+            .add_constraint(absolute(signed, abs))
+            .reify(reification_literal);
+        assert!(result.is_ok());
+        assert_eq!(solver.get_literal_value(reification_literal), None);
+
+        let result = solver
+            .add_constraint(less_than_or_equals([abs.scaled(-1)], -5))
+            .post();
+        assert!(result.is_ok());
+
+        assert_eq!(solver.get_literal_value(reification_literal), Some(false));
+    }
+
+    #[test]
+    fn reification_literal_is_true_if_absolute_equals_the_absolute_value() {
+        let mut solver = Solver::default();
+        let signed = solver.new_bounded_integer(-3, -3);
+        let abs = solver.new_bounded_integer(3, 3);
+        let reification_literal = solver.new_literal();
+
+        let result = solver
+            .add_constraint(absolute(signed, abs))
+            .reify(reification_literal);
+        assert!(result.is_ok());
+
+        assert_eq!(solver.get_literal_value(reification_literal), Some(true));
+    }
+}
@@ -0,0 +1,160 @@
+use std::num::NonZero;
+
+use super::less_than_or_equals;
+use super::maximum;
+use super::minimum;
+use crate::constraints::check_variables_exist;
+use crate::constraints::Constraint;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [`Constraint`] `max(variables) - min(variables) <= max_spread`.
+///
+/// This can be used to express that the values of the `variables` should be balanced. The
+/// constraint is decomposed by introducing new variables for the maximum and the minimum of
+/// `variables`; these are related to `variables` using [`maximum`] and [`minimum`], after which the
+/// difference between the two is bounded by `max_spread`.
+pub fn balance<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    max_spread: i32,
+) -> impl Constraint {
+    Balance {
+        variables: variables.into(),
+        max_spread,
+    }
+}
+
+struct Balance<Var> {
+    variables: Box<[Var]>,
+    max_spread: i32,
+}
+
+impl<Var: IntegerVariable + 'static> Balance<Var> {
+    /// Creates the variables representing the maximum and the minimum of `variables` and posts the
+    /// constraints which define them. Returns `None` if there are no variables to balance.
+    fn post_extremes(
+        &self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<Option<[impl IntegerVariable + 'static; 2]>, ConstraintOperationError> {
+        check_variables_exist(
+            solver,
+            self.variables.iter().map(|variable| variable.flatten().id),
+        )?;
+
+        if self.variables.is_empty() {
+            return Ok(None);
+        }
+
+        let lower_bounds = self
+            .variables
+            .iter()
+            .map(|var| solver.lower_bound(var))
+            .collect::<Vec<_>>();
+        let upper_bounds = self
+            .variables
+            .iter()
+            .map(|var| solver.upper_bound(var))
+            .collect::<Vec<_>>();
+
+        let maximum_variable = solver.new_bounded_integer(
+            *lower_bounds.iter().max().unwrap(),
+            *upper_bounds.iter().max().unwrap(),
+        );
+        let minimum_variable = solver.new_bounded_integer(
+            *lower_bounds.iter().min().unwrap(),
+            *upper_bounds.iter().min().unwrap(),
+        );
+
+        maximum(self.variables.iter().cloned(), maximum_variable).post(solver, tag)?;
+        minimum(self.variables.iter().cloned(), minimum_variable).post(solver, tag)?;
+
+        Ok(Some([
+            maximum_variable.scaled(1),
+            minimum_variable.scaled(-1),
+        ]))
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for Balance<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        match self.post_extremes(solver, tag)? {
+            Some(spread) => less_than_or_equals(spread, self.max_spread).post(solver, tag),
+            None => Ok(()),
+        }
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        // The maximum and minimum variables are functionally defined by the variables, so only the
+        // bound on the spread depends on the reification literal.
+        match self.post_extremes(solver, tag)? {
+            Some(spread) => less_than_or_equals(spread, self.max_spread).implied_by(
+                solver,
+                reification_literal,
+                tag,
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tightening_an_upper_bound_propagates_upper_bounds_through_the_spread() {
+        let mut solver = Solver::default();
+        let variables = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 10));
+
+        let result = solver.add_constraint(balance(variables, 3)).post();
+        assert!(result.is_ok());
+
+        let result = solver
+            .add_constraint(less_than_or_equals([variables[0]], 2))
+            .post();
+        assert!(result.is_ok());
+
+        assert_eq!(solver.upper_bound(&variables[1]), 5);
+        assert_eq!(solver.upper_bound(&variables[2]), 5);
+    }
+
+    #[test]
+    fn tightening_a_lower_bound_propagates_lower_bounds_through_the_spread() {
+        let mut solver = Solver::default();
+        let variables = [0, 1, 2].map(|_| solver.new_bounded_integer(0, 10));
+
+        let result = solver.add_constraint(balance(variables, 3)).post();
+        assert!(result.is_ok());
+
+        let result = solver
+            .add_constraint(less_than_or_equals([variables[0].scaled(-1)], -8))
+            .post();
+        assert!(result.is_ok());
+
+        assert_eq!(solver.lower_bound(&variables[1]), 5);
+        assert_eq!(solver.lower_bound(&variables[2]), 5);
+    }
+
+    #[test]
+    fn spread_which_cannot_be_satisfied_is_detected_at_the_root() {
+        let mut solver = Solver::default();
+        let a = solver.new_bounded_integer(0, 0);
+        let b = solver.new_bounded_integer(5, 5);
+
+        let result = solver.add_constraint(balance([a, b], 4)).post();
+        assert!(result.is_err());
+    }
+}
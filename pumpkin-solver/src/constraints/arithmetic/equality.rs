@@ -1,8 +1,12 @@
 use std::num::NonZero;
 
-use super::less_than_or_equals;
+use super::Inequality;
+use crate::constraints::check_variables_exist;
+#[cfg(doc)]
+use crate::constraints::less_than_or_equals;
 use crate::constraints::Constraint;
 use crate::constraints::NegatableConstraint;
+use crate::propagators::linear_equal::LinearEqualPropagator;
 use crate::propagators::linear_not_equal::LinearNotEqualPropagator;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
@@ -11,6 +15,11 @@ use crate::Solver;
 
 /// Creates the [`NegatableConstraint`] `\sum terms_i = rhs`.
 ///
+/// When posted, the equality is enforced by a single bounds-consistent propagator which tightens
+/// both the lower and the upper bounds of the terms. As for [`less_than_or_equals`], the terms over
+/// variables which are fixed at the root are moved to the right-hand side, and an equality which is
+/// identical to one that was posted before with the same tag does not get a new propagator.
+///
 /// Its negation is [`not_equals`].
 pub fn equals<Var: IntegerVariable + Clone + 'static>(
     terms: impl Into<Box<[Var]>>,
@@ -57,6 +66,26 @@ struct EqualConstraint<Var> {
     rhs: i32,
 }
 
+impl<Var> EqualConstraint<Var>
+where
+    Var: IntegerVariable + Clone + 'static,
+{
+    /// Returns the two inequalities `\sum terms_i <= rhs` and `\sum -terms_i <= -rhs` which
+    /// together make up this equality.
+    ///
+    /// Both inequalities are defined over the same variables with the same (absolute)
+    /// coefficients; the second is obtained by negating the terms of the first.
+    fn inequalities(&self) -> (Inequality<Var>, Inequality<Var::AffineView>) {
+        let less_than_or_equals = Inequality {
+            terms: self.terms.clone(),
+            rhs: self.rhs,
+        };
+        let greater_than_or_equals = less_than_or_equals.negated_terms();
+
+        (less_than_or_equals, greater_than_or_equals)
+    }
+}
+
 impl<Var> Constraint for EqualConstraint<Var>
 where
     Var: IntegerVariable + Clone + 'static,
@@ -66,16 +95,25 @@ where
         solver: &mut Solver,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        less_than_or_equals(self.terms.clone(), self.rhs).post(solver, tag)?;
+        let inequality = Inequality {
+            terms: self.terms,
+            rhs: self.rhs,
+        };
+        inequality.check_variables_exist(solver)?;
+        inequality.check_for_overflow(solver)?;
+        let equality = inequality.fold_fixed(solver);
 
-        let negated = self
-            .terms
-            .iter()
-            .map(|var| var.scaled(-1))
-            .collect::<Box<[_]>>();
-        less_than_or_equals(negated, -self.rhs).post(solver, tag)?;
+        let (terms, rhs) = equality.normalise();
+        if !solver
+            .get_satisfaction_solver_mut()
+            .get_state()
+            .is_inconsistent()
+            && !solver.record_posted_linear_equality(terms, rhs, tag)
+        {
+            return Ok(());
+        }
 
-        Ok(())
+        LinearEqualPropagator::new(equality.terms, equality.rhs).post(solver, tag)
     }
 
     fn implied_by(
@@ -84,18 +122,10 @@ where
         reification_literal: Literal,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        less_than_or_equals(self.terms.clone(), self.rhs).implied_by(
-            solver,
-            reification_literal,
-            tag,
-        )?;
+        let (less_than_or_equals, greater_than_or_equals) = self.inequalities();
 
-        let negated = self
-            .terms
-            .iter()
-            .map(|var| var.scaled(-1))
-            .collect::<Box<[_]>>();
-        less_than_or_equals(negated, -self.rhs).implied_by(solver, reification_literal, tag)?;
+        less_than_or_equals.implied_by(solver, reification_literal, tag)?;
+        greater_than_or_equals.implied_by(solver, reification_literal, tag)?;
 
         Ok(())
     }
@@ -129,6 +159,7 @@ where
         solver: &mut Solver,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(solver, self.terms.iter().map(|term| term.flatten().id))?;
         LinearNotEqualPropagator::new(self.terms, self.rhs).post(solver, tag)
     }
 
@@ -138,6 +169,7 @@ where
         reification_literal: Literal,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(solver, self.terms.iter().map(|term| term.flatten().id))?;
         LinearNotEqualPropagator::new(self.terms, self.rhs).implied_by(
             solver,
             reification_literal,
@@ -159,3 +191,77 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::less_than_or_equals;
+    use crate::engine::variables::DomainId;
+    use crate::variables::TransformableVariable;
+
+    #[test]
+    fn inequalities_of_equality_share_terms() {
+        let x = DomainId::new(0);
+        let y = DomainId::new(1);
+
+        let equality = EqualConstraint {
+            terms: [x.scaled(2), y.scaled(-3)].into(),
+            rhs: 5,
+        };
+
+        let (less_than_or_equals, greater_than_or_equals) = equality.inequalities();
+
+        assert_eq!(
+            less_than_or_equals.terms.as_ref(),
+            &[x.scaled(2), y.scaled(-3)]
+        );
+        assert_eq!(less_than_or_equals.rhs, 5);
+
+        assert_eq!(
+            greater_than_or_equals.terms.as_ref(),
+            &[x.scaled(-2), y.scaled(3)]
+        );
+        assert_eq!(greater_than_or_equals.rhs, -5);
+    }
+
+    #[test]
+    fn identical_equality_is_only_posted_once() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_constraint(equals([x.scaled(2), y.offset(3)], 11))
+            .post();
+        let _ = solver
+            .add_constraint(equals([y.scaled(1), x.scaled(1), x.scaled(1)], 8))
+            .post();
+
+        assert_eq!(1, solver.get_satisfaction_solver_mut().num_propagators());
+    }
+
+    #[test]
+    fn equality_and_inequality_over_the_same_terms_are_both_posted() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver.add_constraint(less_than_or_equals([x, y], 8)).post();
+        let _ = solver.add_constraint(equals([x, y], 8)).post();
+
+        assert_eq!(2, solver.get_satisfaction_solver_mut().num_propagators());
+    }
+
+    #[test]
+    fn fixed_terms_are_moved_to_the_rhs_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(3, 3);
+
+        let result = solver.add_constraint(equals([x, y], 8)).post();
+
+        assert!(result.is_ok());
+        assert_eq!(5, solver.lower_bound(&x));
+        assert_eq!(5, solver.upper_bound(&x));
+    }
+}
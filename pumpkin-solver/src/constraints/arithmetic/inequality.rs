@@ -1,15 +1,35 @@
 use std::num::NonZero;
 
+use log::debug;
+use log::log_enabled;
+use log::Level;
+
+use crate::basic_types::HashMap;
+use crate::constraints::check_variables_exist;
 use crate::constraints::Constraint;
+#[cfg(doc)]
+use crate::constraints::ConstraintPoster;
 use crate::constraints::NegatableConstraint;
 use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
 use crate::variables::IntegerVariable;
+use crate::variables::Literal;
 use crate::ConstraintOperationError;
 use crate::Solver;
 
 /// Create the [`NegatableConstraint`] `\sum terms_i <= rhs`.
 ///
 /// Its negation is `\sum terms_i > rhs`
+///
+/// When this constraint is posted, the terms over variables which are fixed at the root are moved
+/// to the right-hand side. If an identical inequality (after merging the terms over the same
+/// variable and moving the offsets to the right-hand side) was posted before with the same tag, no
+/// new propagator is added; an inequality posted with a different tag gets its own propagator so
+/// that the tag is reported when the inequality is violated.
+///
+/// When the inequality is only implied by a literal, the fixed terms are still moved to the
+/// right-hand side, but a propagator is added for every reification literal.
 pub fn less_than_or_equals<Var: IntegerVariable + 'static>(
     terms: impl Into<Box<[Var]>>,
     rhs: i32,
@@ -20,6 +40,24 @@ pub fn less_than_or_equals<Var: IntegerVariable + 'static>(
     }
 }
 
+/// Create the [`Constraint`] `reification_literal -> \sum terms_i <= rhs`.
+///
+/// This is an alias for posting [`less_than_or_equals`] through
+/// [`ConstraintPoster::implied_by`], for models which state half-reified inequalities as a single
+/// constraint (e.g. the `_imp` constraints of MiniZinc). The inequality is only enforced when
+/// `reification_literal` is true, and `reification_literal` is set to false when the inequality is
+/// violated; it is never set to true because the inequality holds.
+pub fn less_than_or_equals_imp<Var: IntegerVariable + 'static>(
+    terms: impl Into<Box<[Var]>>,
+    rhs: i32,
+    reification_literal: Literal,
+) -> impl Constraint {
+    HalfReified {
+        constraint: less_than_or_equals(terms, rhs),
+        reification_literal,
+    }
+}
+
 /// Creates the [`NegatableConstraint`] `lhs <= rhs`.
 ///
 /// Its negation is `lhs > rhs`.
@@ -40,9 +78,111 @@ pub fn binary_less_than<Var: IntegerVariable + 'static>(
     binary_less_than_or_equals(lhs.scaled(1), rhs.offset(-1))
 }
 
-struct Inequality<Var> {
-    terms: Box<[Var]>,
-    rhs: i32,
+pub(super) struct Inequality<Var> {
+    pub(super) terms: Box<[Var]>,
+    pub(super) rhs: i32,
+}
+
+impl<Var: IntegerVariable + 'static> Inequality<Var> {
+    /// Creates the inequality `\sum -terms_i <= -rhs`, i.e. `\sum terms_i >= rhs`.
+    pub(super) fn negated_terms(&self) -> Inequality<Var::AffineView> {
+        Inequality {
+            terms: self.terms.iter().map(|term| term.scaled(-1)).collect(),
+            rhs: -self.rhs,
+        }
+    }
+
+    /// Checks that every term is defined over a variable of `solver`.
+    pub(super) fn check_variables_exist(
+        &self,
+        solver: &mut Solver,
+    ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(solver, self.terms.iter().map(|term| term.flatten().id))
+    }
+
+    /// Checks that the bounds of every term fit in an `i32` after applying its coefficient; the
+    /// propagator assumes that this is the case.
+    pub(super) fn check_for_overflow(
+        &self,
+        solver: &mut Solver,
+    ) -> Result<(), ConstraintOperationError> {
+        let satisfaction_solver = solver.get_satisfaction_solver_mut();
+
+        match self
+            .terms
+            .iter()
+            .position(|term| !satisfaction_solver.integer_variable_has_representable_bounds(term))
+        {
+            Some(term) => Err(ConstraintOperationError::CoefficientOverflow { term }),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes the terms whose variable is fixed at the root and subtracts their value from the
+    /// right-hand side, which reduces the work done by the propagator.
+    ///
+    /// At least one term is kept, and the inequality is left unchanged if the new right-hand side
+    /// does not fit in an `i32`.
+    pub(super) fn fold_fixed(self, solver: &Solver) -> Self {
+        let (fixed, free): (Vec<_>, Vec<_>) = self
+            .terms
+            .into_vec()
+            .into_iter()
+            .partition(|term| solver.lower_bound(term) == solver.upper_bound(term));
+
+        let fixed_contribution = fixed
+            .iter()
+            .map(|term| solver.lower_bound(term) as i64)
+            .sum::<i64>();
+        let folded_rhs = i32::try_from(self.rhs as i64 - fixed_contribution);
+
+        match folded_rhs {
+            Ok(rhs) if !free.is_empty() => Inequality {
+                terms: free.into(),
+                rhs,
+            },
+            _ => Inequality {
+                terms: fixed.into_iter().chain(free).collect(),
+                rhs: self.rhs,
+            },
+        }
+    }
+
+    /// Creates the propagator for this inequality, in which every term is flattened into a scaled
+    /// [`DomainId`] such that the propagator does not go through nested views.
+    fn flattened_propagator(
+        &self,
+    ) -> Result<LinearLessOrEqualPropagator<AffineView<DomainId>>, ConstraintOperationError> {
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| (term.clone(), 1))
+            .collect::<Vec<_>>();
+
+        LinearLessOrEqualPropagator::from_terms(&terms, self.rhs)
+    }
+
+    /// Rewrites the inequality as `\sum a_i * x_i <= c` over the underlying domains `x_i`, where
+    /// every domain occurs at most once with a non-zero coefficient and the terms are sorted by
+    /// domain.
+    pub(super) fn normalise(&self) -> (Box<[(DomainId, i64)]>, i64) {
+        let mut coefficients: HashMap<DomainId, i64> = HashMap::default();
+        let mut rhs = self.rhs as i64;
+
+        for term in self.terms.iter() {
+            let flattened = term.flatten();
+            *coefficients.entry(flattened.id).or_default() += flattened.scale;
+            rhs -= flattened.offset;
+        }
+
+        let mut terms = coefficients
+            .into_iter()
+            .filter(|&(_, coefficient)| coefficient != 0)
+            .collect::<Vec<_>>();
+        terms.sort_by_key(|&(domain, _)| domain.id);
+
+        (terms.into(), rhs)
+    }
 }
 
 impl<Var: IntegerVariable + 'static> Constraint for Inequality<Var> {
@@ -51,20 +191,48 @@ impl<Var: IntegerVariable + 'static> Constraint for Inequality<Var> {
         solver: &mut Solver,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        LinearLessOrEqualPropagator::new(self.terms, self.rhs).post(solver, tag)
+        self.check_variables_exist(solver)?;
+        self.check_for_overflow(solver)?;
+        let inequality = self.fold_fixed(solver);
+
+        // An inequality which is identical to one that was posted before with the same tag would
+        // only duplicate the work of the existing propagator. If the solver is inconsistent, we
+        // still post it so that the error is reported.
+        let (terms, rhs) = inequality.normalise();
+        if !solver
+            .get_satisfaction_solver_mut()
+            .get_state()
+            .is_inconsistent()
+            && !solver.record_posted_linear_inequality(terms, rhs, tag)
+        {
+            return Ok(());
+        }
+
+        let propagator = inequality.flattened_propagator()?;
+        if log_enabled!(Level::Debug) {
+            let names = solver.get_satisfaction_solver_mut().variable_names();
+            debug!(
+                "Posting the linear inequality {}",
+                propagator.fmt_with(&|domain| names.get_int_name(domain))
+            );
+        }
+
+        propagator.post(solver, tag)
     }
 
     fn implied_by(
         self,
         solver: &mut Solver,
-        reification_literal: crate::variables::Literal,
+        reification_literal: Literal,
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
-        LinearLessOrEqualPropagator::new(self.terms, self.rhs).implied_by(
-            solver,
-            reification_literal,
-            tag,
-        )
+        self.check_variables_exist(solver)?;
+        self.check_for_overflow(solver)?;
+        let inequality = self.fold_fixed(solver);
+
+        inequality
+            .flattened_propagator()?
+            .implied_by(solver, reification_literal, tag)
     }
 }
 
@@ -78,3 +246,287 @@ impl<Var: IntegerVariable + 'static> NegatableConstraint for Inequality<Var> {
         }
     }
 }
+
+/// The [`Constraint`] `reification_literal -> constraint`.
+struct HalfReified<C> {
+    constraint: C,
+    reification_literal: Literal,
+}
+
+impl<C: Constraint> Constraint for HalfReified<C> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.constraint
+            .implied_by(solver, self.reification_literal, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        // `r -> (b -> C)` is equivalent to `(r /\ b) -> C`, so we introduce a literal which is
+        // implied by the conjunction and let it imply the inequality.
+        let conjunction = solver.new_literal();
+        solver.add_clause([!reification_literal, !self.reification_literal, conjunction])?;
+
+        self.constraint.implied_by(solver, conjunction, tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::DomainId;
+    use crate::variables::TransformableVariable;
+
+    #[test]
+    fn overflowing_coefficient_is_reported_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let result = solver
+            .add_constraint(less_than_or_equals([y.scaled(1), x.scaled(i32::MAX)], 5))
+            .post();
+
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::CoefficientOverflow { term: 1 })
+        ));
+    }
+
+    #[test]
+    fn variable_of_another_solver_is_reported_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+
+        let mut other_solver = Solver::default();
+        let _ = other_solver.new_bounded_integer(0, 10);
+        let y = other_solver.new_bounded_integer(0, 10);
+
+        let result = solver.add_constraint(less_than_or_equals([x, y], 5)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 1 })
+        ));
+
+        let result = solver
+            .add_constraint(less_than_or_equals([x, DomainId::new(7)], 5))
+            .post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 1 })
+        ));
+    }
+
+    #[test]
+    fn representable_coefficient_is_accepted_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 1);
+
+        let result = solver
+            .add_constraint(less_than_or_equals([x.scaled(i32::MAX)], i32::MAX))
+            .post();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn views_are_flattened_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        // (2x + 1) + 2 * (y - 3) <= 9, i.e. 2x + 2y <= 14
+        let result = solver
+            .add_constraint(less_than_or_equals(
+                [x.scaled(2).offset(1), y.offset(-3).scaled(2)],
+                9,
+            ))
+            .post();
+
+        assert!(result.is_ok());
+        assert_eq!(7, solver.upper_bound(&x));
+        assert_eq!(7, solver.upper_bound(&y));
+    }
+
+    #[test]
+    fn identical_inequality_is_only_posted_once() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        for _ in 0..2 {
+            let result = solver
+                .add_constraint(less_than_or_equals([x.scaled(2), y.scaled(1)], 8))
+                .post();
+            assert!(result.is_ok());
+        }
+
+        assert_eq!(1, solver.get_satisfaction_solver_mut().num_propagators());
+    }
+
+    #[test]
+    fn equivalent_inequality_with_different_terms_is_only_posted_once() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver
+            .add_constraint(less_than_or_equals([x.scaled(2), y.offset(3)], 11))
+            .post();
+        let _ = solver
+            .add_constraint(less_than_or_equals(
+                [y.scaled(1), x.scaled(1), x.scaled(1)],
+                8,
+            ))
+            .post();
+
+        assert_eq!(1, solver.get_satisfaction_solver_mut().num_propagators());
+    }
+
+    #[test]
+    fn identical_inequality_with_another_tag_is_posted_with_that_tag() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let first_tag = NonZero::new(1).unwrap();
+        let second_tag = NonZero::new(2).unwrap();
+        for tag in [first_tag, first_tag, second_tag] {
+            let result = solver
+                .add_constraint(less_than_or_equals([x, y], 8))
+                .with_tag(tag)
+                .post();
+            assert!(result.is_ok());
+        }
+
+        assert_eq!(2, solver.get_satisfaction_solver_mut().num_propagators());
+    }
+
+    #[test]
+    fn inequalities_with_different_right_hand_sides_are_both_posted() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let _ = solver.add_constraint(less_than_or_equals([x, y], 8)).post();
+        let _ = solver.add_constraint(less_than_or_equals([x, y], 7)).post();
+
+        assert_eq!(2, solver.get_satisfaction_solver_mut().num_propagators());
+    }
+
+    #[test]
+    fn violated_half_reified_inequality_sets_literal_to_false() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(5, 10);
+        let y = solver.new_bounded_integer(5, 10);
+        let b = solver.new_literal();
+
+        let result = solver
+            .add_constraint(less_than_or_equals_imp([x, y], 8, b))
+            .post();
+
+        assert!(result.is_ok());
+        assert_eq!(Some(false), solver.get_literal_value(b));
+    }
+
+    #[test]
+    fn satisfied_half_reified_inequality_does_not_set_literal_to_true() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 3);
+        let y = solver.new_bounded_integer(0, 3);
+        let b = solver.new_literal();
+
+        let result = solver
+            .add_constraint(less_than_or_equals_imp([x, y], 8, b))
+            .post();
+
+        assert!(result.is_ok());
+        assert_eq!(None, solver.get_literal_value(b));
+    }
+
+    #[test]
+    fn half_reified_inequality_is_enforced_when_literal_is_true() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(5, 10);
+        let b = solver.new_literal();
+        let _ = solver.add_clause([b]);
+
+        let result = solver
+            .add_constraint(less_than_or_equals_imp([x, y], 8, b))
+            .post();
+
+        assert!(result.is_ok());
+        assert_eq!(3, solver.upper_bound(&x));
+    }
+
+    #[test]
+    fn half_reified_inequality_is_not_enforced_when_literal_is_false() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(5, 10);
+        let b = solver.new_literal();
+        let _ = solver.add_clause([!b]);
+
+        let result = solver
+            .add_constraint(less_than_or_equals_imp([x, y], 8, b))
+            .post();
+
+        assert!(result.is_ok());
+        assert_eq!(10, solver.upper_bound(&x));
+        assert_eq!(10, solver.upper_bound(&y));
+    }
+
+    #[test]
+    fn fixed_terms_are_folded_into_the_right_hand_side() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(3, 3);
+        let y = solver.new_bounded_integer(0, 10);
+        let z = solver.new_bounded_integer(-2, -2);
+
+        let inequality = Inequality {
+            terms: [x.scaled(2), y.scaled(1), z.scaled(3)].into(),
+            rhs: 7,
+        }
+        .fold_fixed(&solver);
+
+        // 2 * 3 + y + 3 * -2 <= 7 becomes y <= 7
+        assert_eq!(1, inequality.terms.len());
+        assert_eq!(y.scaled(1), inequality.terms[0]);
+        assert_eq!(7, inequality.rhs);
+    }
+
+    #[test]
+    fn inequality_with_only_fixed_terms_is_not_folded() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(3, 3);
+
+        let inequality = Inequality {
+            terms: [x].into(),
+            rhs: 2,
+        }
+        .fold_fixed(&solver);
+
+        assert_eq!(1, inequality.terms.len());
+        assert_eq!(2, inequality.rhs);
+    }
+
+    #[test]
+    fn folded_inequality_propagates_free_terms() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(4, 4);
+        let y = solver.new_bounded_integer(0, 10);
+
+        let result = solver.add_constraint(less_than_or_equals([x, y], 9)).post();
+
+        assert!(result.is_ok());
+        assert_eq!(5, solver.upper_bound(&y));
+    }
+}
@@ -1,15 +1,21 @@
+mod absolute;
+mod balance;
 mod equality;
 mod inequality;
 
+pub use absolute::*;
+pub use balance::*;
 pub use equality::*;
 pub use inequality::*;
 
 use super::Constraint;
-use crate::propagators::absolute_value::AbsoluteValuePropagator;
+use super::KnownVariables;
+use super::ValidatedConstraint;
 use crate::propagators::division::DivisionPropagator;
 use crate::propagators::integer_multiplication::IntegerMultiplicationPropagator;
 use crate::propagators::maximum::MaximumPropagator;
 use crate::variables::IntegerVariable;
+use crate::ConstraintOperationError;
 
 /// Creates the [`Constraint`] `a + b = c`.
 pub fn plus<Var: IntegerVariable + 'static>(a: Var, b: Var, c: Var) -> impl Constraint {
@@ -22,7 +28,10 @@ pub fn times(
     b: impl IntegerVariable + 'static,
     c: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    IntegerMultiplicationPropagator::new(a, b, c)
+    KnownVariables {
+        domains: [a.flatten().id, b.flatten().id, c.flatten().id].into(),
+        constraint: IntegerMultiplicationPropagator::new(a, b, c),
+    }
 }
 
 /// Creates the [`Constraint`] `numerator / denominator = rhs`.
@@ -36,26 +45,45 @@ pub fn division(
     denominator: impl IntegerVariable + 'static,
     rhs: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    DivisionPropagator::new(numerator, denominator, rhs)
-}
-
-/// Creates the [`Constraint`] `|signed| = absolute`.
-pub fn absolute(
-    signed: impl IntegerVariable + 'static,
-    absolute: impl IntegerVariable + 'static,
-) -> impl Constraint {
-    AbsoluteValuePropagator::new(signed, absolute)
+    KnownVariables {
+        domains: [
+            numerator.flatten().id,
+            denominator.flatten().id,
+            rhs.flatten().id,
+        ]
+        .into(),
+        constraint: DivisionPropagator::new(numerator, denominator, rhs),
+    }
 }
 
 /// Creates the [`Constraint`] `max(array) = m`.
+///
+/// Posting the constraint results in [`ConstraintOperationError::EmptyConstraint`] if `array` is
+/// empty.
 pub fn maximum<Var: IntegerVariable + 'static>(
     array: impl IntoIterator<Item = Var>,
     rhs: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    MaximumPropagator::new(array.into_iter().collect(), rhs)
+    let array = array.into_iter().collect::<Box<[_]>>();
+
+    KnownVariables {
+        domains: array
+            .iter()
+            .map(|variable| variable.flatten().id)
+            .chain(std::iter::once(rhs.flatten().id))
+            .collect(),
+        constraint: ValidatedConstraint(
+            (!array.is_empty())
+                .then(|| MaximumPropagator::new(array, rhs))
+                .ok_or(ConstraintOperationError::EmptyConstraint),
+        ),
+    }
 }
 
 /// Creates the [`Constraint`] `min(array) = m`.
+///
+/// Posting the constraint results in [`ConstraintOperationError::EmptyConstraint`] if `array` is
+/// empty.
 pub fn minimum<Var: IntegerVariable + 'static>(
     array: impl IntoIterator<Item = Var>,
     rhs: impl IntegerVariable + 'static,
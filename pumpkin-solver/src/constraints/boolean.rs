@@ -143,3 +143,29 @@ impl BooleanEqual {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weighted_sum_of_booleans_is_propagated() {
+        let mut solver = Solver::default();
+        let a = solver.new_literal();
+        let b = solver.new_literal();
+        let c = solver.new_literal();
+
+        let result = solver
+            .add_constraint(boolean_less_than_or_equals([2, 3, -1], [a, b, c], 2))
+            .post();
+        assert!(result.is_ok());
+        assert_eq!(None, solver.get_literal_value(b));
+
+        let result = solver.add_clause([a]);
+        assert!(result.is_ok());
+
+        // 2 + 3 * b - c <= 2 can only hold if b is false
+        assert_eq!(Some(false), solver.get_literal_value(b));
+        assert_eq!(None, solver.get_literal_value(c));
+    }
+}
@@ -0,0 +1,23 @@
+use super::Constraint;
+use super::KnownVariables;
+use crate::propagators::count::CountPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [count](https://sofdem.github.io/gccat/gccat/Ccount.html) [`Constraint`] which
+/// states that exactly `count` of the variables in `array` take the constant `value`.
+pub fn count_eq<Var: IntegerVariable + 'static>(
+    array: impl IntoIterator<Item = Var>,
+    value: i32,
+    count: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    let array = array.into_iter().collect::<Box<[_]>>();
+
+    KnownVariables {
+        domains: array
+            .iter()
+            .map(|variable| variable.flatten().id)
+            .chain(std::iter::once(count.flatten().id))
+            .collect(),
+        constraint: CountPropagator::new(array, value, count),
+    }
+}
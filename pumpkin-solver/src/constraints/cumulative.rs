@@ -2,6 +2,7 @@ use std::fmt::Debug;
 use std::num::NonZero;
 
 use super::Constraint;
+use super::KnownVariables;
 use crate::options::CumulativePropagationMethod;
 use crate::propagators::ArgTask;
 use crate::propagators::CumulativeOptions;
@@ -172,19 +173,23 @@ where
         "The number of start variables, durations and resource requirements should be the same!"
     );
 
-    CumulativeConstraint::new(
-        &start_times
-            .zip(durations)
-            .zip(resource_requirements)
-            .map(|((start_time, duration), resource_requirement)| ArgTask {
-                start_time,
-                processing_time: duration,
-                resource_usage: resource_requirement,
-            })
-            .collect::<Vec<_>>(),
-        resource_capacity,
-        options,
-    )
+    let tasks = start_times
+        .zip(durations)
+        .zip(resource_requirements)
+        .map(|((start_time, duration), resource_requirement)| ArgTask {
+            start_time,
+            processing_time: duration,
+            resource_usage: resource_requirement,
+        })
+        .collect::<Vec<_>>();
+
+    KnownVariables {
+        domains: tasks
+            .iter()
+            .map(|task| task.start_time.flatten().id)
+            .collect(),
+        constraint: CumulativeConstraint::new(&tasks, resource_capacity, options),
+    }
 }
 
 struct CumulativeConstraint<Var> {
@@ -0,0 +1,137 @@
+use std::num::NonZero;
+
+use super::check_variables_exist;
+use super::Constraint;
+use crate::propagators::disjunctive::DisjunctivePropagator;
+use crate::pumpkin_assert_simple;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [Disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// [`Constraint`], which states that no two tasks overlap in time; task `i` starts at
+/// `start_times[i]` and has duration `durations[i]`.
+///
+/// This is the special case of the [`crate::constraints::cumulative`] constraint where every task
+/// uses the whole resource, which allows it to be propagated using edge finding.
+///
+/// The length of `start_times` and `durations` should be the same; if this is not the case then
+/// this method will panic.
+pub fn disjunctive<Var: IntegerVariable + 'static>(
+    start_times: impl IntoIterator<Item = Var>,
+    durations: impl IntoIterator<Item = i32>,
+) -> impl Constraint {
+    let start_times = start_times.into_iter().collect::<Box<[_]>>();
+    let durations = durations.into_iter().collect::<Box<[_]>>();
+
+    pumpkin_assert_simple!(
+        start_times.len() == durations.len(),
+        "The number of start variables and durations should be the same!"
+    );
+
+    Disjunctive {
+        start_times,
+        durations,
+    }
+}
+
+struct Disjunctive<Var> {
+    start_times: Box<[Var]>,
+    durations: Box<[i32]>,
+}
+
+impl<Var: IntegerVariable + 'static> Disjunctive<Var> {
+    fn check_variables_exist(&self, solver: &mut Solver) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(
+            solver,
+            self.start_times
+                .iter()
+                .map(|start_time| start_time.flatten().id),
+        )
+    }
+
+    /// Creates the propagators which tighten the lower bounds and the upper bounds of the start
+    /// times respectively. The latter operates on the mirrored tasks, which start at
+    /// `-(start_times[i] + durations[i])`.
+    fn create_propagators(
+        self,
+    ) -> (
+        DisjunctivePropagator<Var>,
+        DisjunctivePropagator<<Var::AffineView as IntegerVariable>::AffineView>,
+    ) {
+        let mirrored_start_times = self
+            .start_times
+            .iter()
+            .zip(self.durations.iter())
+            .map(|(start_time, &duration)| start_time.scaled(-1).offset(-duration))
+            .collect();
+
+        (
+            DisjunctivePropagator::new(self.start_times, self.durations.clone()),
+            DisjunctivePropagator::new(mirrored_start_times, self.durations),
+        )
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for Disjunctive<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.check_variables_exist(solver)?;
+        let (forward, mirrored) = self.create_propagators();
+
+        forward.post(solver, tag)?;
+        mirrored.post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.check_variables_exist(solver)?;
+        let (forward, mirrored) = self.create_propagators();
+
+        forward.implied_by(solver, reification_literal, tag)?;
+        mirrored.implied_by(solver, reification_literal, tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::ProblemSolution;
+    use crate::results::SatisfactionResult;
+    use crate::termination::Indefinite;
+
+    #[test]
+    fn three_tasks_on_one_machine_are_ordered() {
+        let mut solver = Solver::default();
+        let a = solver.new_bounded_integer(0, 1);
+        let b = solver.new_bounded_integer(0, 10);
+        let c = solver.new_bounded_integer(0, 4);
+
+        let result = solver
+            .add_constraint(disjunctive([a, b, c], [4, 3, 2]))
+            .post();
+        assert!(result.is_ok());
+
+        // `c` does not fit before `a`, and `b` does not fit before either of them.
+        assert_eq!((0, 0), (solver.lower_bound(&a), solver.upper_bound(&a)));
+        assert_eq!((4, 4), (solver.lower_bound(&c), solver.upper_bound(&c)));
+        assert_eq!(6, solver.lower_bound(&b));
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        match solver.satisfy(&mut brancher, &mut Indefinite) {
+            SatisfactionResult::Satisfiable(solution) => {
+                assert!(solution.get_integer_value(b) >= 6);
+            }
+            result => panic!("expected a solution, got {result:?}"),
+        }
+    }
+}
@@ -1,12 +1,69 @@
 use super::Constraint;
+use super::KnownVariables;
+use super::ValidatedConstraint;
 use crate::propagators::element::ElementPropagator;
+use crate::propagators::element_2d::Element2DPropagator;
 use crate::variables::IntegerVariable;
+use crate::ConstraintOperationError;
 
 /// Creates the [element](https://sofdem.github.io/gccat/gccat/Celement.html) [`Constraint`] which states that `array[index] = rhs`.
+///
+/// Posting the constraint results in [`ConstraintOperationError::EmptyConstraint`] if `array` is
+/// empty.
 pub fn element<ElementVar: IntegerVariable + 'static>(
     index: impl IntegerVariable + 'static,
     array: impl IntoIterator<Item = ElementVar>,
     rhs: impl IntegerVariable + 'static,
 ) -> impl Constraint {
-    ElementPropagator::new(array.into_iter().collect(), index, rhs)
+    let array = array.into_iter().collect::<Box<[_]>>();
+
+    KnownVariables {
+        domains: std::iter::once(index.flatten().id)
+            .chain(array.iter().map(|variable| variable.flatten().id))
+            .chain(std::iter::once(rhs.flatten().id))
+            .collect(),
+        constraint: ValidatedConstraint(
+            (!array.is_empty())
+                .then(|| ElementPropagator::new(array, index, rhs))
+                .ok_or(ConstraintOperationError::EmptyConstraint),
+        ),
+    }
+}
+
+/// Creates the two-dimensional [element](https://sofdem.github.io/gccat/gccat/Celement_matrix.html)
+/// [`Constraint`] which states that `matrix[row][column] = rhs`, where `matrix` is a matrix of
+/// constants and both indices are 0-indexed.
+///
+/// Posting the constraint results in [`ConstraintOperationError::NonRectangularMatrix`] if the
+/// rows of `matrix` do not all have the same length, and in
+/// [`ConstraintOperationError::EmptyConstraint`] if `matrix` contains no cells.
+pub fn element_2d(
+    matrix: impl IntoIterator<Item = impl IntoIterator<Item = i32>>,
+    row: impl IntegerVariable + 'static,
+    column: impl IntegerVariable + 'static,
+    rhs: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    let matrix = matrix
+        .into_iter()
+        .map(|row| row.into_iter().collect::<Box<[_]>>())
+        .collect::<Box<[_]>>();
+
+    let is_rectangular = matrix
+        .iter()
+        .all(|matrix_row| matrix_row.len() == matrix[0].len());
+    let is_empty = matrix.iter().all(|matrix_row| matrix_row.is_empty());
+
+    let domains = [row.flatten().id, column.flatten().id, rhs.flatten().id].into();
+    let constraint = if !is_rectangular {
+        Err(ConstraintOperationError::NonRectangularMatrix)
+    } else if is_empty {
+        Err(ConstraintOperationError::EmptyConstraint)
+    } else {
+        Ok(Element2DPropagator::new(matrix, row, column, rhs))
+    };
+
+    KnownVariables {
+        domains,
+        constraint: ValidatedConstraint(constraint),
+    }
 }
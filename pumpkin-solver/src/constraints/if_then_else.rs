@@ -0,0 +1,27 @@
+use super::Constraint;
+use super::KnownVariables;
+use crate::propagators::if_then_else::IfThenElsePropagator;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+
+/// Creates the [`Constraint`] `result = (condition ? then_value : else_value)`; i.e. `result =
+/// then_value` if `condition` is true, and `result = else_value` otherwise.
+///
+/// The constraint is propagated on the bounds of the variables, and `condition` is assigned as
+/// soon as the bounds of `result` are disjoint from the bounds of one of the branches.
+pub fn if_then_else(
+    condition: Literal,
+    then_value: impl IntegerVariable + 'static,
+    else_value: impl IntegerVariable + 'static,
+    result: impl IntegerVariable + 'static,
+) -> impl Constraint {
+    KnownVariables {
+        domains: [
+            then_value.flatten().id,
+            else_value.flatten().id,
+            result.flatten().id,
+        ]
+        .into(),
+        constraint: IfThenElsePropagator::new(condition, then_value, else_value, result),
+    }
+}
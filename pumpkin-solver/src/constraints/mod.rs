@@ -27,8 +27,12 @@ mod arithmetic;
 mod boolean;
 mod clause;
 mod constraint_poster;
+mod count;
 mod cumulative;
+mod disjunctive;
 mod element;
+mod if_then_else;
+mod set;
 
 use std::num::NonZero;
 
@@ -37,11 +41,16 @@ pub use arithmetic::*;
 pub use boolean::*;
 pub use clause::*;
 pub use constraint_poster::*;
+pub use count::*;
 pub use cumulative::*;
+pub use disjunctive::*;
 pub use element::*;
+pub use if_then_else::*;
+pub use set::*;
 
 use crate::engine::propagation::Propagator;
 use crate::propagators::ReifiedPropagator;
+use crate::variables::DomainId;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
 use crate::Solver;
@@ -131,6 +140,77 @@ impl<C: Constraint> Constraint for Vec<C> {
     }
 }
 
+/// A [`Constraint`] whose arguments are validated when it is created; if they are invalid (e.g.
+/// an empty collection for a constraint which is only defined over a non-empty collection), then
+/// no constraint is provided and posting it results in the provided error.
+struct ValidatedConstraint<C>(Result<C, ConstraintOperationError>);
+
+impl<C: Constraint> Constraint for ValidatedConstraint<C> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.0?.post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.0?.implied_by(solver, reification_literal, tag)
+    }
+}
+
+/// Checks that every domain in `domains` was created by `solver`; a variable of another solver would
+/// otherwise be used to index into the domains of this solver.
+///
+/// The index of the first unknown domain is reported in the error.
+fn check_variables_exist(
+    solver: &mut Solver,
+    domains: impl IntoIterator<Item = DomainId>,
+) -> Result<(), ConstraintOperationError> {
+    let satisfaction_solver = solver.get_satisfaction_solver_mut();
+
+    match domains
+        .into_iter()
+        .position(|domain| !satisfaction_solver.integer_variable_exists(&domain))
+    {
+        Some(term) => Err(ConstraintOperationError::UnknownVariable { term }),
+        None => Ok(()),
+    }
+}
+
+/// A [`Constraint`] which is only posted if the domains of all its variables were created by the
+/// [`Solver`]; the domains are given in the order of the arguments of the constraint.
+struct KnownVariables<C> {
+    domains: Box<[DomainId]>,
+    constraint: C,
+}
+
+impl<C: Constraint> Constraint for KnownVariables<C> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(solver, self.domains.iter().copied())?;
+        self.constraint.post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        check_variables_exist(solver, self.domains.iter().copied())?;
+        self.constraint.implied_by(solver, reification_literal, tag)
+    }
+}
+
 /// A [`Constraint`] which has a well-defined negation.
 ///
 /// Having a negation means the [`Constraint`] can be fully reified; i.e., a constraint `C` can be
@@ -165,3 +245,158 @@ pub trait NegatableConstraint: Constraint {
         negation.implied_by(solver, !reification_literal, tag)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constraints_over_empty_collections_are_reported_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let empty: [crate::variables::DomainId; 0] = [];
+
+        let result = solver.add_constraint(maximum(empty, x)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::EmptyConstraint)
+        ));
+
+        let result = solver.add_constraint(minimum(empty, x)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::EmptyConstraint)
+        ));
+
+        let result = solver.add_constraint(element(x, empty, x)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::EmptyConstraint)
+        ));
+
+        let result = solver
+            .add_constraint(element_2d([[0; 0]; 2], x, x, x))
+            .post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::EmptyConstraint)
+        ));
+
+        // The solver is not affected by the rejected constraints
+        assert!(solver.add_constraint(maximum([x], x)).post().is_ok());
+    }
+
+    #[test]
+    fn constraints_over_variables_of_another_solver_are_reported_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let unknown = DomainId::new(7);
+
+        let result = solver.add_constraint(maximum([x, unknown], x)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 1 })
+        ));
+
+        let result = solver.add_constraint(element(x, [x], unknown)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 2 })
+        ));
+
+        let result = solver.add_constraint(times(unknown, x, x)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 0 })
+        ));
+
+        let result = solver.add_constraint(all_different([x, unknown])).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 1 })
+        ));
+
+        let result = solver.add_constraint(absolute(x, unknown)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 1 })
+        ));
+
+        let result = solver
+            .add_constraint(cumulative([x, unknown], [1, 1], [1, 1], 1))
+            .post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::UnknownVariable { term: 1 })
+        ));
+
+        // The solver is not affected by the rejected constraints
+        assert!(solver.add_constraint(maximum([x], x)).post().is_ok());
+    }
+
+    #[test]
+    fn root_infeasibility_is_reported_when_posting() {
+        let mut solver = Solver::default();
+        let x = solver.new_bounded_integer(0, 10);
+        let y = solver.new_bounded_integer(20, 30);
+
+        let result = solver.add_constraint(maximum([x], y)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InfeasiblePropagator)
+        ));
+
+        // Once the solver is infeasible, every subsequent constraint is rejected
+        let result = solver.add_constraint(maximum([y], y)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InfeasibleState)
+        ));
+    }
+
+    #[test]
+    fn root_infeasibility_of_clauses_is_reported_when_posting() {
+        let mut solver = Solver::default();
+        let literal = solver.new_literal();
+        let x = solver.new_bounded_integer(0, 10);
+
+        assert!(solver.add_clause([literal]).is_ok());
+        let result = solver.add_clause([!literal]);
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InfeasibleClause)
+        ));
+
+        let result = solver.add_clause([literal]);
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InfeasibleState)
+        ));
+        let result = solver.add_constraint(maximum([x], x)).post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::InfeasibleState)
+        ));
+    }
+
+    #[test]
+    fn element_2d_over_non_rectangular_matrix_is_reported_when_posting() {
+        let mut solver = Solver::default();
+        let row = solver.new_bounded_integer(0, 1);
+        let column = solver.new_bounded_integer(0, 1);
+        let rhs = solver.new_bounded_integer(0, 10);
+
+        let result = solver
+            .add_constraint(element_2d([vec![1, 2], vec![3]], row, column, rhs))
+            .post();
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::NonRectangularMatrix)
+        ));
+
+        let result = solver
+            .add_constraint(element_2d([vec![1, 2], vec![3, 4]], row, column, rhs))
+            .post();
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,266 @@
+use std::num::NonZero;
+
+use super::check_variables_exist;
+use super::equals;
+use super::less_than_or_equals;
+use super::Constraint;
+use crate::predicate;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::variables::SetVariable;
+use crate::variables::TransformableVariable;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [`Constraint`] `|set| = cardinality`.
+///
+/// The constraint is decomposed into the linear equality over the membership variables
+/// `\sum members_i - cardinality = 0`.
+pub fn set_card(set: &SetVariable, cardinality: DomainId) -> impl Constraint {
+    let terms = set
+        .members()
+        .iter()
+        .map(|member| member.scaled(1))
+        .chain(std::iter::once(cardinality.scaled(-1)))
+        .collect::<Box<[_]>>();
+
+    equals(terms, 0)
+}
+
+/// Creates the [`Constraint`] `subset \subseteq superset`.
+///
+/// For every element in the universe of `subset`, the membership variable of `subset` is at most
+/// the membership variable of `superset`; elements which are not in the universe of `superset`
+/// cannot be a member of `subset`.
+pub fn set_subset(subset: &SetVariable, superset: &SetVariable) -> impl Constraint {
+    SetSubset {
+        members: subset
+            .iter()
+            .map(|(element, member)| (member, superset.membership(element)))
+            .collect(),
+    }
+}
+
+/// Creates the [`Constraint`] `element \in set`.
+///
+/// The constraint is decomposed into clauses: `element` cannot take a value outside the universe
+/// of `set`, and `[element = v]` implies that `v` is a member of `set`. Since clauses cannot be
+/// tagged, this constraint cannot be posted with a tag.
+pub fn set_member(element: impl IntegerVariable + 'static, set: &SetVariable) -> impl Constraint {
+    SetMember {
+        element,
+        set: set.clone(),
+    }
+}
+
+struct SetSubset {
+    /// The membership variables of the subset, paired with the corresponding membership variable
+    /// of the superset if it exists.
+    members: Vec<(DomainId, Option<DomainId>)>,
+}
+
+impl Constraint for SetSubset {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        for (member, superset_member) in self.members {
+            match superset_member {
+                Some(superset_member) => {
+                    less_than_or_equals([member.scaled(1), superset_member.scaled(-1)], 0)
+                        .post(solver, tag)?
+                }
+                None => less_than_or_equals([member.scaled(1)], 0).post(solver, tag)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        for (member, superset_member) in self.members {
+            match superset_member {
+                Some(superset_member) => {
+                    less_than_or_equals([member.scaled(1), superset_member.scaled(-1)], 0)
+                        .implied_by(solver, reification_literal, tag)?
+                }
+                None => less_than_or_equals([member.scaled(1)], 0).implied_by(
+                    solver,
+                    reification_literal,
+                    tag,
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct SetMember<Var> {
+    element: Var,
+    set: SetVariable,
+}
+
+impl<Var: IntegerVariable> SetMember<Var> {
+    /// Returns the clauses which make up the constraint.
+    fn create_clauses(
+        &self,
+        solver: &mut Solver,
+    ) -> Result<Vec<Vec<Literal>>, ConstraintOperationError> {
+        check_variables_exist(solver, [self.element.flatten().id])?;
+
+        let lower_bound = solver.lower_bound(&self.element);
+        let upper_bound = solver.upper_bound(&self.element);
+
+        let clauses = (lower_bound..=upper_bound)
+            .map(|value| match self.set.membership(value) {
+                // [element = value] -> [member >= 1]
+                Some(member) => vec![
+                    solver.get_literal(predicate![self.element != value]),
+                    solver.get_literal(predicate![member >= 1]),
+                ],
+                None => vec![solver.get_literal(predicate![self.element != value])],
+            })
+            .collect();
+
+        Ok(clauses)
+    }
+}
+
+impl<Var: IntegerVariable> Constraint for SetMember<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        assert!(tag.is_none(), "tagging clauses is not implemented");
+
+        for clause in self.create_clauses(solver)? {
+            solver.add_clause(clause)?;
+        }
+
+        Ok(())
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        assert!(tag.is_none(), "tagging clauses is not implemented");
+
+        for clause in self.create_clauses(solver)? {
+            solver.add_clause(
+                clause
+                    .into_iter()
+                    .chain(std::iter::once(!reification_literal)),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cardinality_propagates_membership() {
+        let mut solver = Solver::default();
+        let set = solver.new_set_variable(&[1, 2, 3]);
+        let cardinality = solver.new_bounded_integer(3, 5);
+
+        let result = solver.add_constraint(set_card(&set, cardinality)).post();
+        assert!(result.is_ok());
+
+        for &member in set.members() {
+            assert_eq!(solver.lower_bound(&member), 1);
+        }
+        assert_eq!(solver.upper_bound(&cardinality), 3);
+    }
+
+    #[test]
+    fn cardinality_excludes_remaining_elements() {
+        let mut solver = Solver::default();
+        let set = solver.new_set_variable(&[1, 2, 3]);
+        let cardinality = solver.new_bounded_integer(0, 1);
+
+        let result = solver
+            .add_constraint(less_than_or_equals(
+                [set.membership(2).unwrap().scaled(-1)],
+                -1,
+            ))
+            .post();
+        assert!(result.is_ok());
+        let result = solver.add_constraint(set_card(&set, cardinality)).post();
+        assert!(result.is_ok());
+
+        assert_eq!(solver.upper_bound(&set.membership(1).unwrap()), 0);
+        assert_eq!(solver.upper_bound(&set.membership(3).unwrap()), 0);
+        assert_eq!(solver.lower_bound(&cardinality), 1);
+    }
+
+    #[test]
+    fn subset_excludes_elements_outside_the_superset() {
+        let mut solver = Solver::default();
+        let subset = solver.new_set_variable(&[1, 2, 3]);
+        let superset = solver.new_set_variable(&[2, 3]);
+
+        let result = solver
+            .add_constraint(less_than_or_equals(
+                [superset.membership(3).unwrap().scaled(1)],
+                0,
+            ))
+            .post();
+        assert!(result.is_ok());
+        let result = solver.add_constraint(set_subset(&subset, &superset)).post();
+        assert!(result.is_ok());
+
+        assert_eq!(solver.upper_bound(&subset.membership(1).unwrap()), 0);
+        assert_eq!(solver.upper_bound(&subset.membership(2).unwrap()), 1);
+        assert_eq!(solver.upper_bound(&subset.membership(3).unwrap()), 0);
+    }
+
+    #[test]
+    fn member_restricts_element_to_the_members_of_the_set() {
+        let mut solver = Solver::default();
+        let set = solver.new_set_variable(&[1, 3, 5]);
+        let element = solver.new_bounded_integer(0, 4);
+
+        let result = solver
+            .add_constraint(less_than_or_equals(
+                [set.membership(1).unwrap().scaled(1)],
+                0,
+            ))
+            .post();
+        assert!(result.is_ok());
+        let result = solver.add_constraint(set_member(element, &set)).post();
+        assert!(result.is_ok());
+
+        assert_eq!(solver.lower_bound(&element), 3);
+        assert_eq!(solver.upper_bound(&element), 3);
+        assert_eq!(solver.lower_bound(&set.membership(3).unwrap()), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "tagging clauses is not implemented")]
+    fn tagged_member_cannot_be_posted() {
+        let mut solver = Solver::default();
+        let set = solver.new_set_variable(&[1, 3, 5]);
+        let element = solver.new_bounded_integer(0, 4);
+
+        let _ = solver
+            .add_constraint(set_member(element, &set))
+            .with_tag(NonZero::new(1).unwrap())
+            .post();
+    }
+}
@@ -24,6 +24,7 @@ use crate::engine::SatisfactionSolverOptions;
 use crate::engine::VariableLiteralMappings;
 use crate::propagators::clausal::ClausalPropagator;
 use crate::pumpkin_assert_moderate;
+use crate::pumpkin_assert_simple;
 
 /// Used during conflict analysis to provide the necessary information.
 /// All fields are made public for the time being for simplicity. In the future that may change.
@@ -182,6 +183,11 @@ impl ConflictAnalysisContext<'_> {
                     propagated: self.assignments_propositional.false_literal,
                 });
 
+                pumpkin_assert_simple!(
+                    explanation_literals.len() >= 2,
+                    "Conflicts explained by fewer than two predicates are handled before conflict analysis."
+                );
+
                 self.explanation_clause_manager
                     .add_explanation_clause_unchecked(explanation_literals, self.clause_allocator)
             }
@@ -458,6 +458,22 @@ impl ResolutionConflictAnalyser {
         self.compute_all_decision_learning_helper(next_literal, true, context, on_analysis_step);
     }
 
+    /// Reports the steps which explain why `propagated_literal` is assigned, in the same way as
+    /// [`ResolutionConflictAnalyser::get_conflict_reasons`] reports the steps of a conflict.
+    pub(crate) fn get_propagation_reasons(
+        &mut self,
+        propagated_literal: Literal,
+        context: &mut ConflictAnalysisContext,
+        on_analysis_step: impl FnMut(AnalysisStep),
+    ) {
+        self.compute_all_decision_learning_helper(
+            Some(propagated_literal),
+            true,
+            context,
+            on_analysis_step,
+        );
+    }
+
     pub(crate) fn compute_clausal_core(
         &mut self,
         context: &mut ConflictAnalysisContext,
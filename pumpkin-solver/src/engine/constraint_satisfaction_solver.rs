@@ -4,11 +4,13 @@
 use std::cmp::min;
 use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::fmt::Write;
 use std::marker::PhantomData;
 use std::num::NonZero;
 use std::time::Instant;
 
 use drcp_format::steps::StepId;
+use log::trace;
 use rand::rngs::SmallRng;
 use rand::SeedableRng;
 
@@ -19,6 +21,7 @@ use super::conflict_analysis::ConflictAnalysisResult;
 use super::conflict_analysis::ResolutionConflictAnalyser;
 use super::propagation::store::PropagatorStore;
 use super::solver_statistics::SolverStatistics;
+use super::termination::interrupt::InterruptHandle;
 use super::termination::TerminationCondition;
 use super::variables::IntegerVariable;
 use crate::basic_types::moving_averages::MovingAverage;
@@ -28,12 +31,16 @@ use crate::basic_types::ConflictInfo;
 use crate::basic_types::ConstraintOperationError;
 use crate::basic_types::ConstraintReference;
 use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
 use crate::basic_types::Inconsistency;
 use crate::basic_types::KeyedVec;
 use crate::basic_types::PropagationStatusOneStepCP;
 use crate::basic_types::Random;
+use crate::basic_types::Solution;
 use crate::basic_types::SolutionReference;
 use crate::basic_types::StoredConflictInfo;
+use crate::basic_types::VariableCreationError;
+use crate::basic_types::ViolatedConstraint;
 use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
 use crate::branching::Brancher;
 use crate::branching::PhaseSaving;
@@ -47,12 +54,16 @@ use crate::engine::cp::WatchListCP;
 use crate::engine::cp::WatchListPropositional;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::proof::ProofLog;
+use crate::engine::propagation::propagation_context::HasAssignments;
 use crate::engine::propagation::EnqueueDecision;
 use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::propagation::PropagatorStatistics;
 use crate::engine::reason::ReasonStore;
+use crate::engine::sat::PERTURBATION_FLIP_PROBABILITY;
 use crate::engine::variables::DomainId;
 use crate::engine::variables::Literal;
 use crate::engine::variables::PropositionalVariable;
@@ -67,7 +78,9 @@ use crate::engine::LearnedClauseManager;
 use crate::engine::LearningOptions;
 use crate::engine::RestartOptions;
 use crate::engine::RestartStrategy;
+use crate::engine::SearchTreeLog;
 use crate::engine::VariableLiteralMappings;
+use crate::predicate;
 use crate::propagators::clausal::BasicClausalPropagator;
 use crate::propagators::clausal::ClausalPropagator;
 use crate::pumpkin_assert_advanced;
@@ -202,6 +215,47 @@ pub struct ConstraintSatisfactionSolver {
     /// A map from clause references to nogood step ids in the proof.
     nogood_step_ids: KeyedVec<ClauseReference, Option<StepId>>,
     unit_nogood_step_ids: HashMap<Literal, StepId>,
+    /// The phases of the propositional variables which correspond to the assignment provided
+    /// through [`ConstraintSatisfactionSolver::warm_start`].
+    warm_start_phases: Vec<(PropositionalVariable, bool)>,
+    /// The initial activities of the propositional variables which correspond to the activities
+    /// provided through [`ConstraintSatisfactionSolver::import_activities`].
+    initial_activities: Vec<(PropositionalVariable, f64)>,
+    /// The literal which is used as the first decision of the next search, provided through
+    /// [`ConstraintSatisfactionSolver::branch_on`].
+    forced_decision: Option<Literal>,
+    /// The number of integer variables when the previous search started, or `None` if no search
+    /// has taken place yet; the variables created since are announced to the brancher through
+    /// [`Brancher::on_new_variable`].
+    num_domains_at_previous_search: Option<u32>,
+    /// Allows the search to be interrupted from outside of the solver; see
+    /// [`ConstraintSatisfactionSolver::interrupt_handle`].
+    interrupt_handle: InterruptHandle,
+    /// The total number of conflicts after which the search is stopped; see
+    /// [`ConstraintSatisfactionSolver::set_conflict_budget`].
+    conflict_limit: Option<u64>,
+    /// The most recent decision and the type name of the brancher which made it, which are logged
+    /// once the decision has been propagated; only used if
+    /// [`SatisfactionSolverOptions::log_decisions`] is enabled.
+    traced_decision: Option<(Predicate, &'static str)>,
+    /// Receives the decision traces; by default they are logged at the trace level.
+    decision_trace_sink: DecisionTraceSink,
+}
+
+/// The function which receives every decision trace when
+/// [`SatisfactionSolverOptions::log_decisions`] is enabled.
+struct DecisionTraceSink(Box<dyn FnMut(&str)>);
+
+impl Default for DecisionTraceSink {
+    fn default() -> Self {
+        DecisionTraceSink(Box::new(|decision_trace| trace!("{decision_trace}")))
+    }
+}
+
+impl Debug for DecisionTraceSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DecisionTraceSink").finish_non_exhaustive()
+    }
 }
 
 impl Default for ConstraintSatisfactionSolver {
@@ -244,6 +298,28 @@ pub struct SatisfactionSolverOptions {
     /// A random generator which is used by the [`Solver`], passing it as an
     /// argument allows seeding of the randomization.
     pub random_generator: SmallRng,
+    /// The maximum number of values in the initial domain of an integer variable; creating a
+    /// variable with a larger domain results in an error. If [`None`], then the size of domains
+    /// is not limited.
+    ///
+    /// Every value in a domain is represented by propositional variables, so this can be used to
+    /// detect variables which are accidentally created with (close to) unbounded domains.
+    pub max_domain_size: Option<u32>,
+    /// Whether every decision is logged at the trace level, together with the brancher which made
+    /// it and the number of propagations which it caused.
+    pub log_decisions: bool,
+    /// The log to which the explored search tree is written, e.g. to visualise the search; see
+    /// [`SearchTreeLog`].
+    pub search_tree_log: SearchTreeLog,
+    /// Whether the descriptions of domains are cached for as long as the trail does not change;
+    /// this avoids recomputing them when the same domain is described repeatedly, e.g. in
+    /// explanation-heavy workloads.
+    pub cache_domain_descriptions: bool,
+    /// If set, the solver backtracks chronologically (i.e. a single decision level) after a
+    /// conflict when the distance to the backjump level of the learned clause exceeds this
+    /// threshold; this preserves more of the trail. The learned clause is still added, and it
+    /// propagates after backtracking. If [`None`], then the solver always backjumps.
+    pub chronological_backtracking_threshold: Option<usize>,
 }
 
 impl Default for SatisfactionSolverOptions {
@@ -253,6 +329,11 @@ impl Default for SatisfactionSolverOptions {
             proof_log: ProofLog::default(),
             learning_clause_minimisation: true,
             random_generator: SmallRng::seed_from_u64(42),
+            max_domain_size: None,
+            log_decisions: false,
+            search_tree_log: SearchTreeLog::default(),
+            cache_domain_descriptions: false,
+            chronological_backtracking_threshold: None,
         }
     }
 }
@@ -409,6 +490,19 @@ impl ConstraintSatisfactionSolver {
             "Proof attempted to be completed while not in conflicting state"
         );
 
+        if let Some((propagator, explanation)) = self.get_short_conflict_explanation() {
+            let _ = self.internal_parameters.proof_log.log_inference(
+                self.cp_propagators.get_tag(propagator),
+                explanation,
+                None,
+            );
+            let _ = self
+                .internal_parameters
+                .proof_log
+                .log_learned_clause(std::iter::empty());
+            return;
+        }
+
         let result = self.compute_learned_clause(&mut DummyBrancher);
         let _ = self
             .internal_parameters
@@ -416,6 +510,26 @@ impl ConstraintSatisfactionSolver {
             .log_learned_clause(result.learned_literals);
     }
 
+    /// Returns the propagator and the literals of the explanation of the current conflict if it
+    /// is explained by fewer than two predicates. Such an explanation cannot be stored as a
+    /// clause, so it is handled before conflict analysis; see
+    /// [`ConstraintSatisfactionSolver::resolve_short_conflict`].
+    fn get_short_conflict_explanation(&self) -> Option<(PropagatorId, Vec<Literal>)> {
+        match self.state.get_conflict_info() {
+            StoredConflictInfo::Explanation {
+                propagator,
+                conjunction,
+            } if conjunction.num_predicates() < 2 => Some((
+                *propagator,
+                conjunction
+                    .iter()
+                    .map(|&predicate| self.get_literal(predicate))
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
+
     // fn debug_check_consistency(&self, cp_data_structures: &CPEngineDataStructures) -> bool {
     // pumpkin_assert_simple!(
     // assignments_integer.num_domains() as usize
@@ -440,12 +554,17 @@ impl ConstraintSatisfactionSolver {
     ) -> ConstraintSatisfactionSolver {
         let dummy_literal = Literal::new(PropositionalVariable::new(0), true);
 
+        let mut assignments_integer = AssignmentsInteger::default();
+        if solver_options.cache_domain_descriptions {
+            assignments_integer.enable_domain_description_cache();
+        }
+
         let mut csp_solver = ConstraintSatisfactionSolver {
             state: CSPSolverState::default(),
             assumptions: Vec::default(),
             assignments_propositional: AssignmentsPropositional::default(),
             clause_allocator: ClauseAllocator::default(),
-            assignments_integer: AssignmentsInteger::default(),
+            assignments_integer,
             watch_list_cp: WatchListCP::default(),
             watch_list_propositional: WatchListPropositional::default(),
             propagator_queue: PropagatorQueue::new(5),
@@ -471,6 +590,14 @@ impl ConstraintSatisfactionSolver {
             variable_names: VariableNames::default(),
             nogood_step_ids: KeyedVec::default(),
             unit_nogood_step_ids: HashMap::default(),
+            warm_start_phases: Vec::default(),
+            initial_activities: Vec::default(),
+            forced_decision: None,
+            num_domains_at_previous_search: None,
+            interrupt_handle: InterruptHandle::default(),
+            conflict_limit: None,
+            traced_decision: None,
+            decision_trace_sink: DecisionTraceSink::default(),
         };
 
         // we introduce a dummy variable set to true at the root level
@@ -512,8 +639,15 @@ impl ConstraintSatisfactionSolver {
 
         let start_time = Instant::now();
 
+        // An interrupt only applies to the search which was ongoing when it was requested
+        self.interrupt_handle.reset();
         self.initialise(assumptions);
+        self.notify_brancher_of_new_variables(brancher);
+        self.internal_parameters.search_tree_log.start_search();
         let result = self.solve_internal(termination, brancher);
+        self.internal_parameters.search_tree_log.finish_search();
+        // The forced decision only applies to a single search, even if it was never used
+        self.forced_decision = None;
 
         self.counters.engine_statistics.time_spent_in_solver +=
             start_time.elapsed().as_millis() as u64;
@@ -528,25 +662,354 @@ impl ConstraintSatisfactionSolver {
             .get_propositional_variables()
             .collect::<Vec<_>>();
 
+        let variable_selector = if self.initial_activities.is_empty() {
+            Vsids::new(&variables)
+        } else {
+            let initial_activities = self
+                .initial_activities
+                .iter()
+                .copied()
+                .collect::<HashMap<_, _>>();
+            let initial_values = variables
+                .iter()
+                .map(|variable| {
+                    initial_activities
+                        .get(variable)
+                        .copied()
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            Vsids::with_initial_values(&variables, &initial_values)
+        };
+
         IndependentVariableValueBrancher {
-            variable_selector: Vsids::new(&variables),
+            variable_selector,
             value_selector: SolutionGuidedValueSelector::new(
                 &variables,
-                Vec::new(),
+                self.warm_start_phases.clone(),
                 PhaseSaving::new(&variables),
             ),
             variable_type: PhantomData,
         }
     }
 
+    /// Provides an assignment of integer variables which the search should try first. The
+    /// assignment does not have to be complete and no constraints are posted; it only sets the
+    /// phases of the propositional variables representing the provided integer variables.
+    ///
+    /// The phases are used as initial values by the brancher created through
+    /// [`ConstraintSatisfactionSolver::default_brancher_over_all_propositional_variables`], so
+    /// this method should be called before the brancher is created. Any previously provided
+    /// assignment is replaced.
+    pub fn warm_start(&mut self, assignment: &[(DomainId, i32)]) {
+        self.warm_start_phases.clear();
+
+        for &(domain, value) in assignment {
+            let initial_lower_bound = self.assignments_integer.get_initial_lower_bound(domain);
+
+            let lower_bound_literals = self
+                .variable_literal_mappings
+                .domain_to_lower_bound_literals[domain]
+                .iter()
+                .enumerate()
+                .map(|(index, &literal)| (literal, value >= initial_lower_bound + index as i32));
+            let equality_literals = self.variable_literal_mappings.domain_to_equality_literals
+                [domain]
+                .iter()
+                .enumerate()
+                .map(|(index, &literal)| (literal, value == initial_lower_bound + index as i32));
+
+            self.warm_start_phases.extend(
+                lower_bound_literals
+                    .chain(equality_literals)
+                    .filter(|&(literal, _)| {
+                        literal != self.true_literal && literal != self.false_literal
+                    })
+                    .map(|(literal, is_true)| {
+                        (
+                            literal.get_propositional_variable(),
+                            is_true == literal.is_positive(),
+                        )
+                    }),
+            );
+        }
+
+        // Some literals represent multiple predicates (e.g. `[x == lb]` and `[x <= lb]`), these
+        // are only stored once.
+        self.warm_start_phases
+            .sort_unstable_by_key(|&(variable, _)| variable.get_index());
+        self.warm_start_phases
+            .dedup_by_key(|&mut (variable, _)| variable);
+    }
+
+    /// Returns the activity of every integer variable according to the [`Vsids`] variable
+    /// selector of `brancher`, which is the maximum activity of the propositional variables that
+    /// represent its domain; these can be provided to
+    /// [`ConstraintSatisfactionSolver::import_activities`] for a subsequent search.
+    pub fn export_activities(&self, brancher: &DefaultBrancher) -> Vec<(DomainId, f64)> {
+        self.assignments_integer
+            .get_domains()
+            .map(|domain| {
+                let activity = self
+                    .get_domain_propositional_variables(domain)
+                    .map(|variable| brancher.variable_selector.activity(variable))
+                    .fold(0.0, f64::max);
+                (domain, activity)
+            })
+            .collect()
+    }
+
+    /// Provides the activities of integer variables which are used to initialise the [`Vsids`]
+    /// variable selector of the brancher created through
+    /// [`ConstraintSatisfactionSolver::default_brancher_over_all_propositional_variables`], e.g.
+    /// as exported from a previous search on a similar model. Every propositional variable which
+    /// represents the domain of an integer variable is given its activity.
+    ///
+    /// This method should be called before the brancher is created. Any previously provided
+    /// activities are replaced.
+    pub fn import_activities(&mut self, activities: &[(DomainId, f64)]) {
+        self.initial_activities = activities
+            .iter()
+            .flat_map(|&(domain, activity)| {
+                self.get_domain_propositional_variables(domain)
+                    .map(move |variable| (variable, activity))
+            })
+            .collect();
+    }
+
+    /// Returns the propositional variables of the literals which represent the lower bound and
+    /// equality predicates over the provided domain.
+    fn get_domain_propositional_variables(
+        &self,
+        domain: DomainId,
+    ) -> impl Iterator<Item = PropositionalVariable> + '_ {
+        self.variable_literal_mappings
+            .domain_to_lower_bound_literals[domain]
+            .iter()
+            .chain(self.variable_literal_mappings.domain_to_equality_literals[domain].iter())
+            .filter(|&&literal| literal != self.true_literal && literal != self.false_literal)
+            .map(|literal| literal.get_propositional_variable())
+    }
+
+    /// Makes the provided predicate the first decision of the next search, after which the
+    /// brancher takes over. Unlike an assumption, the decision is undone by backtracking as any
+    /// other decision, and the search may thus find solutions in which the predicate does not
+    /// hold.
+    ///
+    /// If the predicate is already assigned when the first decision is made, then it is ignored.
+    pub fn branch_on(&mut self, predicate: Predicate) {
+        self.forced_decision = Some(self.get_literal(predicate));
+    }
+
+    /// Requests a restart at the first opportunity, regardless of the conditions of the restart
+    /// strategy; see [`RestartStrategy::force_restart`].
+    pub fn force_restart(&mut self) {
+        self.restart_strategy.force_restart()
+    }
+
+    /// Returns a handle which can be used to interrupt the search of this solver, for example from
+    /// another thread. See [`InterruptHandle`] for more information.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.interrupt_handle.clone()
+    }
+
+    /// Limits the number of conflicts which the solver may encounter, counted from now on, before
+    /// the search stops as if the termination condition was triggered. If [`None`] is provided,
+    /// then the number of conflicts is not limited.
+    pub(crate) fn set_conflict_budget(&mut self, conflict_budget: Option<u64>) {
+        self.conflict_limit =
+            conflict_budget.map(|budget| self.counters.engine_statistics.num_conflicts + budget);
+    }
+
+    fn is_conflict_budget_exhausted(&self) -> bool {
+        self.conflict_limit
+            .is_some_and(|limit| self.counters.engine_statistics.num_conflicts >= limit)
+    }
+
     pub fn get_state(&self) -> &CSPSolverState {
         &self.state
     }
 
+    pub(crate) fn variable_names(&self) -> &VariableNames {
+        &self.variable_names
+    }
+
     pub fn get_random_generator(&mut self) -> &mut impl Random {
         &mut self.internal_parameters.random_generator
     }
 
+    /// Returns an iterator over the literals of the learned clauses which are currently stored in
+    /// the learned clause database; clauses which have been marked as deleted are skipped.
+    ///
+    /// Note that learned unit clauses are not stored as clauses but are assigned at the root
+    /// level, so these are not returned.
+    pub fn learned_clauses(&self) -> impl Iterator<Item = &[Literal]> + '_ {
+        self.learned_clause_manager
+            .learned_clause_references()
+            .map(|clause_reference| &self.clause_allocator[clause_reference])
+            .filter(|clause| !clause.is_deleted())
+            .map(|clause| clause.get_literal_slice())
+    }
+
+    /// Writes the current model in the DIMACS CNF format to `writer`. The model consists of the
+    /// root-level assignments (as unit clauses) and the permanent clauses; learned clauses are not
+    /// exported. A literal is written as the index of its [`PropositionalVariable`].
+    ///
+    /// If the model contains propagators then it cannot be expressed as CNF, in which case an
+    /// error of kind [`std::io::ErrorKind::InvalidInput`] is returned and nothing is written.
+    pub fn export_dimacs(&self, mut writer: impl std::io::Write) -> std::io::Result<()> {
+        pumpkin_assert_simple!(self.get_decision_level() == 0);
+
+        if self.cp_propagators.iter_propagators().next().is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the model contains propagators which cannot be expressed as clauses",
+            ));
+        }
+
+        let (units, clauses) = self.clausal_model();
+
+        writeln!(
+            writer,
+            "p cnf {} {}",
+            self.assignments_propositional.num_propositional_variables() - 1,
+            units.len() + clauses.len()
+        )?;
+
+        for clause in units.iter().map(std::slice::from_ref).chain(clauses) {
+            for literal in clause {
+                let prefix = if literal.is_negative() { "-" } else { "" };
+                write!(
+                    writer,
+                    "{prefix}{} ",
+                    literal.get_propositional_variable().get_index()
+                )?;
+            }
+            writeln!(writer, "0")?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the current model in the OPB (pseudo-Boolean) format to `writer`. The model
+    /// consists of the clausal part which is exported by
+    /// [`ConstraintSatisfactionSolver::export_dimacs`] and of `linear_inequalities` and
+    /// `linear_equalities`, where every inequality `\sum a_i * x_i <= c` (or equality
+    /// `\sum a_i * x_i = c`) is described by the pairs `(x_i, a_i)` and `c`. A variable `x<i>` is
+    /// the [`PropositionalVariable`] with index `i`, and a 0/1 domain `x_i` is written as the
+    /// variable of the literal `[x_i >= 1]`.
+    ///
+    /// The caller should ensure that `linear_inequalities` and `linear_equalities` describe all
+    /// propagators of the solver. If a constraint contains a domain which is not 0/1, then an error
+    /// of kind [`std::io::ErrorKind::InvalidInput`] is returned and nothing is written.
+    pub(crate) fn export_opb(
+        &self,
+        linear_inequalities: &[(&[(DomainId, i64)], i64)],
+        linear_equalities: &[(&[(DomainId, i64)], i64)],
+        mut writer: impl std::io::Write,
+    ) -> std::io::Result<()> {
+        pumpkin_assert_simple!(self.get_decision_level() == 0);
+
+        let (units, clauses) = self.clausal_model();
+
+        // Every constraint is gathered as `\sum a_i * l_i >= d` (or `= d`) before anything is
+        // written, such that nothing is written if the model cannot be expressed.
+        let mut constraints = units
+            .iter()
+            .map(std::slice::from_ref)
+            .chain(clauses)
+            .map(|clause| {
+                (
+                    clause.iter().map(|&literal| (literal, 1)).collect(),
+                    ">=",
+                    1,
+                )
+            })
+            .collect::<Vec<(Vec<(Literal, i64)>, &str, i64)>>();
+        let linear_constraints = linear_inequalities
+            .iter()
+            .map(|&constraint| (constraint, ">="))
+            .chain(
+                linear_equalities
+                    .iter()
+                    .map(|&constraint| (constraint, "=")),
+            );
+        for ((terms, rhs), relation) in linear_constraints {
+            let mut degree = -rhs;
+            let mut pseudo_boolean_terms = vec![];
+            for &(domain, coefficient) in terms {
+                let lower_bound = self.assignments_integer.get_lower_bound(domain);
+                let upper_bound = self.assignments_integer.get_upper_bound(domain);
+                if lower_bound == upper_bound {
+                    degree += coefficient * lower_bound as i64;
+                } else if lower_bound == 0 && upper_bound == 1 {
+                    pseudo_boolean_terms
+                        .push((self.get_literal(predicate![domain >= 1]), -coefficient));
+                } else {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "the linear constraints contain the domain {domain} which is not 0/1"
+                        ),
+                    ));
+                }
+            }
+            constraints.push((pseudo_boolean_terms, relation, degree));
+        }
+
+        writeln!(
+            writer,
+            "* #variable= {} #constraint= {}",
+            self.assignments_propositional.num_propositional_variables() - 1,
+            constraints.len()
+        )?;
+
+        for (terms, relation, mut degree) in constraints {
+            for (literal, coefficient) in terms {
+                // A negative literal `!x` is written as `1 - x`.
+                let coefficient = if literal.is_negative() {
+                    degree -= coefficient;
+                    -coefficient
+                } else {
+                    coefficient
+                };
+                write!(
+                    writer,
+                    "{coefficient:+} x{} ",
+                    literal.get_propositional_variable().get_index()
+                )?;
+            }
+            writeln!(writer, "{relation} {degree} ;")?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the root-level assignments and the literals of the permanent clauses, which
+    /// together form the clausal part of the model.
+    fn clausal_model(&self) -> (Vec<Literal>, Vec<&[Literal]>) {
+        // The variable of the true literal has index 0, which cannot be written in DIMACS; it
+        // does not occur in any clause since clauses satisfied at the root are not stored.
+        let units = (0..self.assignments_propositional.num_trail_entries())
+            .map(|index| self.assignments_propositional.get_trail_entry(index))
+            .filter(|&literal| literal != self.true_literal)
+            .collect::<Vec<_>>();
+        let clauses = self
+            .clausal_propagator
+            .permanent_clauses
+            .iter()
+            .map(|&clause_reference| &self.clause_allocator[clause_reference])
+            .filter(|clause| !clause.is_learned() && !clause.is_deleted())
+            .map(|clause| clause.get_literal_slice())
+            .collect::<Vec<_>>();
+
+        (units, clauses)
+    }
+
+    pub(crate) fn num_propagators(&self) -> usize {
+        self.cp_propagators.iter_propagators().count()
+    }
+
     pub fn log_statistics(&self) {
         // We first check whether the statistics will/should be logged to prevent unnecessarily
         // going through all the propagators
@@ -562,18 +1025,86 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
+    /// Creates a diagnostic snapshot of the solver, containing the decision level, the sizes of the
+    /// trails, the number of propagators, the state (including the conflict, if any) and the
+    /// statistics.
+    pub fn dump_state(&self) -> String {
+        let mut dump = String::new();
+
+        // Writing to a `String` cannot fail
+        let _ = writeln!(dump, "decision level: {}", self.get_decision_level());
+        let _ = writeln!(
+            dump,
+            "trail: {} propositional entries, {} integer entries, {} assumptions",
+            self.assignments_propositional.num_trail_entries(),
+            self.assignments_integer.num_trail_entries(),
+            self.assumptions.len()
+        );
+        let _ = writeln!(
+            dump,
+            "propagators: {}",
+            self.cp_propagators.iter_propagators().count()
+        );
+        let _ = writeln!(dump, "state: {:?}", self.state.internal_state);
+        let _ = writeln!(dump, "statistics: {:?}", self.counters);
+
+        dump
+    }
+
+    /// Resets the statistics which are gathered while solving (e.g. the number of conflicts), such
+    /// that later statistics only describe later calls to the solver. The constraints, the learned
+    /// clauses, and the domains are not affected.
+    pub fn reset_statistics(&mut self) {
+        self.counters = SolverStatistics::default();
+        self.cp_propagators.reset_statistics();
+    }
+
+    /// Returns the name and the [`PropagatorStatistics`] of every propagator.
+    pub(crate) fn propagator_statistics(
+        &self,
+    ) -> impl Iterator<Item = (&str, PropagatorStatistics)> + '_ {
+        self.cp_propagators.iter_statistics()
+    }
+
     /// Create a new integer variable. Its domain will have the given lower and upper bounds.
+    ///
+    /// # Panics
+    /// If the domain contains more values than allowed by
+    /// [`SatisfactionSolverOptions::max_domain_size`]; see
+    /// [`ConstraintSatisfactionSolver::try_create_new_integer_variable`] for a fallible version.
     pub fn create_new_integer_variable(
         &mut self,
         lower_bound: i32,
         upper_bound: i32,
         name: Option<String>,
     ) -> DomainId {
+        self.try_create_new_integer_variable(lower_bound, upper_bound, name)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Create a new integer variable with the given bounds, or returns an error if the domain
+    /// contains more values than allowed by [`SatisfactionSolverOptions::max_domain_size`].
+    pub fn try_create_new_integer_variable(
+        &mut self,
+        lower_bound: i32,
+        upper_bound: i32,
+        name: Option<String>,
+    ) -> Result<DomainId, VariableCreationError> {
         assert!(
             !self.state.is_inconsistent(),
             "Variables cannot be created in an inconsistent state"
         );
 
+        if let Some(max_domain_size) = self.internal_parameters.max_domain_size {
+            let size = (upper_bound as i64 - lower_bound as i64 + 1).max(0) as u64;
+            if size > max_domain_size as u64 {
+                return Err(VariableCreationError::DomainTooLarge {
+                    size,
+                    max_domain_size,
+                });
+            }
+        }
+
         let domain = self.variable_literal_mappings.create_new_domain(
             lower_bound,
             upper_bound,
@@ -589,7 +1120,7 @@ impl ConstraintSatisfactionSolver {
             self.variable_names.add_integer(domain, name);
         }
 
-        domain
+        Ok(domain)
     }
 
     /// Creates an integer variable with a domain containing only the values in `values`
@@ -740,8 +1271,42 @@ impl ConstraintSatisfactionSolver {
     pub(crate) fn get_conflict_reasons(
         &mut self,
         brancher: &mut impl Brancher,
-        on_analysis_step: impl FnMut(AnalysisStep),
+        mut on_analysis_step: impl FnMut(AnalysisStep),
     ) {
+        // A conflict which is explained by fewer than two predicates is not stored as a clause, so
+        // its explanation is reported here and the analysis continues from the propagated literal
+        // of the explanation, if there is one.
+        let mut propagated_literal = None;
+        if self.state.conflicting() {
+            if let Some((propagator, explanation)) = self.get_short_conflict_explanation() {
+                let conjunction: Vec<Literal> =
+                    explanation.iter().map(|&literal| !literal).collect();
+                on_analysis_step(AnalysisStep::Propagation {
+                    propagator,
+                    conjunction: &conjunction,
+                    propagated: self.assignments_propositional.false_literal,
+                });
+
+                for literal in explanation {
+                    if !self
+                        .assignments_propositional
+                        .is_literal_root_assignment(literal)
+                        && self
+                            .assignments_propositional
+                            .is_literal_propagated(literal)
+                    {
+                        propagated_literal = Some(literal);
+                    } else {
+                        on_analysis_step(AnalysisStep::Unit(!literal));
+                    }
+                }
+
+                if propagated_literal.is_none() {
+                    return;
+                }
+            }
+        }
+
         let mut conflict_analysis_context = ConflictAnalysisContext {
             propagator_store: &self.cp_propagators,
             assumptions: &self.assumptions,
@@ -760,8 +1325,197 @@ impl ConstraintSatisfactionSolver {
             nogood_step_ids: &self.nogood_step_ids,
         };
 
-        self.conflict_analyser
-            .get_conflict_reasons(&mut conflict_analysis_context, on_analysis_step);
+        if let Some(propagated_literal) = propagated_literal {
+            self.conflict_analyser.get_propagation_reasons(
+                propagated_literal,
+                &mut conflict_analysis_context,
+                on_analysis_step,
+            );
+        } else {
+            self.conflict_analyser
+                .get_conflict_reasons(&mut conflict_analysis_context, on_analysis_step);
+        }
+    }
+
+    /// Checks whether `solution` satisfies the constraints in the solver and returns the
+    /// constraints which are violated.
+    ///
+    /// The permanent clauses, including the unit clauses which were assigned at the root rather
+    /// than stored, are evaluated directly, while the constraints of the propagators are checked
+    /// by letting each propagator propagate from scratch on the assignment of `solution`; a
+    /// propagator which reports a conflict is considered to be violated.
+    pub fn verify_solution(&self, solution: &Solution) -> Vec<ViolatedConstraint> {
+        let unit_clauses = self
+            .clausal_propagator
+            .permanent_unit_clauses
+            .iter()
+            .map(std::slice::from_ref);
+        let violated_clauses = self
+            .clausal_propagator
+            .permanent_clauses
+            .iter()
+            .map(|&clause_reference| &self.clause_allocator[clause_reference])
+            .filter(|clause| !clause.is_learned() && !clause.is_deleted())
+            .map(|clause| clause.get_literal_slice())
+            .chain(unit_clauses)
+            .filter(|literals| {
+                !literals.iter().any(|&literal| {
+                    solution
+                        .assignments_propositional()
+                        .is_literal_assigned_true(literal)
+                })
+            })
+            .map(|literals| ViolatedConstraint::Clause(literals.to_vec()));
+
+        let violated_propagators = self
+            .cp_propagators
+            .iter_propagators()
+            .enumerate()
+            .filter_map(|(index, propagator)| {
+                let propagator_id = PropagatorId(index as u32);
+
+                let mut assignments_integer = solution.assignments_integer().clone();
+                let mut assignments_propositional = solution.assignments_propositional().clone();
+                let mut reason_store = ReasonStore::default();
+                let context = PropagationContextMut::new(
+                    &mut assignments_integer,
+                    &mut reason_store,
+                    &mut assignments_propositional,
+                    propagator_id,
+                );
+
+                propagator
+                    .debug_propagate_from_scratch(context)
+                    .is_err()
+                    .then(|| ViolatedConstraint::Propagator {
+                        name: propagator.name().to_owned(),
+                        tag: self.cp_propagators.get_tag(propagator_id),
+                    })
+            });
+
+        violated_clauses.chain(violated_propagators).collect()
+    }
+
+    /// Returns whether `solution` assigns every variable of the solver, i.e. whether no
+    /// variables have been created since `solution` was found. Only such solutions can be
+    /// checked by [`ConstraintSatisfactionSolver::verify_solution`].
+    pub(crate) fn assigns_all_variables(&self, solution: &Solution) -> bool {
+        solution
+            .assignments_propositional()
+            .num_propositional_variables()
+            == self.assignments_propositional.num_propositional_variables()
+            && solution.assignments_integer().num_domains()
+                == self.assignments_integer.num_domains()
+    }
+
+    /// Decides every assumption at its own decision level and propagates it, as is done at the
+    /// start of a search under `assumptions`, but without making any other decisions. Returns
+    /// `false` if an assumption is falsified or the propagation leads to a conflict.
+    ///
+    /// The solver should be restored to the root afterwards (see
+    /// [`ConstraintSatisfactionSolver::restore_state_at_root`]).
+    pub(crate) fn propagate_assumptions(&mut self, assumptions: &[Literal]) -> bool {
+        self.initialise(assumptions);
+
+        for &assumption in assumptions {
+            self.declare_new_decision_level();
+            if !self.enqueue_assumption_literal(assumption) {
+                return false;
+            }
+
+            self.propagate_enqueued();
+            if self.state.conflicting() {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the decisions which are responsible for `predicate` being true in the current
+    /// assignment; this is mainly intended for debugging explanations.
+    ///
+    /// Starting from the [`Literal`] of `predicate`, the reasons of the propagated literals are
+    /// followed (using the reason clauses and the explanations of the propagators) until only
+    /// decisions remain. Root-level assignments are not part of the result, so if `predicate` is
+    /// true at the root then the result is empty. The decisions are returned as the
+    /// [`Predicate`]s they represent (or as [`Predicate::Literal`] if they do not represent an
+    /// integer predicate).
+    ///
+    /// Note that reasons are only available for the current assignment; `predicate` should be
+    /// true in the current state of the solver.
+    pub fn explain_predicate(&mut self, predicate: Predicate) -> Vec<Predicate> {
+        let literal = self.get_literal(predicate);
+        pumpkin_assert_simple!(
+            self.assignments_propositional
+                .is_literal_assigned_true(literal),
+            "Only predicates which are true can be explained"
+        );
+
+        let mut decisions = vec![];
+        let mut seen: HashSet<PropositionalVariable> = HashSet::default();
+        let mut to_explain = vec![literal];
+
+        while let Some(literal) = to_explain.pop() {
+            if !seen.insert(literal.get_propositional_variable())
+                || self
+                    .assignments_propositional
+                    .is_literal_root_assignment(literal)
+            {
+                continue;
+            }
+
+            if self.assignments_propositional.is_literal_decision(literal) {
+                decisions.push(
+                    self.variable_literal_mappings
+                        .get_predicates(literal)
+                        .next()
+                        .map_or(Predicate::Literal(literal), Predicate::IntegerPredicate),
+                );
+                continue;
+            }
+
+            let constraint_reference = self
+                .assignments_propositional
+                .get_literal_reason_constraint(literal);
+
+            if constraint_reference.is_clause() {
+                let clause_reference = self
+                    .clausal_propagator
+                    .get_literal_propagation_clause_reference(
+                        literal,
+                        &self.assignments_propositional,
+                        &mut self.clause_allocator,
+                        &mut self.explanation_clause_manager,
+                    );
+                // The propagated literal is stored at the zero-th position of the clause, the
+                // other literals are false and their negations form the reason
+                to_explain.extend(
+                    self.clause_allocator[clause_reference].get_literal_slice()[1..]
+                        .iter()
+                        .map(|&reason_literal| !reason_literal),
+                );
+            } else {
+                let context = PropagationContext::new(
+                    &self.assignments_integer,
+                    &self.assignments_propositional,
+                );
+                let reason = self
+                    .reason_store
+                    .get_or_compute(constraint_reference.get_reason_ref(), context)
+                    .expect("reason reference should not be stale")
+                    .iter()
+                    .copied()
+                    .collect::<Vec<_>>();
+                to_explain.extend(
+                    reason
+                        .into_iter()
+                        .map(|predicate| self.get_literal(predicate)),
+                );
+            }
+        }
+
+        decisions
     }
 
     /// Returns an infinite iterator of positive literals of new variables. The new variables will
@@ -817,6 +1571,20 @@ impl ConstraintSatisfactionSolver {
         variable.contains(&self.assignments_integer, value)
     }
 
+    /// Determine whether the domain which `variable` is defined over was created by this solver.
+    pub fn integer_variable_exists(&self, variable: &impl IntegerVariable) -> bool {
+        let (domain, _, _) = variable.decompose();
+        domain.id < self.assignments_integer.num_domains()
+    }
+
+    /// Determine whether both bounds of `variable` can be represented by an `i32`.
+    pub fn integer_variable_has_representable_bounds(
+        &self,
+        variable: &impl IntegerVariable,
+    ) -> bool {
+        variable.has_representable_bounds(&self.assignments_integer)
+    }
+
     /// Get the assigned integer for the given variable. If it is not assigned, `None` is returned.
     pub fn get_assigned_integer_value(&self, variable: &impl IntegerVariable) -> Option<i32> {
         let lb = self.get_lower_bound(variable);
@@ -850,6 +1618,10 @@ impl ConstraintSatisfactionSolver {
         if !self.assignments_propositional.is_at_the_root_level() {
             self.backtrack(0, brancher);
             self.state.declare_ready();
+        } else if self.state.timeout() {
+            // The search can be stopped before any decision is made, in which case there is
+            // nothing to backtrack, but the solver should still be ready to solve again.
+            self.state.declare_ready();
         }
     }
 
@@ -985,13 +1757,29 @@ impl ConstraintSatisfactionSolver {
         assumptions.clone_into(&mut self.assumptions);
     }
 
+    /// Informs the brancher of the integer variables which have been created since the previous
+    /// search started.
+    fn notify_brancher_of_new_variables(&mut self, brancher: &mut impl Brancher) {
+        let num_domains = self.assignments_integer.num_domains();
+
+        if let Some(num_domains_at_previous_search) = self.num_domains_at_previous_search {
+            (num_domains_at_previous_search..num_domains)
+                .for_each(|id| brancher.on_new_variable(DomainId::new(id)));
+        }
+
+        self.num_domains_at_previous_search = Some(num_domains);
+    }
+
     fn solve_internal(
         &mut self,
         termination: &mut impl TerminationCondition,
         brancher: &mut impl Brancher,
     ) -> CSPSolverExecutionFlag {
         loop {
-            if termination.should_stop() {
+            if termination.should_stop()
+                || self.interrupt_handle.is_interrupted()
+                || self.is_conflict_budget_exhausted()
+            {
                 self.state.declare_timeout();
                 return CSPSolverExecutionFlag::Timeout;
             }
@@ -1003,8 +1791,28 @@ impl ConstraintSatisfactionSolver {
                     &mut self.clausal_propagator,
                 );
 
+            let num_trail_entries_before_propagation =
+                self.assignments_propositional.num_trail_entries();
+
             self.propagate_enqueued();
 
+            if self.state.conflicting() {
+                self.internal_parameters.search_tree_log.conflict();
+            }
+
+            if let Some((decision, brancher_name)) = self.traced_decision.take() {
+                (self.decision_trace_sink.0)(&format!(
+                    "Decision {decision:?} made by {brancher_name} led to {} propagations{}",
+                    self.assignments_propositional.num_trail_entries()
+                        - num_trail_entries_before_propagation,
+                    if self.state.conflicting() {
+                        " and a conflict"
+                    } else {
+                        ""
+                    }
+                ));
+            }
+
             if self.state.no_conflict() {
                 self.declare_new_decision_level();
 
@@ -1017,6 +1825,10 @@ impl ConstraintSatisfactionSolver {
                     self.restart_during_search(brancher);
                 }
 
+                if self.restart_strategy.should_perturb() {
+                    self.perturb_during_search(brancher);
+                }
+
                 let branching_result = self.enqueue_next_decision(brancher);
                 if let Err(flag) = branching_result {
                     return flag;
@@ -1038,7 +1850,16 @@ impl ConstraintSatisfactionSolver {
                     return CSPSolverExecutionFlag::Infeasible;
                 }
 
-                self.resolve_conflict(brancher);
+                if let Some((propagator, explanation)) = self.get_short_conflict_explanation() {
+                    if self.resolve_short_conflict(propagator, explanation, brancher) {
+                        return CSPSolverExecutionFlag::Infeasible;
+                    }
+                } else {
+                    self.resolve_conflict(brancher);
+                }
+                self.internal_parameters
+                    .search_tree_log
+                    .propagate_learned_clause();
 
                 self.learned_clause_manager.decay_clause_activities();
 
@@ -1056,6 +1877,20 @@ impl ConstraintSatisfactionSolver {
             if !success {
                 return Err(CSPSolverExecutionFlag::Infeasible);
             }
+            self.internal_parameters
+                .search_tree_log
+                .decide(assumption_literal);
+            Ok(())
+        } else if let Some(forced_literal) = self.forced_decision.take().filter(|&literal| {
+            self.assignments_propositional
+                .is_literal_unassigned(literal)
+        }) {
+            self.counters.engine_statistics.num_decisions += 1;
+            self.internal_parameters
+                .search_tree_log
+                .decide(forced_literal);
+            self.assignments_propositional
+                .enqueue_decision_literal(forced_literal);
             Ok(())
         } else {
             let decided_predicate = brancher.next_decision(&mut SelectionContext::new(
@@ -1065,6 +1900,10 @@ impl ConstraintSatisfactionSolver {
             ));
             if let Some(predicate) = decided_predicate {
                 self.counters.engine_statistics.num_decisions += 1;
+                if self.internal_parameters.log_decisions {
+                    self.traced_decision = Some((predicate, std::any::type_name_of_val(brancher)));
+                }
+                self.internal_parameters.search_tree_log.decide(predicate);
                 self.assignments_propositional
                     .enqueue_decision_literal(match predicate {
                         Predicate::IntegerPredicate(integer_predicate) => {
@@ -1082,6 +1921,7 @@ impl ConstraintSatisfactionSolver {
                     });
                 Ok(())
             } else {
+                self.internal_parameters.search_tree_log.solution();
                 self.state.declare_solution_found();
                 Err(CSPSolverExecutionFlag::Feasible)
             }
@@ -1146,6 +1986,54 @@ impl ConstraintSatisfactionSolver {
         self.state.declare_solving();
     }
 
+    /// Resolves a conflict which is explained by fewer than two predicates. The negation of such
+    /// an explanation holds at the root: if the explanation contains a literal which is not
+    /// assigned at the root, its negation is learned as a unit clause, and otherwise the problem
+    /// is infeasible.
+    ///
+    /// Returns true if the problem is found to be infeasible.
+    fn resolve_short_conflict(
+        &mut self,
+        propagator: PropagatorId,
+        explanation: Vec<Literal>,
+        brancher: &mut impl Brancher,
+    ) -> bool {
+        pumpkin_assert_moderate!(self.state.conflicting());
+
+        let _ = self.internal_parameters.proof_log.log_inference(
+            self.cp_propagators.get_tag(propagator),
+            explanation.iter().copied(),
+            None,
+        );
+
+        let non_root_literal = explanation.into_iter().find(|&literal| {
+            !self
+                .assignments_propositional
+                .is_literal_root_assignment(literal)
+        });
+
+        if let Some(literal) = non_root_literal {
+            self.analysis_result = ConflictAnalysisResult {
+                learned_literals: vec![!literal],
+                backjump_level: 0,
+            };
+            self.process_learned_clause(brancher);
+            self.state.declare_solving();
+
+            false
+        } else {
+            if self.assumptions.is_empty() {
+                let _ = self
+                    .internal_parameters
+                    .proof_log
+                    .log_learned_clause(std::iter::empty());
+            }
+            self.state.declare_infeasible();
+
+            true
+        }
+    }
+
     fn compute_learned_clause(&mut self, brancher: &mut impl Brancher) -> ConflictAnalysisResult {
         let mut conflict_analysis_context = ConflictAnalysisContext {
             propagator_store: &self.cp_propagators,
@@ -1204,11 +2092,12 @@ impl ConstraintSatisfactionSolver {
             let num_variables_assigned_before_conflict =
                 &self.assignments_propositional.num_trail_entries();
 
+            let backtrack_level = self.compute_backtrack_level();
             self.counters
                 .learned_clause_statistics
                 .average_backtrack_amount
-                .add_term((self.get_decision_level() - self.analysis_result.backjump_level) as u64);
-            self.backtrack(self.analysis_result.backjump_level, brancher);
+                .add_term((self.get_decision_level() - backtrack_level) as u64);
+            self.backtrack(backtrack_level, brancher);
 
             let clause_reference = self.learned_clause_manager.add_learned_clause(
                 self.analysis_result.learned_literals.clone(), // todo not ideal with clone
@@ -1220,6 +2109,11 @@ impl ConstraintSatisfactionSolver {
             self.nogood_step_ids.accomodate(clause_reference, None);
             self.nogood_step_ids[clause_reference] = Some(proof_step_id);
 
+            if backtrack_level > self.analysis_result.backjump_level {
+                self.clausal_propagator
+                    .add_misplaced_assertion(clause_reference);
+            }
+
             let lbd = self.learned_clause_manager.compute_lbd_for_literals(
                 &self.analysis_result.learned_literals,
                 &self.assignments_propositional,
@@ -1229,6 +2123,27 @@ impl ConstraintSatisfactionSolver {
                 .notify_conflict(lbd, *num_variables_assigned_before_conflict);
         }
     }
+
+    /// Returns the decision level to backtrack to after learning a clause which is asserting at
+    /// [`ConflictAnalysisResult::backjump_level`]; see
+    /// [`SatisfactionSolverOptions::chronological_backtracking_threshold`].
+    ///
+    /// When backtracking chronologically, the literals of the learned clause other than the
+    /// asserting literal are all assigned at or below the backjump level, so the learned clause
+    /// still propagates after backtracking.
+    fn compute_backtrack_level(&self) -> usize {
+        let decision_level = self.get_decision_level();
+        let backjump_level = self.analysis_result.backjump_level;
+
+        match self
+            .internal_parameters
+            .chronological_backtracking_threshold
+        {
+            Some(threshold) if decision_level - backjump_level > threshold => decision_level - 1,
+            _ => backjump_level,
+        }
+    }
+
     /// Performs a restart during the search process; it is only called when it has been determined
     /// to be necessary by the [`ConstraintSatisfactionSolver::restart_strategy`]. A 'restart'
     /// differs from backtracking to level zero in that a restart backtracks to decision level
@@ -1264,9 +2179,32 @@ impl ConstraintSatisfactionSolver {
         self.declare_new_decision_level();
     }
 
+    /// Changes the direction of the search without restarting, after the
+    /// [`ConstraintSatisfactionSolver::restart_strategy`] has blocked a number of consecutive
+    /// restarts; the brancher flips some of its saved values, which diversifies the search while
+    /// retaining the current trail.
+    fn perturb_during_search(&mut self, brancher: &mut impl Brancher) {
+        self.counters.engine_statistics.num_perturbations += 1;
+
+        brancher.on_perturbation(
+            &mut SelectionContext::new(
+                &self.assignments_integer,
+                &self.assignments_propositional,
+                &mut self.internal_parameters.random_generator,
+            ),
+            PERTURBATION_FLIP_PROBABILITY,
+        );
+
+        self.restart_strategy.notify_perturbation();
+    }
+
     pub(crate) fn backtrack(&mut self, backtrack_level: usize, brancher: &mut impl Brancher) {
         pumpkin_assert_simple!(backtrack_level < self.get_decision_level());
 
+        self.internal_parameters
+            .search_tree_log
+            .backtrack(backtrack_level);
+
         // We clear all of the unprocessed events from the watch list since synchronisation, we do
         // not need to process these events
         if self.watch_list_cp.is_watching_anything() {
@@ -1276,16 +2214,30 @@ impl ConstraintSatisfactionSolver {
                 .for_each(drop);
         }
 
+        // Only the propagators which watch a literal or domain that is restored are synchronised
+        let mut propagators_to_synchronise = Vec::new();
+
         // We synchronise the assignments propositional and for each unassigned literal, we notify
         // the brancher that it has been unassigned
         let unassigned_literals = self.assignments_propositional.synchronise(backtrack_level);
         unassigned_literals.for_each(|literal| {
             brancher.on_unassign_literal(literal);
-        });
 
-        // We synchronise the clausal propagator which sets the next variable on the trail to
-        // propagate
-        self.clausal_propagator
+            for (event, affected_literal) in BooleanDomainEvent::get_iterator(literal)
+                .chain(BooleanDomainEvent::get_iterator(!literal))
+            {
+                propagators_to_synchronise.extend(
+                    self.watch_list_propositional
+                        .get_affected_propagators(event, affected_literal)
+                        .iter()
+                        .map(|propagator_var| propagator_var.propagator),
+                );
+            }
+        });
+
+        // We synchronise the clausal propagator which sets the next variable on the trail to
+        // propagate
+        self.clausal_propagator
             .synchronise(self.assignments_propositional.num_trail_entries());
         pumpkin_assert_simple!(
             self.assignments_propositional.get_decision_level()
@@ -1301,16 +2253,24 @@ impl ConstraintSatisfactionSolver {
 
         // We synchronise the assignments integer and for each of the unassigned integer variables,
         // we notify the brancher that it has been unassigned
-        self.assignments_integer
-            .synchronise(
-                backtrack_level,
-                self.watch_list_cp.is_watching_any_backtrack_events(),
-                self.last_notified_cp_trail_index,
-            )
+        let synchronisation_result = self.assignments_integer.synchronise(
+            backtrack_level,
+            self.watch_list_cp.is_watching_any_backtrack_events(),
+            self.last_notified_cp_trail_index,
+        );
+        synchronisation_result
+            .unfixed_variables
             .iter()
             .for_each(|(domain_id, previous_value)| {
                 brancher.on_unassign_integer(*domain_id, *previous_value)
             });
+        for &domain_id in synchronisation_result.restored_domains.iter() {
+            propagators_to_synchronise.extend(
+                self.watch_list_cp
+                    .get_watching_propagators(domain_id)
+                    .map(|propagator_var| propagator_var.propagator),
+            );
+        }
         pumpkin_assert_simple!(
             !self.watch_list_cp.is_watching_anything()
                 || self.last_notified_cp_trail_index
@@ -1322,18 +2282,21 @@ impl ConstraintSatisfactionSolver {
         //  note that variable_literal_mappings sync should be called after the sat/cp data
         // structures backtrack
         self.synchronise_assignments();
-        // for now all propagators are called to synchronise
-        //  in the future this will be improved in two ways:
-        //      + allow incremental synchronisation
-        //      + only call the subset of propagators that were notified since last backtrack
-        for propagator in self.cp_propagators.iter_propagators_mut() {
-            let context =
-                PropagationContext::new(&self.assignments_integer, &self.assignments_propositional);
-            propagator.synchronise(context);
+        let mut is_synchronised: HashSet<PropagatorId> = HashSet::default();
+        for propagator_id in propagators_to_synchronise {
+            if is_synchronised.insert(propagator_id) {
+                let context = PropagationContext::new(
+                    &self.assignments_integer,
+                    &self.assignments_propositional,
+                );
+                self.cp_propagators[propagator_id].synchronise(context);
+            }
         }
 
         let _ = self.process_backtrack_events();
         self.propagator_queue.clear();
+
+        brancher.on_backtrack(backtrack_level);
     }
 
     /// Main propagation loop.
@@ -1426,6 +2389,7 @@ impl ConstraintSatisfactionSolver {
         }
 
         let cp_trail_length = self.assignments_integer.num_trail_entries();
+        let propositional_trail_length = self.assignments_propositional.num_trail_entries();
         let is_at_root = self.get_decision_level() == 0;
         let propagator_id = self.propagator_queue.pop();
         let tag = self.cp_propagators.get_tag(propagator_id);
@@ -1446,6 +2410,13 @@ impl ConstraintSatisfactionSolver {
             self.log_root_propagation_to_proof(cp_trail_length, tag);
         }
 
+        let had_effect = propagation_status.is_err()
+            || self.assignments_integer.num_trail_entries() != cp_trail_length
+            || self.assignments_propositional.num_trail_entries() != propositional_trail_length;
+        self.cp_propagators
+            .get_statistics_mut(propagator_id)
+            .record_call(had_effect);
+
         let result = match propagation_status {
             // An empty domain conflict will be caught by the clausal propagator.
             Err(Inconsistency::EmptyDomain) => PropagationStatusOneStepCP::PropagationHappened,
@@ -1671,7 +2642,7 @@ impl ConstraintSatisfactionSolver {
         tag: Option<NonZero<u32>>,
     ) -> Result<(), ConstraintOperationError> {
         if self.state.is_inconsistent() {
-            return Err(ConstraintOperationError::InfeasiblePropagator);
+            return Err(ConstraintOperationError::InfeasibleState);
         }
 
         pumpkin_assert_simple!(
@@ -1914,13 +2885,41 @@ impl Brancher for DummyBrancher {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::num::NonZero;
+    use std::rc::Rc;
+
     use super::ConstraintSatisfactionSolver;
     use super::CoreExtractionResult;
+    use super::DecisionTraceSink;
+    use super::SatisfactionSolverOptions;
+    use crate::basic_types::moving_averages::MovingAverage;
     use crate::basic_types::CSPSolverExecutionFlag;
+    use crate::basic_types::PropagationStatusCP;
+    use crate::basic_types::PropositionalConjunction;
+    use crate::basic_types::Solution;
+    use crate::basic_types::VariableCreationError;
+    use crate::basic_types::ViolatedConstraint;
+    use crate::branching::Brancher;
+    use crate::branching::RecordingBrancher;
+    use crate::branching::SelectionContext;
+    use crate::engine::conflict_analysis::ConflictAnalysisContext;
+    use crate::engine::domain_events::DomainEvents;
+    use crate::engine::predicates::predicate::Predicate;
+    use crate::engine::propagation::LocalId;
+    use crate::engine::propagation::PropagationContext;
+    use crate::engine::propagation::PropagationContextMut;
+    use crate::engine::propagation::Propagator;
+    use crate::engine::propagation::PropagatorInitialisationContext;
     use crate::engine::reason::ReasonRef;
     use crate::engine::termination::indefinite::Indefinite;
+    use crate::engine::variables::DomainId;
     use crate::engine::variables::Literal;
+    use crate::engine::variables::TransformableVariable;
+    use crate::engine::LearningOptions;
+    use crate::engine::SearchTreeLog;
     use crate::predicate;
+    use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
     use crate::propagators::linear_not_equal::LinearNotEqualPropagator;
 
     /// A test propagator which propagates the stored propagations and then reports one of the
@@ -1986,6 +2985,339 @@ mod tests {
         (solver, vec![lit1, lit2])
     }
 
+    /// Creates a solver containing the clausal encoding of the pigeonhole problem.
+    fn create_pigeonhole_solver(
+        num_pigeons: usize,
+        num_holes: usize,
+    ) -> ConstraintSatisfactionSolver {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let in_hole = (0..num_pigeons)
+            .map(|_| {
+                (0..num_holes)
+                    .map(|_| Literal::new(solver.create_new_propositional_variable(None), true))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for pigeon in &in_hole {
+            let _ = solver.add_clause(pigeon.clone());
+        }
+        for hole in 0..num_holes {
+            for (index, pigeon1) in in_hole.iter().enumerate() {
+                for pigeon2 in in_hole.iter().skip(index + 1) {
+                    let _ = solver.add_clause([!pigeon1[hole], !pigeon2[hole]]);
+                }
+            }
+        }
+
+        solver
+    }
+
+    #[test]
+    fn learned_clauses_are_enumerable_after_solving() {
+        // The pigeonhole problem with 4 pigeons and 3 holes, which is unsatisfiable
+        let mut solver = create_pigeonhole_solver(4, 3);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Infeasible);
+
+        let learned_clauses = solver.learned_clauses().collect::<Vec<_>>();
+        assert!(!learned_clauses.is_empty());
+
+        let num_variables = solver
+            .assignments_propositional
+            .num_propositional_variables();
+        for clause in learned_clauses {
+            assert!(clause.len() >= 2);
+            assert!(clause
+                .iter()
+                .all(|literal| literal.get_propositional_variable().get_index() < num_variables));
+        }
+    }
+
+    #[test]
+    fn pigeonhole_is_exported_as_dimacs() {
+        let solver = create_pigeonhole_solver(3, 2);
+
+        let mut output = Vec::new();
+        let result = solver.export_dimacs(&mut output);
+        assert!(result.is_ok());
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        // 3 clauses stating that each pigeon is in a hole, 2 * 3 clauses stating that no two
+        // pigeons share a hole
+        assert_eq!(Some("p cnf 6 9"), lines.next());
+
+        let clauses = lines.collect::<Vec<_>>();
+        assert_eq!(9, clauses.len());
+        assert!(clauses.contains(&"1 2 0"));
+        assert!(clauses.contains(&"-2 -6 0"));
+    }
+
+    #[test]
+    fn root_assignments_are_exported_as_unit_clauses() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let literal = Literal::new(solver.create_new_propositional_variable(None), true);
+        let _ = solver.add_clause([!literal]);
+
+        let mut output = Vec::new();
+        let result = solver.export_dimacs(&mut output);
+        assert!(result.is_ok());
+
+        assert_eq!("p cnf 1 1\n-1 0\n", String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn model_with_propagators_cannot_be_exported_as_dimacs() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 5, None);
+        let _ = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1)]), 3),
+            None,
+        );
+
+        let mut output = Vec::new();
+        let result = solver.export_dimacs(&mut output);
+
+        assert_eq!(std::io::ErrorKind::InvalidInput, result.unwrap_err().kind());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn brancher_is_notified_of_backtracks_and_conflicts() {
+        let mut solver = create_pigeonhole_solver(4, 3);
+        let mut brancher =
+            RecordingBrancher::new(solver.default_brancher_over_all_propositional_variables());
+
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Infeasible);
+
+        // Every conflict which is not at the root level is followed by a backtrack
+        assert!(brancher.num_conflicts > 0);
+        assert!(brancher.backtrack_levels.len() as u64 >= brancher.num_conflicts);
+    }
+
+    fn solve_pigeonhole_with_chronological_backtracking_threshold(
+        threshold: Option<usize>,
+    ) -> ConstraintSatisfactionSolver {
+        let mut solver = create_pigeonhole_solver(6, 5);
+        solver
+            .internal_parameters
+            .chronological_backtracking_threshold = threshold;
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(CSPSolverExecutionFlag::Infeasible, flag);
+
+        solver
+    }
+
+    #[test]
+    fn backtracks_chronologically_when_backjump_distance_exceeds_threshold() {
+        let solver = solve_pigeonhole_with_chronological_backtracking_threshold(Some(0));
+
+        // Every conflict which does not lead to a unit clause backtracks a single level
+        let average_backtrack_amount = solver
+            .counters
+            .learned_clause_statistics
+            .average_backtrack_amount;
+        assert!(average_backtrack_amount.is_initialised());
+        assert_eq!(1.0, average_backtrack_amount.value());
+    }
+
+    #[test]
+    fn backjumps_without_chronological_backtracking_threshold() {
+        let solver = solve_pigeonhole_with_chronological_backtracking_threshold(None);
+
+        let average_backtrack_amount = solver
+            .counters
+            .learned_clause_statistics
+            .average_backtrack_amount;
+        assert!(average_backtrack_amount.value() > 1.0);
+    }
+
+    #[test]
+    fn chronological_backtracking_preserves_solver_results() {
+        for (num_pigeons, expected_flag) in [
+            (4, CSPSolverExecutionFlag::Infeasible),
+            (3, CSPSolverExecutionFlag::Feasible),
+        ] {
+            let mut solver = create_pigeonhole_solver(num_pigeons, 3);
+            solver
+                .internal_parameters
+                .chronological_backtracking_threshold = Some(0);
+
+            let mut brancher = solver.default_brancher_over_all_propositional_variables();
+            let flag = solver.solve(&mut Indefinite, &mut brancher);
+            assert_eq!(expected_flag, flag);
+        }
+    }
+
+    #[test]
+    fn chronological_backtracking_does_not_lose_learned_clause_propagations() {
+        // Interrupt the search after every conflict and check that, once propagation has reached
+        // a fixed point, no learned clause has all but one literal falsified while the remaining
+        // literal is unassigned.
+        for num_conflicts in 1..200 {
+            let mut solver = create_pigeonhole_solver(6, 5);
+            solver
+                .internal_parameters
+                .chronological_backtracking_threshold = Some(0);
+            solver.set_conflict_budget(Some(num_conflicts));
+
+            let mut brancher = solver.default_brancher_over_all_propositional_variables();
+            let flag = solver.solve(&mut Indefinite, &mut brancher);
+            if flag != CSPSolverExecutionFlag::Timeout {
+                break;
+            }
+
+            solver.propagate_enqueued();
+            if solver.state.conflicting() {
+                continue;
+            }
+
+            let assignments = &solver.assignments_propositional;
+            for clause in solver.learned_clauses() {
+                let num_falsified = clause
+                    .iter()
+                    .filter(|&&literal| assignments.is_literal_assigned_false(literal))
+                    .count();
+                let has_unassigned_literal = clause
+                    .iter()
+                    .any(|&literal| assignments.is_literal_unassigned(literal));
+
+                assert!(
+                    num_falsified + 1 < clause.len() || !has_unassigned_literal,
+                    "learned clause {clause:?} should have propagated after {num_conflicts} conflicts"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn creating_a_domain_larger_than_the_maximum_domain_size_fails() {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                max_domain_size: Some(100),
+                ..Default::default()
+            },
+        );
+
+        let result = solver.try_create_new_integer_variable(0, i32::MAX, None);
+        assert_eq!(
+            Err(VariableCreationError::DomainTooLarge {
+                size: i32::MAX as u64 + 1,
+                max_domain_size: 100
+            }),
+            result
+        );
+
+        let result = solver.try_create_new_integer_variable(1, 100, None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn violated_constraints_of_solution_are_reported() {
+        let create_variables = |solver: &mut ConstraintSatisfactionSolver| {
+            let x = solver.create_new_integer_variable(0, 5, None);
+            let y = solver.create_new_integer_variable(0, 5, None);
+            let a = Literal::new(solver.create_new_propositional_variable(None), true);
+            let b = Literal::new(solver.create_new_propositional_variable(None), true);
+            (x, y, a, b)
+        };
+
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let (x, y, a, b) = create_variables(&mut solver);
+        let _ = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1), y.scaled(1)]), 5),
+            NonZero::new(1),
+        );
+        let _ = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 2),
+            NonZero::new(2),
+        );
+        let _ = solver.add_clause([a, b]);
+
+        // The assignment x = 4, y = 3, a = false and b = false is created by a solver over the
+        // same variables without any constraints
+        let mut unconstrained_solver = ConstraintSatisfactionSolver::default();
+        let _ = create_variables(&mut unconstrained_solver);
+        let assumptions = [
+            unconstrained_solver.get_literal(predicate!(x == 4)),
+            unconstrained_solver.get_literal(predicate!(y == 3)),
+            !a,
+            !b,
+        ];
+        let mut brancher = unconstrained_solver.default_brancher_over_all_propositional_variables();
+        let flag = unconstrained_solver.solve_under_assumptions(
+            &assumptions,
+            &mut Indefinite,
+            &mut brancher,
+        );
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+        let solution: Solution = unconstrained_solver.get_solution_reference().into();
+
+        assert_eq!(
+            vec![
+                ViolatedConstraint::Clause(vec![a, b]),
+                ViolatedConstraint::Propagator {
+                    name: "LinearLeq".to_owned(),
+                    tag: NonZero::new(1),
+                }
+            ],
+            solver.verify_solution(&solution)
+        );
+    }
+
+    #[test]
+    fn violated_unit_clauses_of_solution_are_reported() {
+        let create_variables = |solver: &mut ConstraintSatisfactionSolver| {
+            let a = Literal::new(solver.create_new_propositional_variable(None), true);
+            let b = Literal::new(solver.create_new_propositional_variable(None), true);
+            (a, b)
+        };
+
+        // The unit clause is assigned at the root rather than stored as a clause
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let (a, b) = create_variables(&mut solver);
+        let _ = solver.add_clause([a]);
+        let _ = solver.add_clause([a, b]);
+
+        let mut unconstrained_solver = ConstraintSatisfactionSolver::default();
+        let _ = create_variables(&mut unconstrained_solver);
+        let mut brancher = unconstrained_solver.default_brancher_over_all_propositional_variables();
+        let flag =
+            unconstrained_solver.solve_under_assumptions(&[!a, b], &mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+        let solution: Solution = unconstrained_solver.get_solution_reference().into();
+
+        assert_eq!(
+            vec![ViolatedConstraint::Clause(vec![a])],
+            solver.verify_solution(&solution)
+        );
+    }
+
+    #[test]
+    fn found_solution_violates_no_constraints() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 5, None);
+        let y = solver.create_new_integer_variable(0, 5, None);
+        let _ = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(-1), y.scaled(-1)]), -7),
+            None,
+        );
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+
+        let solution: Solution = solver.get_solution_reference().into();
+        assert!(solver.verify_solution(&solution).is_empty());
+    }
+
     #[test]
     fn core_extraction_unit_core() {
         let mut solver = ConstraintSatisfactionSolver::default();
@@ -2275,4 +3607,322 @@ mod tests {
         let result = solver.add_propagator(propagator, None);
         assert!(result.is_err());
     }
+
+    fn solve_all_different_with_too_few_values() -> ConstraintSatisfactionSolver {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let variables = (0..5)
+            .map(|_| solver.create_new_integer_variable(1, 4, None))
+            .collect::<Vec<_>>();
+
+        for (index, &x) in variables.iter().enumerate() {
+            for &y in variables.iter().skip(index + 1) {
+                let _ = solver.add_propagator(
+                    LinearNotEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 0),
+                    None,
+                );
+            }
+        }
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Infeasible);
+
+        solver
+    }
+
+    #[test]
+    fn search_is_deterministic_across_runs() {
+        let first = solve_all_different_with_too_few_values();
+        let second = solve_all_different_with_too_few_values();
+
+        let first_statistics = &first.counters.engine_statistics;
+        let second_statistics = &second.counters.engine_statistics;
+        assert_eq!(
+            first_statistics.num_decisions,
+            second_statistics.num_decisions
+        );
+        assert_eq!(
+            first_statistics.num_conflicts,
+            second_statistics.num_conflicts
+        );
+        assert_eq!(
+            first_statistics.num_propagations,
+            second_statistics.num_propagations
+        );
+        assert!(first.learned_clauses().eq(second.learned_clauses()));
+    }
+
+    #[test]
+    fn first_solution_follows_warm_start() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 5, None);
+        let y = solver.create_new_integer_variable(0, 5, None);
+
+        // x + y = 5
+        let _ = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(1), y.scaled(1)]), 5),
+            None,
+        );
+        let _ = solver.add_propagator(
+            LinearLessOrEqualPropagator::new(Box::new([x.scaled(-1), y.scaled(-1)]), -5),
+            None,
+        );
+
+        solver.warm_start(&[(x, 2), (y, 3)]);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+
+        assert_eq!(solver.get_assigned_integer_value(&x), Some(2));
+        assert_eq!(solver.get_assigned_integer_value(&y), Some(3));
+    }
+
+    #[test]
+    fn imported_activities_bias_the_first_decision() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let _ = solver.create_new_integer_variable(0, 5, None);
+        let y = solver.create_new_integer_variable(0, 5, None);
+        let _ = solver.create_new_integer_variable(0, 5, None);
+
+        solver.import_activities(&[(y, 100.0)]);
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let decision = brancher
+            .next_decision(&mut SelectionContext::new(
+                &solver.assignments_integer,
+                &solver.assignments_propositional,
+                &mut solver.internal_parameters.random_generator,
+            ))
+            .expect("not all variables are assigned");
+        let literal = decision
+            .get_literal_of_bool_predicate(solver.true_literal)
+            .expect("the default brancher decides on literals");
+
+        assert_eq!(
+            Some(y),
+            solver.variable_literal_mappings.get_domain_literal(literal)
+        );
+    }
+
+    #[test]
+    fn exported_activities_are_those_of_the_brancher() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 5, None);
+        let y = solver.create_new_integer_variable(0, 5, None);
+
+        solver.import_activities(&[(y, 100.0)]);
+        let brancher = solver.default_brancher_over_all_propositional_variables();
+
+        assert_eq!(
+            vec![(x, 0.0), (y, 100.0)],
+            solver.export_activities(&brancher)
+        );
+    }
+
+    #[test]
+    fn learned_clauses_are_kept_when_resetting_statistics() {
+        // The pigeonhole problem with 5 pigeons and 4 holes, where every pigeon only needs to be
+        // placed if `a` is true
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let a = Literal::new(solver.create_new_propositional_variable(None), true);
+        let in_hole = (0..5)
+            .map(|_| {
+                (0..4)
+                    .map(|_| Literal::new(solver.create_new_propositional_variable(None), true))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for pigeon in &in_hole {
+            let _ = solver.add_clause(pigeon.iter().copied().chain(std::iter::once(!a)));
+        }
+        for hole in 0..4 {
+            for (index, pigeon1) in in_hole.iter().enumerate() {
+                for pigeon2 in in_hole.iter().skip(index + 1) {
+                    let _ = solver.add_clause([!pigeon1[hole], !pigeon2[hole]]);
+                }
+            }
+        }
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve_under_assumptions(&[a], &mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Infeasible);
+        let _ = solver.extract_clausal_core(&mut brancher);
+        let num_conflicts_first_solve = solver.counters.engine_statistics.num_conflicts;
+        assert!(num_conflicts_first_solve > 0);
+
+        solver.reset_statistics();
+        assert_eq!(0, solver.counters.engine_statistics.num_conflicts);
+        assert_eq!(0, solver.counters.engine_statistics.num_decisions);
+
+        let flag = solver.solve_under_assumptions(&[a], &mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Infeasible);
+        assert!(solver.counters.engine_statistics.num_conflicts < num_conflicts_first_solve);
+    }
+
+    #[test]
+    fn every_decision_is_traced_when_logging_decisions() {
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                log_decisions: true,
+                ..Default::default()
+            },
+        );
+        let x = solver.create_new_integer_variable(0, 5, None);
+        let y = solver.create_new_integer_variable(0, 5, None);
+        let _ = solver.add_propagator(
+            LinearNotEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 0),
+            None,
+        );
+
+        let traces = Rc::new(RefCell::new(vec![]));
+        let sink_traces = Rc::clone(&traces);
+        solver.decision_trace_sink = DecisionTraceSink(Box::new(move |trace| {
+            sink_traces.borrow_mut().push(trace.to_owned())
+        }));
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+
+        let traces = traces.borrow();
+        assert!(solver.counters.engine_statistics.num_decisions > 0);
+        assert_eq!(
+            solver.counters.engine_statistics.num_decisions,
+            traces.len() as u64
+        );
+        assert!(traces
+            .iter()
+            .all(|trace| trace.contains("made by") && trace.contains("propagations")));
+    }
+
+    /// A writer which can be inspected after it has been moved into the solver.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn search_tree_is_logged_as_json_lines() {
+        let buffer = SharedBuffer::default();
+        let mut solver = ConstraintSatisfactionSolver::new(
+            LearningOptions::default(),
+            SatisfactionSolverOptions {
+                search_tree_log: SearchTreeLog::new(buffer.clone()),
+                ..Default::default()
+            },
+        );
+        let x = solver.create_new_integer_variable(0, 5, None);
+        let y = solver.create_new_integer_variable(0, 5, None);
+        let _ = solver.add_propagator(
+            LinearNotEqualPropagator::new(Box::new([x.scaled(1), y.scaled(-1)]), 0),
+            None,
+        );
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+
+        let log = String::from_utf8(buffer.0.borrow().clone()).unwrap();
+        let lines = log.lines().collect::<Vec<_>>();
+
+        assert_eq!(
+            r#"{"id":0,"parent":null,"decision":null,"outcome":"propagated"}"#,
+            lines[0]
+        );
+        assert!(lines[1..]
+            .iter()
+            .any(|line| line.contains(r#""parent":0,"decision":""#)));
+        assert!(lines.last().unwrap().ends_with(r#""outcome":"solution"}"#));
+        assert!(lines
+            .iter()
+            .enumerate()
+            .all(|(index, line)| line.starts_with(&format!("{{\"id\":{index},"))));
+    }
+
+    /// A propagator which counts how often it is synchronised, but never propagates.
+    struct SynchronisationCounter {
+        variable: DomainId,
+        num_synchronisations: Rc<RefCell<usize>>,
+    }
+
+    impl Propagator for SynchronisationCounter {
+        fn initialise_at_root(
+            &mut self,
+            context: &mut PropagatorInitialisationContext,
+        ) -> Result<(), PropositionalConjunction> {
+            let _ = context.register(self.variable, DomainEvents::ANY_INT, LocalId::from(0));
+            Ok(())
+        }
+
+        fn synchronise(&mut self, _context: PropagationContext) {
+            *self.num_synchronisations.borrow_mut() += 1;
+        }
+
+        fn name(&self) -> &str {
+            "SynchronisationCounter"
+        }
+
+        fn debug_propagate_from_scratch(&self, _: PropagationContextMut) -> PropagationStatusCP {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn backtracking_only_synchronises_propagators_of_restored_domains() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        let x_synchronisations = Rc::new(RefCell::new(0));
+        let y_synchronisations = Rc::new(RefCell::new(0));
+        for (variable, num_synchronisations) in [(x, &x_synchronisations), (y, &y_synchronisations)]
+        {
+            let result = solver.add_propagator(
+                SynchronisationCounter {
+                    variable,
+                    num_synchronisations: Rc::clone(num_synchronisations),
+                },
+                None,
+            );
+            assert!(result.is_ok());
+        }
+
+        solver.declare_new_decision_level();
+        let decision = solver.get_literal(predicate![x >= 5]);
+        solver
+            .assignments_propositional
+            .enqueue_decision_literal(decision);
+        solver.propagate_enqueued();
+        assert!(!solver.state.conflicting());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        solver.backtrack(0, &mut brancher);
+
+        assert_eq!(1, *x_synchronisations.borrow());
+        assert_eq!(0, *y_synchronisations.borrow());
+    }
+
+    #[test]
+    fn forced_restart_takes_place_during_the_next_search() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let _ = solver.create_new_integer_variable(0, 10, None);
+        let _ = solver.create_new_integer_variable(0, 10, None);
+
+        solver.force_restart();
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+        assert_eq!(1, solver.counters.engine_statistics.num_restarts);
+    }
 }
@@ -1,3 +1,7 @@
+use std::cell::RefCell;
+
+use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
 use crate::basic_types::KeyedVec;
 use crate::basic_types::Trail;
 use crate::engine::cp::event_sink::EventSink;
@@ -29,6 +33,29 @@ pub struct AssignmentsInteger {
     /// Keeps track of the [`IntDomainEvent`]s which are undone while backtracking, this is used to
     /// implement [`Propagator::notify_backtrack`].
     backtrack_events: EventSink,
+
+    /// An optional memo of the results of [`AssignmentsInteger::get_domain_description`]; see
+    /// [`AssignmentsInteger::enable_domain_description_cache`].
+    domain_description_cache: Option<RefCell<DomainDescriptionCache>>,
+}
+
+/// The cached descriptions of domains, keyed by the number of trail entries at the time the
+/// description was created.
+#[derive(Clone, Default, Debug)]
+struct DomainDescriptionCache {
+    descriptions: HashMap<DomainId, (usize, Vec<Predicate>)>,
+    num_hits: u64,
+}
+
+/// The result of [`AssignmentsInteger::synchronise`].
+#[derive(Clone, Debug, Default)]
+pub struct SynchronisationResult {
+    /// The [`DomainId`]s of which at least one trail entry was undone, in the order in which they
+    /// were first restored.
+    pub(crate) restored_domains: Vec<DomainId>,
+    /// The [`DomainId`]s which were fixed before backtracking and are unfixed after
+    /// synchronisation, together with the value they were fixed to.
+    pub(crate) unfixed_variables: Vec<(DomainId, i32)>,
 }
 
 /// A structure which indicates that an empty domain has been encountered; oftentimes returned as
@@ -143,6 +170,7 @@ impl AssignmentsInteger {
             domains,
             events: event_sink,
             backtrack_events: backtrack_sink,
+            domain_description_cache: None,
         }
     }
 }
@@ -177,6 +205,11 @@ impl AssignmentsInteger {
             .copied()
     }
 
+    /// Returns the number of values in the domain of the provided [`DomainId`]
+    pub fn get_domain_size(&self, domain_id: DomainId) -> u32 {
+        self.domains[domain_id].size
+    }
+
     /// Returns the assigned value of the provided [`DomainId`]; this method will panic if the
     /// [`DomainId`] is not assigned
     pub fn get_assigned_value(&self, domain_id: DomainId) -> i32 {
@@ -184,8 +217,40 @@ impl AssignmentsInteger {
         self.domains[domain_id].lower_bound
     }
 
+    /// Memoises the results of [`AssignmentsInteger::get_domain_description`], which is useful
+    /// when the same domain is described repeatedly without it changing in between (e.g. when
+    /// explaining propagations during conflict analysis).
+    ///
+    /// A cached description is only reused as long as the trail has not changed since it was
+    /// created.
+    pub fn enable_domain_description_cache(&mut self) {
+        self.domain_description_cache = Some(RefCell::default());
+    }
+
     /// Returns a description of the provided [`DomainId`] in terms of [`Predicate`]s
     pub fn get_domain_description(&self, domain_id: DomainId) -> Vec<Predicate> {
+        let Some(cache) = self.domain_description_cache.as_ref() else {
+            return self.create_domain_description(domain_id);
+        };
+
+        let trail_length = self.num_trail_entries();
+        let mut cache = cache.borrow_mut();
+        if let Some((cached_trail_length, description)) = cache.descriptions.get(&domain_id) {
+            if *cached_trail_length == trail_length {
+                let description = description.clone();
+                cache.num_hits += 1;
+                return description;
+            }
+        }
+
+        let description = self.create_domain_description(domain_id);
+        let _ = cache
+            .descriptions
+            .insert(domain_id, (trail_length, description.clone()));
+        description
+    }
+
+    fn create_domain_description(&self, domain_id: DomainId) -> Vec<Predicate> {
         let mut predicates = Vec::new();
         let domain = &self.domains[domain_id];
         // if fixed, this is just one predicate
@@ -248,11 +313,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.domains[domain_id].size;
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -286,11 +353,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.domains[domain_id].size;
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -346,11 +415,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.domains[domain_id].size;
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -381,11 +452,13 @@ impl AssignmentsInteger {
 
         let old_lower_bound = self.get_lower_bound(domain_id);
         let old_upper_bound = self.get_upper_bound(domain_id);
+        let old_domain_size = self.domains[domain_id].size;
 
         self.trail.push(ConstraintProgrammingTrailEntry {
             predicate,
             old_lower_bound,
             old_upper_bound,
+            old_domain_size,
             reason,
         });
 
@@ -452,9 +525,11 @@ impl AssignmentsInteger {
     }
 
     /// Synchronises the internal structures of [`AssignmentsInteger`] based on the fact that
-    /// backtracking to `new_decision_level` is taking place. This method returns the list of
+    /// backtracking to `new_decision_level` is taking place. This method returns the
+    /// [`DomainId`]s whose domains were restored by the synchronisation, and the list of
     /// [`DomainId`]s and their values which were fixed (i.e. domain of size one) before
-    /// backtracking and are unfixed (i.e. domain of two or more values) after synchronisation.
+    /// backtracking and are unfixed (i.e. domain of two or more values) after synchronisation;
+    /// see [`SynchronisationResult`].
     ///
     /// The `last_notified_trail_index` is used to only create backtrack events for events for
     /// which the propagators have been notified of the "forward" event.
@@ -463,20 +538,31 @@ impl AssignmentsInteger {
         new_decision_level: usize,
         is_watching_any_backtrack_events: bool,
         last_notified_trail_index: usize,
-    ) -> Vec<(DomainId, i32)> {
+    ) -> SynchronisationResult {
         let mut unfixed_variables = Vec::new();
+        let mut restored_domains = Vec::new();
+        let mut is_restored: HashSet<DomainId> = HashSet::default();
 
         // Used to calculate the index on the trail of the current entry; we only create the
         // backtrack events for entries for which the notification of the "forward" event has
         // occurred.
         let num_trail_entries_before_synchronisation = self.num_trail_entries();
 
+        // After backtracking, the trail can reach the same length with different entries, so the
+        // cached descriptions cannot be reused
+        if let Some(cache) = self.domain_description_cache.as_mut() {
+            cache.get_mut().descriptions.clear();
+        }
+
         self.trail.synchronise(new_decision_level).enumerate().for_each(|(index, entry)| {
             pumpkin_assert_moderate!(
                 !entry.predicate.is_equality_predicate(),
                 "For now we do not expect equality predicates on the trail, since currently equality predicates are split into lower and upper bound predicates."
             );
             let domain_id = entry.predicate.get_domain();
+            if is_restored.insert(domain_id) {
+                restored_domains.push(domain_id);
+            }
 
             let lower_bound_before = self.domains[domain_id].lower_bound;
             let upper_bound_before = self.domains[domain_id].upper_bound;
@@ -510,7 +596,11 @@ impl AssignmentsInteger {
             }
 
         });
-        unfixed_variables
+
+        SynchronisationResult {
+            restored_domains,
+            unfixed_variables,
+        }
     }
 }
 
@@ -538,6 +628,8 @@ pub struct ConstraintProgrammingTrailEntry {
     pub old_lower_bound: i32,
     /// The upper-bound before the predicate was applied
     pub old_upper_bound: i32,
+    /// The number of values in the domain before the predicate was applied
+    pub old_domain_size: u32,
     /// Stores the a reference to the reason in the [`ReasonStore`], only makes sense if a
     /// propagation  took place, e.g., does _not_ make sense in the case of a decision or if
     /// the update was due to synchronisation from the propositional trail.
@@ -560,6 +652,9 @@ struct IntegerDomainExplicit {
     initial_lower_bound: i32,
     initial_upper_bound: i32,
     initial_removed_values: Vec<i32>,
+    /// The number of values in the domain; i.e. the number of values between the bounds which
+    /// are not removed.
+    size: u32,
 
     offset: i32,
 
@@ -582,6 +677,7 @@ impl IntegerDomainExplicit {
             initial_removed_values: vec![],
             initial_lower_bound: lower_bound,
             initial_upper_bound: upper_bound,
+            size: size as u32,
             offset,
             is_value_in_domain: is_value_in_domain.into(),
         }
@@ -607,6 +703,7 @@ impl IntegerDomainExplicit {
 
         if self.is_value_in_domain[idx] {
             events.event_occurred(IntDomainEvent::Removal, self.id);
+            self.size -= 1;
         }
 
         self.is_value_in_domain[idx] = false;
@@ -626,6 +723,7 @@ impl IntegerDomainExplicit {
 
         events.event_occurred(IntDomainEvent::UpperBound, self.id);
 
+        self.size -= self.count_values_in_range(value + 1, self.upper_bound);
         self.upper_bound = value;
         self.update_upper_bound(events);
 
@@ -641,6 +739,7 @@ impl IntegerDomainExplicit {
 
         events.event_occurred(IntDomainEvent::LowerBound, self.id);
 
+        self.size -= self.count_values_in_range(self.lower_bound, value - 1);
         self.lower_bound = value;
         self.update_lower_bound(events);
 
@@ -667,6 +766,13 @@ impl IntegerDomainExplicit {
         }
     }
 
+    /// Counts the values in the domain which lie in the interval `[from, to]`.
+    fn count_values_in_range(&self, from: i32, to: i32) -> u32 {
+        (from.max(self.lower_bound)..=to.min(self.upper_bound))
+            .filter(|&value| self.is_value_in_domain[self.get_index(value)])
+            .count() as u32
+    }
+
     fn get_index(&self, value: i32) -> usize {
         (value + self.offset) as usize
     }
@@ -706,6 +812,7 @@ impl IntegerDomainExplicit {
 
         self.lower_bound = entry.old_lower_bound;
         self.upper_bound = entry.old_upper_bound;
+        self.size = entry.old_domain_size;
 
         pumpkin_assert_moderate!(self.debug_bounds_check());
     }
@@ -715,6 +822,101 @@ impl IntegerDomainExplicit {
 mod tests {
     use super::*;
 
+    fn num_domain_description_cache_hits(assignment: &AssignmentsInteger) -> u64 {
+        assignment
+            .domain_description_cache
+            .as_ref()
+            .expect("cache is enabled")
+            .borrow()
+            .num_hits
+    }
+
+    #[test]
+    fn domain_description_is_cached_at_the_same_trail_state() {
+        let mut assignment = AssignmentsInteger::default();
+        assignment.enable_domain_description_cache();
+        let d1 = assignment.grow(1, 5);
+        assignment
+            .remove_value_from_domain(d1, 3, None)
+            .expect("non-empty domain");
+
+        let first = assignment.get_domain_description(d1);
+        let second = assignment.get_domain_description(d1);
+
+        assert_eq!(first, second);
+        assert_eq!(
+            vec![
+                predicate![d1 >= 1],
+                predicate![d1 <= 5],
+                predicate![d1 != 3]
+            ],
+            second
+        );
+        assert_eq!(1, num_domain_description_cache_hits(&assignment));
+    }
+
+    #[test]
+    fn domain_description_cache_is_invalidated_by_domain_changes() {
+        let mut assignment = AssignmentsInteger::default();
+        assignment.enable_domain_description_cache();
+        let d1 = assignment.grow(1, 5);
+        let _ = assignment.get_domain_description(d1);
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_lower_bound(d1, 2, None)
+            .expect("non-empty domain");
+        assert_eq!(
+            vec![predicate![d1 >= 2], predicate![d1 <= 5]],
+            assignment.get_domain_description(d1)
+        );
+
+        // Backtracking and making a different change leads to the same trail length
+        let _ = assignment.synchronise(0, false, usize::MAX);
+        assignment.increase_decision_level();
+        assignment
+            .tighten_upper_bound(d1, 4, None)
+            .expect("non-empty domain");
+        assert_eq!(
+            vec![predicate![d1 >= 1], predicate![d1 <= 4]],
+            assignment.get_domain_description(d1)
+        );
+
+        assert_eq!(0, num_domain_description_cache_hits(&assignment));
+    }
+
+    #[test]
+    fn synchronise_reports_exactly_the_restored_domains() {
+        let mut assignment = AssignmentsInteger::default();
+        let d1 = assignment.grow(1, 5);
+        let d2 = assignment.grow(1, 5);
+        let d3 = assignment.grow(1, 5);
+        let d4 = assignment.grow(1, 5);
+
+        assignment
+            .tighten_lower_bound(d1, 2, None)
+            .expect("non-empty domain");
+
+        assignment.increase_decision_level();
+        assignment
+            .tighten_upper_bound(d2, 3, None)
+            .expect("non-empty domain");
+        assignment
+            .remove_value_from_domain(d3, 4, None)
+            .expect("non-empty domain");
+        assignment
+            .tighten_lower_bound(d2, 3, None)
+            .expect("non-empty domain");
+
+        let result = assignment.synchronise(0, false, usize::MAX);
+
+        assert_eq!(vec![d2, d3], result.restored_domains);
+        assert_eq!(vec![(d2, 3)], result.unfixed_variables);
+
+        assert_eq!(2, assignment.get_lower_bound(d1));
+        assert_eq!(5, assignment.get_upper_bound(d4));
+    }
+
     #[test]
     fn jump_in_bound_change_lower_and_upper_bound_event_backtrack() {
         let mut assignment = AssignmentsInteger::default();
@@ -9,6 +9,7 @@ pub(crate) mod test_helper;
 mod variable_literal_mappings;
 mod watch_list_cp;
 mod watch_list_propositional;
+pub(crate) mod watcher_recorder;
 
 pub(crate) use assignments_integer::AssignmentsInteger;
 pub(crate) use assignments_integer::EmptyDomain;
@@ -81,6 +81,7 @@ pub(crate) mod propagation_context;
 pub(crate) mod propagator;
 pub(crate) mod propagator_id;
 pub(crate) mod propagator_initialisation_context;
+pub(crate) mod propagator_statistics;
 pub(crate) mod propagator_var_id;
 pub(crate) mod store;
 
@@ -92,6 +93,7 @@ pub(crate) use propagator::EnqueueDecision;
 pub(crate) use propagator::Propagator;
 pub(crate) use propagator_id::PropagatorId;
 pub(crate) use propagator_initialisation_context::PropagatorInitialisationContext;
+pub use propagator_statistics::PropagatorStatistics;
 pub(crate) use propagator_var_id::PropagatorVarId;
 
 #[cfg(doc)]
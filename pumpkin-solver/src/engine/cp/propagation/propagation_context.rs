@@ -163,6 +163,14 @@ pub(crate) trait ReadDomains: HasAssignments {
         var.upper_bound(self.assignments_integer())
     }
 
+    fn lower_bound_i64<Var: IntegerVariable>(&self, var: &Var) -> i64 {
+        var.lower_bound_i64(self.assignments_integer())
+    }
+
+    fn upper_bound_i64<Var: IntegerVariable>(&self, var: &Var) -> i64 {
+        var.upper_bound_i64(self.assignments_integer())
+    }
+
     fn contains<Var: IntegerVariable>(&self, var: &Var, value: i32) -> bool {
         var.contains(self.assignments_integer(), value)
     }
@@ -195,7 +203,7 @@ impl PropagationContextMut<'_> {
         bound: i32,
         reason: R,
     ) -> Result<(), EmptyDomain> {
-        if bound < var.upper_bound(self.assignments_integer) {
+        if (bound as i64) < var.upper_bound_i64(self.assignments_integer) {
             let reason = self.build_reason(reason.into());
             let reason_ref = self.reason_store.push(self.propagator, reason);
             return var.set_upper_bound(self.assignments_integer, bound, Some(reason_ref));
@@ -209,7 +217,7 @@ impl PropagationContextMut<'_> {
         bound: i32,
         reason: R,
     ) -> Result<(), EmptyDomain> {
-        if bound > var.lower_bound(self.assignments_integer) {
+        if (bound as i64) > var.lower_bound_i64(self.assignments_integer) {
             let reason = self.build_reason(reason.into());
             let reason_ref = self.reason_store.push(self.propagator, reason);
             return var.set_lower_bound(self.assignments_integer, bound, Some(reason_ref));
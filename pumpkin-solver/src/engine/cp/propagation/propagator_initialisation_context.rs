@@ -14,6 +14,7 @@ use crate::engine::WatchListCP;
 use crate::engine::WatchListPropositional;
 use crate::engine::Watchers;
 use crate::engine::WatchersPropositional;
+use crate::pumpkin_assert_simple;
 
 /// [`PropagatorInitialisationContext`] is used when [`Propagator`]s are initialised after creation.
 ///
@@ -63,6 +64,9 @@ impl PropagatorInitialisationContext<'_> {
     ///
     /// Note that the [`LocalId`] is used to differentiate between [`DomainId`]s and
     /// [`AffineView`]s.
+    ///
+    /// The constraints of the solver check that their variables belong to the solver before their
+    /// propagators are added; registering a variable of another solver is a bug in the caller.
     pub fn register<Var: IntegerVariable>(
         &mut self,
         var: Var,
@@ -76,6 +80,11 @@ impl PropagatorInitialisationContext<'_> {
 
         self.next_local_id = self.next_local_id.max(LocalId::from(local_id.unpack() + 1));
 
+        pumpkin_assert_simple!(
+            var.decompose().0.id < self.assignments_integer().num_domains(),
+            "The registered variable does not belong to this solver"
+        );
+
         let mut watchers = Watchers::new(propagator_var, self.watch_list);
         var.watch_all(&mut watchers, domain_events.get_int_events());
 
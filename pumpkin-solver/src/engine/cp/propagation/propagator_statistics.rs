@@ -0,0 +1,44 @@
+#[cfg(doc)]
+use crate::Solver;
+
+/// Statistics which are gathered for every propagator while solving; see
+/// [`Solver::propagator_stats`].
+///
+/// A propagator is called without effect when it is woken up but does not change any domain (nor
+/// reports a conflict). A high [`PropagatorStatistics::thrashing_ratio`] indicates that the
+/// propagator registers for more domain events than it needs.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PropagatorStatistics {
+    pub(crate) num_calls: u64,
+    pub(crate) num_calls_without_effect: u64,
+}
+
+impl PropagatorStatistics {
+    /// The number of times the propagator has been called.
+    pub fn num_calls(&self) -> u64 {
+        self.num_calls
+    }
+
+    /// The number of calls to the propagator which did not change any domain.
+    pub fn num_calls_without_effect(&self) -> u64 {
+        self.num_calls_without_effect
+    }
+
+    /// The fraction of the calls to the propagator which did not change any domain; this is `0`
+    /// if the propagator has not been called.
+    pub fn thrashing_ratio(&self) -> f64 {
+        if self.num_calls == 0 {
+            0.0
+        } else {
+            self.num_calls_without_effect as f64 / self.num_calls as f64
+        }
+    }
+
+    /// Records a call to the propagator, which either did or did not have an effect.
+    pub(crate) fn record_call(&mut self, had_effect: bool) {
+        self.num_calls += 1;
+        if !had_effect {
+            self.num_calls_without_effect += 1;
+        }
+    }
+}
@@ -5,17 +5,19 @@ use std::ops::IndexMut;
 
 use super::Propagator;
 use super::PropagatorId;
+use super::PropagatorStatistics;
 use crate::basic_types::KeyedVec;
 use crate::engine::DebugDyn;
 
 /// A central store for propagators.
 ///
 /// The propagator store associates tags with propagators, whenever a tag is provided for a
-/// propagator.
+/// propagator. It also keeps track of the [`PropagatorStatistics`] of every propagator.
 #[derive(Default)]
 pub(crate) struct PropagatorStore {
     propagators: KeyedVec<PropagatorId, Box<dyn Propagator>>,
     tags: KeyedVec<PropagatorId, Option<NonZero<u32>>>,
+    statistics: KeyedVec<PropagatorId, PropagatorStatistics>,
 }
 
 impl PropagatorStore {
@@ -26,6 +28,7 @@ impl PropagatorStore {
     ) -> PropagatorId {
         let id = self.propagators.push(propagator);
         let _ = self.tags.push(tag);
+        let _ = self.statistics.push(PropagatorStatistics::default());
 
         id
     }
@@ -34,14 +37,32 @@ impl PropagatorStore {
         self.tags[propagator_id]
     }
 
-    pub(crate) fn iter_propagators(&self) -> impl Iterator<Item = &dyn Propagator> + '_ {
-        self.propagators.iter().map(|b| b.as_ref())
+    pub(crate) fn get_statistics_mut(
+        &mut self,
+        propagator_id: PropagatorId,
+    ) -> &mut PropagatorStatistics {
+        &mut self.statistics[propagator_id]
     }
 
-    pub(crate) fn iter_propagators_mut(
-        &mut self,
-    ) -> impl Iterator<Item = &mut Box<dyn Propagator>> + '_ {
-        self.propagators.iter_mut()
+    /// Returns the name and the statistics of every propagator, in the order in which the
+    /// propagators were added.
+    pub(crate) fn iter_statistics(
+        &self,
+    ) -> impl Iterator<Item = (&str, PropagatorStatistics)> + '_ {
+        self.propagators
+            .iter()
+            .zip(self.statistics.iter())
+            .map(|(propagator, statistics)| (propagator.name(), *statistics))
+    }
+
+    pub(crate) fn reset_statistics(&mut self) {
+        self.statistics
+            .iter_mut()
+            .for_each(|statistics| *statistics = PropagatorStatistics::default());
+    }
+
+    pub(crate) fn iter_propagators(&self) -> impl Iterator<Item = &dyn Propagator> + '_ {
+        self.propagators.iter().map(|b| b.as_ref())
     }
 }
 
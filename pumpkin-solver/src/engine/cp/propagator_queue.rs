@@ -6,6 +6,13 @@ use crate::basic_types::HashSet;
 use crate::engine::cp::propagation::PropagatorId;
 use crate::pumpkin_assert_moderate;
 
+/// The queue of propagators which are scheduled for propagation.
+///
+/// Propagators are popped in order of priority (lower values first), and propagators with the same
+/// priority are popped in the order in which they were enqueued. The set of enqueued propagators
+/// is only used for membership checks, so the order of propagation is fully determined by the
+/// sequence of enqueue operations; given the same model and random seed, the solver performs the
+/// same sequence of propagations across runs.
 #[derive(Debug)]
 pub(crate) struct PropagatorQueue {
     queues: Vec<VecDeque<PropagatorId>>,
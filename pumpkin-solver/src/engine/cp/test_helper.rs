@@ -153,6 +153,12 @@ impl TestSolver {
             .is_literal_assigned_false(var)
     }
 
+    /// Returns a [`PropagationContext`] over the current domains, e.g. to query the state of a
+    /// propagator which has not been added to the solver.
+    pub(crate) fn propagation_context(&self) -> PropagationContext<'_> {
+        PropagationContext::new(&self.assignments_integer, &self.assignments_propositional)
+    }
+
     pub(crate) fn upper_bound(&self, var: DomainId) -> i32 {
         self.assignments_integer.get_upper_bound(var)
     }
@@ -65,6 +65,27 @@ impl WatchListCP {
         }
     }
 
+    /// Returns the propagators which watch any (forward or backtrack) event of `domain`; a
+    /// propagator can occur multiple times.
+    pub(crate) fn get_watching_propagators(
+        &self,
+        domain: DomainId,
+    ) -> impl Iterator<Item = PropagatorVarId> + '_ {
+        let watcher = &self.watchers[domain];
+
+        [&watcher.forward_watcher, &watcher.backtrack_watcher]
+            .into_iter()
+            .flat_map(|watcher| {
+                watcher
+                    .lower_bound_watchers
+                    .iter()
+                    .chain(&watcher.upper_bound_watchers)
+                    .chain(&watcher.assign_watchers)
+                    .chain(&watcher.removal_watchers)
+            })
+            .copied()
+    }
+
     pub(crate) fn get_backtrack_affected_propagators(
         &self,
         event: IntDomainEvent,
@@ -0,0 +1,125 @@
+#![cfg(test)]
+//! This module exposes the [`WatcherRecorder`], which records which propagators are woken up by
+//! changes to integer domains. This allows tests to assert which events a propagator registers
+//! for, without needing to run a full solver.
+use super::propagation::EnqueueDecision;
+use super::WatchListPropositional;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorId;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::DomainId;
+use crate::engine::AssignmentsInteger;
+use crate::engine::AssignmentsPropositional;
+use crate::engine::EmptyDomain;
+use crate::engine::WatchListCP;
+
+/// Records which propagators would be enqueued when an integer domain changes.
+///
+/// Propagators are registered through [`Propagator::initialise_at_root`], so the recorder uses
+/// exactly the events which the propagator subscribes to. A propagator is only considered to be
+/// woken up if it is watching one of the resulting events and its [`Propagator::notify`] returns
+/// [`EnqueueDecision::Enqueue`].
+#[derive(Default)]
+pub(crate) struct WatcherRecorder {
+    assignments_integer: AssignmentsInteger,
+    assignments_propositional: AssignmentsPropositional,
+    watch_list: WatchListCP,
+    watch_list_propositional: WatchListPropositional,
+    propagators: Vec<Box<dyn Propagator>>,
+}
+
+impl WatcherRecorder {
+    pub(crate) fn new_variable(&mut self, lb: i32, ub: i32) -> DomainId {
+        self.watch_list.grow();
+        self.assignments_integer.grow(lb, ub)
+    }
+
+    /// Initialises the propagator at the root and records the events it registers for.
+    ///
+    /// Panics if the propagator detects a conflict during initialisation.
+    pub(crate) fn add_propagator(&mut self, propagator: impl Propagator + 'static) -> PropagatorId {
+        let id = PropagatorId(self.propagators.len() as u32);
+        let mut propagator: Box<dyn Propagator> = Box::new(propagator);
+
+        propagator
+            .initialise_at_root(&mut PropagatorInitialisationContext::new(
+                &mut self.watch_list,
+                &mut self.watch_list_propositional,
+                id,
+                &self.assignments_integer,
+                &self.assignments_propositional,
+            ))
+            .expect("the propagator should not detect a conflict at the root");
+
+        self.propagators.push(propagator);
+        id
+    }
+
+    /// Removes `value` from the domain of `domain` and returns the propagators which would be
+    /// enqueued as a result.
+    pub(crate) fn remove(
+        &mut self,
+        domain: DomainId,
+        value: i32,
+    ) -> Result<Vec<PropagatorId>, EmptyDomain> {
+        self.assignments_integer
+            .remove_value_from_domain(domain, value, None)?;
+        Ok(self.record_woken_propagators())
+    }
+
+    /// Tightens the lower bound of `domain` and returns the propagators which would be enqueued as
+    /// a result.
+    pub(crate) fn set_lower_bound(
+        &mut self,
+        domain: DomainId,
+        value: i32,
+    ) -> Result<Vec<PropagatorId>, EmptyDomain> {
+        self.assignments_integer
+            .tighten_lower_bound(domain, value, None)?;
+        Ok(self.record_woken_propagators())
+    }
+
+    /// Tightens the upper bound of `domain` and returns the propagators which would be enqueued as
+    /// a result.
+    pub(crate) fn set_upper_bound(
+        &mut self,
+        domain: DomainId,
+        value: i32,
+    ) -> Result<Vec<PropagatorId>, EmptyDomain> {
+        self.assignments_integer
+            .tighten_upper_bound(domain, value, None)?;
+        Ok(self.record_woken_propagators())
+    }
+
+    /// Notifies the watching propagators of the pending domain events, in the same way as the
+    /// solver does, and returns the propagators which requested to be enqueued.
+    fn record_woken_propagators(&mut self) -> Vec<PropagatorId> {
+        let events = self
+            .assignments_integer
+            .drain_domain_events()
+            .collect::<Vec<_>>();
+
+        let mut woken = vec![];
+        for (event, domain) in events {
+            for propagator_var in self.watch_list.get_affected_propagators(event, domain) {
+                let propagator = &mut self.propagators[propagator_var.propagator.0 as usize];
+                let context = PropagationContext::new(
+                    &self.assignments_integer,
+                    &self.assignments_propositional,
+                );
+
+                let enqueue_decision =
+                    propagator.notify(context, propagator_var.variable, event.into());
+
+                if enqueue_decision == EnqueueDecision::Enqueue
+                    && !woken.contains(&propagator_var.propagator)
+                {
+                    woken.push(propagator_var.propagator);
+                }
+            }
+        }
+
+        woken
+    }
+}
@@ -7,6 +7,7 @@ mod preprocessor;
 pub(crate) mod proof;
 pub mod rp_engine;
 mod sat;
+mod search_tree_log;
 mod solver_statistics;
 pub(crate) mod termination;
 pub(crate) mod variables;
@@ -20,3 +21,4 @@ pub(crate) use debug_helper::DebugHelper;
 pub(crate) use domain_events::DomainEvents;
 pub(crate) use preprocessor::Preprocessor;
 pub use sat::*;
+pub use search_tree_log::SearchTreeLog;
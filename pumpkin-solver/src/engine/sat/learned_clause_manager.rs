@@ -118,6 +118,16 @@ impl LearnedClauseManager {
         unreachable!("This should always allocate a clause");
     }
 
+    /// Returns the references of all learned clauses which are currently in the database, both
+    /// those with low and those with high LBD.
+    pub(crate) fn learned_clause_references(&self) -> impl Iterator<Item = ClauseReference> + '_ {
+        self.learned_clauses
+            .low_lbd
+            .iter()
+            .chain(self.learned_clauses.high_lbd.iter())
+            .copied()
+    }
+
     pub(crate) fn shrink_learned_clause_database_if_needed(
         &mut self,
         assignments: &AssignmentsPropositional,
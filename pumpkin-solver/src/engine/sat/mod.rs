@@ -11,3 +11,4 @@ pub use learned_clause_manager::LearnedClauseSortingStrategy;
 pub use learned_clause_manager::LearningOptions;
 pub use restart_strategy::RestartOptions;
 pub(crate) use restart_strategy::RestartStrategy;
+pub(crate) use restart_strategy::PERTURBATION_FLIP_PROBABILITY;
@@ -8,7 +8,16 @@ use crate::basic_types::sequence_generators::GeometricSequence;
 use crate::basic_types::sequence_generators::LubySequence;
 use crate::basic_types::sequence_generators::SequenceGenerator;
 use crate::basic_types::sequence_generators::SequenceGeneratorType;
+use crate::basic_types::RestartOptionError;
+#[cfg(doc)]
+use crate::branching::Brancher;
 use crate::pumpkin_assert_simple;
+#[cfg(doc)]
+use crate::Solver;
+
+/// The probability with which every saved phase is flipped when the search is perturbed; see
+/// [`RestartOptions::perturb_after_blocked`].
+pub(crate) const PERTURBATION_FLIP_PROBABILITY: f64 = 0.1;
 
 /// The options which are used by the solver to determine when a restart should occur.
 ///
@@ -78,6 +87,11 @@ pub struct RestartOptions {
     pub geometric_coef: Option<f64>,
     /// Determines whether restarts should be able to occur
     pub no_restarts: bool,
+    /// If set to `Some(k)`, then after `k` consecutive blocked restarts the solver perturbs the
+    /// search instead: a fraction of the saved phases of the brancher is flipped at random (see
+    /// [`Brancher::on_perturbation`]). This prevents the search from being trapped in a region
+    /// where restarts keep being blocked.
+    pub perturb_after_blocked: Option<u64>,
 }
 
 impl Default for RestartOptions {
@@ -92,10 +106,83 @@ impl Default for RestartOptions {
             num_assigned_window: 5000,
             geometric_coef: None,
             no_restarts: false,
+            perturb_after_blocked: None,
         }
     }
 }
 
+impl RestartOptions {
+    /// Checks whether the options are consistent, returning the first [`RestartOptionError`]
+    /// which is found otherwise.
+    ///
+    /// This is called when the solver is created (see [`Solver::try_with_options`]), such that
+    /// invalid options are reported upfront rather than causing a panic during search.
+    pub fn validate(&self) -> Result<(), RestartOptionError> {
+        if self.base_interval == 0 {
+            return Err(RestartOptionError::ZeroBaseInterval);
+        }
+
+        if matches!(
+            self.sequence_generator_type,
+            SequenceGeneratorType::Geometric
+        ) {
+            let geometric_coef = self
+                .geometric_coef
+                .ok_or(RestartOptionError::MissingGeometricCoefficient)?;
+            if !geometric_coef.is_finite() || geometric_coef < 1.0 {
+                return Err(RestartOptionError::InvalidGeometricCoefficient(
+                    geometric_coef,
+                ));
+            }
+        }
+
+        if self.num_assigned_window == 0 {
+            return Err(RestartOptionError::ZeroNumAssignedWindow);
+        }
+
+        if !self.lbd_coef.is_finite() || self.lbd_coef <= 0.0 {
+            return Err(RestartOptionError::InvalidLbdCoefficient(self.lbd_coef));
+        }
+
+        if !self.num_assigned_coef.is_finite() || self.num_assigned_coef <= 0.0 {
+            return Err(RestartOptionError::InvalidNumAssignedCoefficient(
+                self.num_assigned_coef,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the options which are rejected by [`RestartOptions::validate`] with valid values
+    /// and returns the errors which were found. An invalid value is replaced by its default,
+    /// except for a geometric sequence without a valid coefficient, which is replaced by the
+    /// constant sequence.
+    pub(crate) fn replace_invalid_options(&mut self) -> Vec<RestartOptionError> {
+        let defaults = RestartOptions::default();
+
+        let mut errors = vec![];
+        while let Err(error) = self.validate() {
+            match error {
+                RestartOptionError::MissingGeometricCoefficient
+                | RestartOptionError::InvalidGeometricCoefficient(_) => {
+                    self.sequence_generator_type = SequenceGeneratorType::Constant
+                }
+                RestartOptionError::ZeroBaseInterval => self.base_interval = defaults.base_interval,
+                RestartOptionError::ZeroNumAssignedWindow => {
+                    self.num_assigned_window = defaults.num_assigned_window
+                }
+                RestartOptionError::InvalidLbdCoefficient(_) => self.lbd_coef = defaults.lbd_coef,
+                RestartOptionError::InvalidNumAssignedCoefficient(_) => {
+                    self.num_assigned_coef = defaults.num_assigned_coef
+                }
+            }
+            errors.push(error);
+        }
+
+        errors
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct RestartStrategy {
     /// A generator for determining how many conflicts should be found before the next restart is
@@ -130,8 +217,18 @@ pub(crate) struct RestartStrategy {
     number_of_restarts: u64,
     /// The number of restarts which have been blocked.
     number_of_blocked_restarts: u64,
+    /// The number of restarts which have been blocked since the last restart or perturbation.
+    number_of_consecutive_blocked_restarts: u64,
+    /// The number of consecutive blocked restarts after which the search is perturbed, if any.
+    perturb_after_blocked: Option<u64>,
+    /// Whether a perturbation should be performed; this flag is cleared in
+    /// [`RestartStrategy::notify_perturbation`].
+    perturbation_requested: bool,
     /// Determines whether restarts should be able to occur
     no_restarts: bool,
+    /// Whether a restart has been requested through [`RestartStrategy::force_restart`]; this flag
+    /// is cleared in [`RestartStrategy::notify_restart`].
+    restart_forced: bool,
 }
 
 impl Default for RestartStrategy {
@@ -175,7 +272,11 @@ impl RestartStrategy {
             )),
             number_of_restarts: 0,
             number_of_blocked_restarts: 0,
+            number_of_consecutive_blocked_restarts: 0,
+            perturb_after_blocked: options.perturb_after_blocked,
+            perturbation_requested: false,
             no_restarts: options.no_restarts,
+            restart_forced: false,
         }
     }
 
@@ -192,12 +293,20 @@ impl RestartStrategy {
     ///   average LBD is lower than the long-term average LBD multiplied by
     ///   [`RestartOptions::lbd_coef`], this condition determines whether the solver is learning
     ///   "bad" clauses based on the LBD; if it is learning "sufficiently bad" clauses then a
-    ///   restart will be performed.
+    ///   restart will be performed. If no LBDs have been recorded yet, then no restart is
+    ///   performed.
+    ///
+    /// These conditions are skipped if a restart has been requested through
+    /// [`RestartStrategy::force_restart`] (unless restarts are disabled altogether).
     pub(crate) fn should_restart(&self) -> bool {
         if self.no_restarts {
             return false;
         }
 
+        if self.restart_forced {
+            return true;
+        }
+
         // Do not restart until a certain number of conflicts take place before the first restart
         // this is done to collect some early runtime statistics for the restart strategy
         if self.number_of_restarts == 0
@@ -212,6 +321,12 @@ impl RestartStrategy {
         {
             return false;
         }
+        // Without any recorded LBDs, there is no evidence that the solver is learning "bad" clauses
+        if !self.lbd_long_term_moving_average.is_initialised()
+            || !self.lbd_short_term_moving_average.is_initialised()
+        {
+            return false;
+        }
         // Restarts can now be considered!
         // Only restart if the solver is learning "bad" clauses, this is the case if the long-term
         // average lbd multiplied by the `lbd_coefficient` is lower than the short-term average lbd
@@ -251,16 +366,49 @@ impl RestartStrategy {
         {
             // Restart has been blocked
             self.number_of_blocked_restarts += 1;
+            self.number_of_consecutive_blocked_restarts += 1;
+
+            if self
+                .perturb_after_blocked
+                .is_some_and(|limit| self.number_of_consecutive_blocked_restarts >= limit)
+            {
+                self.perturbation_requested = true;
+            }
+
             self.reset_values()
         }
     }
 
+    /// Determines whether the search should be perturbed, which is the case if
+    /// [`RestartOptions::perturb_after_blocked`] consecutive restarts have been blocked.
+    pub(crate) fn should_perturb(&self) -> bool {
+        self.perturbation_requested
+    }
+
+    /// Notifies the restart strategy that the search has been perturbed, after which the count of
+    /// consecutive blocked restarts starts again from zero.
+    pub(crate) fn notify_perturbation(&mut self) {
+        self.perturbation_requested = false;
+        self.number_of_consecutive_blocked_restarts = 0;
+    }
+
+    /// Requests a restart, causing [`RestartStrategy::should_restart`] to return `true` until the
+    /// next call to [`RestartStrategy::notify_restart`], regardless of the other conditions.
+    ///
+    /// This can be used to restart the solver based on external signals. Note that it has no
+    /// effect if restarts are disabled.
+    pub(crate) fn force_restart(&mut self) {
+        self.restart_forced = true;
+    }
+
     /// Notifies the restart strategy that a restart has taken place so that it can adjust its
     /// internal values
     pub(crate) fn notify_restart(&mut self) {
         pumpkin_assert_simple!(!self.no_restarts);
 
+        self.restart_forced = false;
         self.number_of_restarts += 1;
+        self.number_of_consecutive_blocked_restarts = 0;
         self.reset_values()
     }
 
@@ -276,3 +424,246 @@ impl RestartStrategy {
             .adapt(self.number_of_conflicts_until_restart);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_options_are_replaced() {
+        let mut options = RestartOptions {
+            sequence_generator_type: SequenceGeneratorType::Geometric,
+            geometric_coef: Some(0.5),
+            base_interval: 0,
+            lbd_coef: f64::NAN,
+            ..Default::default()
+        };
+
+        let errors = options.replace_invalid_options();
+        assert_eq!(3, errors.len());
+        assert_eq!(RestartOptionError::ZeroBaseInterval, errors[0]);
+        assert_eq!(
+            RestartOptionError::InvalidGeometricCoefficient(0.5),
+            errors[1]
+        );
+        assert!(matches!(
+            errors[2],
+            RestartOptionError::InvalidLbdCoefficient(_)
+        ));
+        assert!(options.validate().is_ok());
+        assert!(matches!(
+            options.sequence_generator_type,
+            SequenceGeneratorType::Constant
+        ));
+        assert_eq!(
+            RestartOptions::default().base_interval,
+            options.base_interval
+        );
+        assert_eq!(RestartOptions::default().lbd_coef, options.lbd_coef);
+    }
+
+    #[test]
+    fn valid_options_are_not_replaced() {
+        let mut options = RestartOptions {
+            sequence_generator_type: SequenceGeneratorType::Geometric,
+            geometric_coef: Some(1.5),
+            ..Default::default()
+        };
+
+        assert!(options.replace_invalid_options().is_empty());
+        assert_eq!(Some(1.5), options.geometric_coef);
+    }
+
+    #[test]
+    fn forced_restart_is_indicated_before_the_conditions_are_met() {
+        let mut restart_strategy = RestartStrategy::default();
+        restart_strategy.notify_conflict(2, 10);
+        assert!(!restart_strategy.should_restart());
+
+        restart_strategy.force_restart();
+        assert!(restart_strategy.should_restart());
+
+        restart_strategy.notify_restart();
+        assert!(!restart_strategy.should_restart());
+    }
+
+    #[test]
+    fn forced_restart_is_ignored_if_restarts_are_disabled() {
+        let mut restart_strategy = RestartStrategy::new(RestartOptions {
+            no_restarts: true,
+            ..Default::default()
+        });
+
+        restart_strategy.force_restart();
+        assert!(!restart_strategy.should_restart());
+    }
+
+    #[test]
+    fn no_restart_without_recorded_lbds() {
+        let mut restart_strategy = RestartStrategy::new(RestartOptions {
+            min_num_conflicts_before_first_restart: 0,
+            ..Default::default()
+        });
+        restart_strategy.number_of_conflicts_until_restart = 0;
+
+        assert!(!restart_strategy.should_restart());
+    }
+
+    #[test]
+    fn restart_with_recorded_lbds_of_zero() {
+        let mut restart_strategy = RestartStrategy::new(RestartOptions {
+            min_num_conflicts_before_first_restart: 0,
+            ..Default::default()
+        });
+        restart_strategy.number_of_conflicts_until_restart = 1;
+        restart_strategy.notify_conflict(0, 0);
+
+        assert!(restart_strategy.should_restart());
+    }
+
+    /// Creates a restart strategy which blocks the restart on every conflict which has more than
+    /// half of the average number of assigned variables on the trail.
+    fn create_blocking_restart_strategy(perturb_after_blocked: Option<u64>) -> RestartStrategy {
+        RestartStrategy::new(RestartOptions {
+            base_interval: 1,
+            min_num_conflicts_before_first_restart: 0,
+            num_assigned_coef: 0.5,
+            perturb_after_blocked,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn perturbation_is_requested_after_consecutive_blocked_restarts() {
+        let mut restart_strategy = create_blocking_restart_strategy(Some(3));
+
+        for _ in 0..2 {
+            restart_strategy.notify_conflict(2, 10);
+            assert!(!restart_strategy.should_perturb());
+        }
+        restart_strategy.notify_conflict(2, 10);
+        assert_eq!(3, restart_strategy.number_of_blocked_restarts);
+        assert!(restart_strategy.should_perturb());
+
+        restart_strategy.notify_perturbation();
+        assert!(!restart_strategy.should_perturb());
+
+        for _ in 0..2 {
+            restart_strategy.notify_conflict(2, 10);
+            assert!(!restart_strategy.should_perturb());
+        }
+        restart_strategy.notify_conflict(2, 10);
+        assert!(restart_strategy.should_perturb());
+    }
+
+    #[test]
+    fn restart_resets_the_consecutive_blocked_restarts() {
+        let mut restart_strategy = create_blocking_restart_strategy(Some(2));
+
+        restart_strategy.notify_conflict(2, 10);
+        restart_strategy.notify_restart();
+        restart_strategy.notify_conflict(2, 10);
+
+        assert_eq!(2, restart_strategy.number_of_blocked_restarts);
+        assert!(!restart_strategy.should_perturb());
+    }
+
+    #[test]
+    fn no_perturbation_by_default() {
+        let mut restart_strategy = create_blocking_restart_strategy(None);
+
+        for _ in 0..10 {
+            restart_strategy.notify_conflict(2, 10);
+        }
+
+        assert_eq!(10, restart_strategy.number_of_blocked_restarts);
+        assert!(!restart_strategy.should_perturb());
+    }
+
+    fn assert_invalid(options: RestartOptions, expected: RestartOptionError) {
+        assert_eq!(Err(expected), options.validate());
+    }
+
+    #[test]
+    fn default_options_are_valid() {
+        assert_eq!(Ok(()), RestartOptions::default().validate());
+    }
+
+    #[test]
+    fn geometric_sequence_without_coefficient_is_invalid() {
+        assert_invalid(
+            RestartOptions {
+                sequence_generator_type: SequenceGeneratorType::Geometric,
+                geometric_coef: None,
+                ..Default::default()
+            },
+            RestartOptionError::MissingGeometricCoefficient,
+        );
+    }
+
+    #[test]
+    fn geometric_coefficient_below_one_is_invalid() {
+        assert_invalid(
+            RestartOptions {
+                sequence_generator_type: SequenceGeneratorType::Geometric,
+                geometric_coef: Some(0.5),
+                ..Default::default()
+            },
+            RestartOptionError::InvalidGeometricCoefficient(0.5),
+        );
+    }
+
+    #[test]
+    fn geometric_coefficient_is_ignored_for_other_sequences() {
+        let options = RestartOptions {
+            sequence_generator_type: SequenceGeneratorType::Luby,
+            geometric_coef: Some(0.5),
+            ..Default::default()
+        };
+        assert_eq!(Ok(()), options.validate());
+    }
+
+    #[test]
+    fn zero_base_interval_is_invalid() {
+        assert_invalid(
+            RestartOptions {
+                base_interval: 0,
+                ..Default::default()
+            },
+            RestartOptionError::ZeroBaseInterval,
+        );
+    }
+
+    #[test]
+    fn zero_num_assigned_window_is_invalid() {
+        assert_invalid(
+            RestartOptions {
+                num_assigned_window: 0,
+                ..Default::default()
+            },
+            RestartOptionError::ZeroNumAssignedWindow,
+        );
+    }
+
+    #[test]
+    fn non_positive_lbd_coefficient_is_invalid() {
+        assert_invalid(
+            RestartOptions {
+                lbd_coef: 0.0,
+                ..Default::default()
+            },
+            RestartOptionError::InvalidLbdCoefficient(0.0),
+        );
+    }
+
+    #[test]
+    fn non_positive_num_assigned_coefficient_is_invalid() {
+        assert_invalid(
+            RestartOptions {
+                num_assigned_coef: -1.0,
+                ..Default::default()
+            },
+            RestartOptionError::InvalidNumAssignedCoefficient(-1.0),
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::io::Write;
+
+#[cfg(doc)]
+use crate::engine::SatisfactionSolverOptions;
+
+/// A log of the search tree which is explored by the solver, which can be used to visualise the
+/// search; see [`SatisfactionSolverOptions::search_tree_log`].
+///
+/// Every node of the tree is written as a single line of JSON once it has been propagated, e.g.
+/// `{"id":3,"parent":1,"decision":"[x >= 5]","outcome":"conflict"}`, where:
+/// - `id` is a unique identifier of the node, the root node has id 0;
+/// - `parent` is the id of the parent node, or `null` for the root node;
+/// - `decision` is the decision which was made in the node, or `null` if no decision was made
+///   (the root node, and the nodes in which a learned clause is propagated after backjumping);
+/// - `outcome` is either `"propagated"`, `"conflict"` or `"solution"`.
+///
+/// When a search is not completed (e.g. due to a time-out), the last node is reported as
+/// `"propagated"`. When the solver restarts, the subsequent decisions are children of the root
+/// node again. If writing to the log fails, a warning is logged and the rest of the search tree
+/// is not logged.
+///
+/// When a search tree should not be logged, use the implementation of [`Default`].
+#[derive(Default)]
+pub struct SearchTreeLog {
+    writer: Option<Box<dyn Write>>,
+    /// The id which is given to the next node.
+    next_id: u64,
+    /// The ids of the nodes on the path from the root to the current node; the node at index `i`
+    /// is the node of decision level `i`.
+    path: Vec<u64>,
+    /// The node which is being propagated, and whose outcome is thus not yet known.
+    pending_node: Option<PendingNode>,
+}
+
+#[derive(Debug)]
+struct PendingNode {
+    id: u64,
+    parent: Option<u64>,
+    decision: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Outcome {
+    Propagated,
+    Conflict,
+    Solution,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Propagated => write!(f, "propagated"),
+            Outcome::Conflict => write!(f, "conflict"),
+            Outcome::Solution => write!(f, "solution"),
+        }
+    }
+}
+
+impl Debug for SearchTreeLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchTreeLog")
+            .field("enabled", &self.writer.is_some())
+            .field("next_id", &self.next_id)
+            .finish()
+    }
+}
+
+impl SearchTreeLog {
+    /// Creates a log which writes the search tree to the provided writer.
+    pub fn new(writer: impl Write + 'static) -> SearchTreeLog {
+        SearchTreeLog {
+            writer: Some(Box::new(writer)),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.writer.is_some()
+    }
+
+    /// Starts a new search, of which the root node is propagated first.
+    pub(crate) fn start_search(&mut self) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        self.path.clear();
+        self.add_node(None, None);
+    }
+
+    /// Adds a node in which `decision` is made, as a child of the current node.
+    pub(crate) fn decide(&mut self, decision: impl Display) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        self.flush(Outcome::Propagated);
+        self.add_node(self.path.last().copied(), Some(decision.to_string()));
+    }
+
+    /// Adds a node without a decision as a child of the current node, in which the learned clause
+    /// is propagated after backjumping; it replaces the current node for the subsequent
+    /// decisions.
+    pub(crate) fn propagate_learned_clause(&mut self) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let parent = self.path.pop();
+        self.add_node(parent, None);
+    }
+
+    /// Removes the nodes above `decision_level` from the current path.
+    pub(crate) fn backtrack(&mut self, decision_level: usize) {
+        self.path.truncate(decision_level + 1);
+    }
+
+    /// Reports that the propagation of the current node led to a conflict.
+    pub(crate) fn conflict(&mut self) {
+        self.flush(Outcome::Conflict);
+    }
+
+    /// Reports that the current node is a solution.
+    pub(crate) fn solution(&mut self) {
+        self.flush(Outcome::Solution);
+    }
+
+    /// Reports the current node, if it has not been reported yet, at the end of a search.
+    pub(crate) fn finish_search(&mut self) {
+        self.flush(Outcome::Propagated);
+        if let Some(writer) = self.writer.as_mut() {
+            if let Err(error) = writer.flush() {
+                self.disable(error);
+            }
+        }
+    }
+
+    /// Stops logging the search tree after writing to the log failed, such that the search
+    /// itself is not interrupted.
+    fn disable(&mut self, error: std::io::Error) {
+        log::warn!("Failed to write search tree log, it is disabled from now on: {error}");
+        self.writer = None;
+        self.pending_node = None;
+        self.path.clear();
+    }
+
+    fn add_node(&mut self, parent: Option<u64>, decision: Option<String>) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.pending_node = Some(PendingNode {
+            id,
+            parent,
+            decision,
+        });
+        self.path.push(id);
+    }
+
+    fn flush(&mut self, outcome: Outcome) {
+        let (Some(writer), Some(node)) = (self.writer.as_mut(), self.pending_node.take()) else {
+            return;
+        };
+
+        let parent = node
+            .parent
+            .map_or_else(|| "null".to_owned(), |parent| parent.to_string());
+        let decision = node.decision.map_or_else(
+            || "null".to_owned(),
+            |decision| format!("\"{}\"", escape_json(&decision)),
+        );
+
+        let result = writeln!(
+            writer,
+            "{{\"id\":{},\"parent\":{parent},\"decision\":{decision},\"outcome\":\"{outcome}\"}}",
+            node.id
+        );
+        if let Err(error) = result {
+            self.disable(error);
+        }
+    }
+}
+
+fn escape_json(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len());
+    for c in string.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::escape_json;
+    use super::SearchTreeLog;
+
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk is full"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Err(std::io::Error::other("disk is full"))
+        }
+    }
+
+    #[test]
+    fn control_characters_are_escaped() {
+        assert_eq!(
+            escape_json("a\"b\\c\nd\te\u{1}"),
+            "a\\\"b\\\\c\\nd\\te\\u0001"
+        );
+    }
+
+    #[test]
+    fn failing_writer_disables_the_log() {
+        let mut log = SearchTreeLog::new(FailingWriter);
+
+        log.start_search();
+        log.decide("[x >= 5]");
+        assert!(!log.is_enabled());
+
+        log.decide("[x >= 6]");
+        log.conflict();
+        log.finish_search();
+        assert!(!log.is_enabled());
+    }
+}
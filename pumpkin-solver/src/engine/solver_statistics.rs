@@ -21,6 +21,8 @@ create_statistics_struct!(
         num_conflicts: u64,
         /// The number of times the solver has restarted
         num_restarts: u64,
+        /// The number of times the solver has perturbed its saved phases instead of restarting
+        num_perturbations: u64,
         /// The average number of (integer) propagations made by the solver
         num_propagations: u64,
         /// The amount of time which is spent in the solver
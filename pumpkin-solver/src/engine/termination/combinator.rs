@@ -1,3 +1,6 @@
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
 use super::TerminationCondition;
 
 /// A [`TerminationCondition`] which triggers when one of two given [`TerminationCondition`]s
@@ -22,3 +25,107 @@ impl<T1: TerminationCondition, T2: TerminationCondition> TerminationCondition
         self.t1.should_stop() || self.t2.should_stop()
     }
 }
+
+/// A [`TerminationCondition`] which triggers as soon as any of the given
+/// [`TerminationCondition`]s triggers, e.g. to stop after a time budget or after an external
+/// interrupt, whichever comes first.
+///
+/// Unlike [`Combinator`], the number of conditions does not have to be known at compile time. An
+/// empty [`TerminationAny`] never triggers.
+pub struct TerminationAny(Vec<Box<dyn TerminationCondition>>);
+
+impl TerminationAny {
+    /// Combine the given [`TerminationCondition`]s into one which triggers when any of them does.
+    pub fn new(conditions: Vec<Box<dyn TerminationCondition>>) -> Self {
+        TerminationAny(conditions)
+    }
+}
+
+impl Debug for TerminationAny {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TerminationAny")
+            .field("num_conditions", &self.0.len())
+            .finish()
+    }
+}
+
+impl TerminationCondition for TerminationAny {
+    fn should_stop(&mut self) -> bool {
+        self.0.iter_mut().any(|condition| condition.should_stop())
+    }
+}
+
+/// A [`TerminationCondition`] which triggers only once all of the given
+/// [`TerminationCondition`]s trigger.
+///
+/// An empty [`TerminationAll`] triggers immediately.
+pub struct TerminationAll(Vec<Box<dyn TerminationCondition>>);
+
+impl TerminationAll {
+    /// Combine the given [`TerminationCondition`]s into one which triggers when all of them do.
+    pub fn new(conditions: Vec<Box<dyn TerminationCondition>>) -> Self {
+        TerminationAll(conditions)
+    }
+}
+
+impl Debug for TerminationAll {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TerminationAll")
+            .field("num_conditions", &self.0.len())
+            .finish()
+    }
+}
+
+impl TerminationCondition for TerminationAll {
+    fn should_stop(&mut self) -> bool {
+        self.0.iter_mut().all(|condition| condition.should_stop())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::TerminationAll;
+    use super::TerminationAny;
+    use crate::constraints::all_different;
+    use crate::results::SatisfactionResult;
+    use crate::termination::Indefinite;
+    use crate::termination::TerminationCondition;
+    use crate::termination::TimeBudget;
+    use crate::Solver;
+
+    #[test]
+    fn any_of_with_an_exhausted_budget_stops_the_search_immediately() {
+        let mut solver = Solver::default();
+        let pigeons = (0..20)
+            .map(|_| solver.new_bounded_integer(1, 19))
+            .collect::<Vec<_>>();
+        let _ = solver.add_constraint(all_different(pigeons)).post();
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+
+        let mut termination = TerminationAny::new(vec![
+            Box::new(TimeBudget::starting_now(Duration::ZERO)),
+            Box::new(Indefinite),
+        ]);
+        let result = solver.satisfy(&mut brancher, &mut termination);
+
+        assert!(matches!(result, SatisfactionResult::Unknown));
+    }
+
+    #[test]
+    fn all_of_does_not_stop_while_one_condition_is_not_triggered() {
+        let mut termination = TerminationAll::new(vec![
+            Box::new(TimeBudget::starting_now(Duration::ZERO)),
+            Box::new(Indefinite),
+        ]);
+
+        assert!(!termination.should_stop());
+    }
+
+    #[test]
+    fn empty_combinators_follow_the_identity_of_their_operator() {
+        assert!(!TerminationAny::new(vec![]).should_stop());
+        assert!(TerminationAll::new(vec![]).should_stop());
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use super::TerminationCondition;
+
+/// A handle which can be used to interrupt the solver from another thread; it can be obtained
+/// through [`Solver::interrupt_handle`](crate::Solver::interrupt_handle).
+///
+/// Once [`InterruptHandle::interrupt`] is called, the solver stops at the next point at which it
+/// polls its termination conditions and reports that no conclusion could be made. The interrupt
+/// only applies to the ongoing search; the handle is reset whenever a new search starts.
+#[derive(Clone, Debug, Default)]
+pub struct InterruptHandle {
+    interrupted: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Request the solver to stop searching.
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::Relaxed);
+    }
+
+    /// Withdraws a previously requested interrupt.
+    pub fn reset(&self) {
+        self.interrupted.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an interrupt has been requested, `false` otherwise.
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+}
+
+impl TerminationCondition for InterruptHandle {
+    fn should_stop(&mut self) -> bool {
+        self.is_interrupted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::thread;
+
+    use crate::branching::Brancher;
+    use crate::branching::SelectionContext;
+    use crate::constraints::all_different;
+    use crate::predicates::Predicate;
+    use crate::results::SatisfactionResult;
+    use crate::termination::Indefinite;
+    use crate::Solver;
+
+    /// Creates a pigeonhole problem with 20 pigeons and 19 holes, which takes a long time to prove
+    /// unsatisfiable.
+    fn pigeonhole_solver() -> Solver {
+        let mut solver = Solver::default();
+        let pigeons = (0..20)
+            .map(|_| solver.new_bounded_integer(1, 19))
+            .collect::<Vec<_>>();
+        let _ = solver.add_constraint(all_different(pigeons)).post();
+        solver
+    }
+
+    /// A [`Brancher`] which forwards the decisions of `brancher`, and which performs `on_decision`
+    /// the first time that it is asked for a decision.
+    struct FirstDecisionBrancher<B, F> {
+        brancher: B,
+        on_decision: Option<F>,
+    }
+
+    impl<B: Brancher, F: FnOnce()> Brancher for FirstDecisionBrancher<B, F> {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            if let Some(on_decision) = self.on_decision.take() {
+                on_decision();
+            }
+            self.brancher.next_decision(context)
+        }
+    }
+
+    #[test]
+    fn interrupting_from_another_thread_stops_the_search() {
+        let mut solver = pigeonhole_solver();
+
+        // The other thread only interrupts once the search has started.
+        let (search_started, search_started_receiver) = mpsc::channel();
+        let handle = solver.interrupt_handle();
+        let interrupter = thread::spawn(move || {
+            search_started_receiver
+                .recv()
+                .expect("the search should signal that it started");
+            handle.interrupt();
+        });
+
+        let mut brancher = FirstDecisionBrancher {
+            brancher: solver.default_brancher_over_all_propositional_variables(),
+            on_decision: Some(move || search_started.send(()).unwrap()),
+        };
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        interrupter
+            .join()
+            .expect("the interrupting thread should not panic");
+
+        assert!(matches!(result, SatisfactionResult::Unknown));
+    }
+
+    #[test]
+    fn interrupt_does_not_carry_over_to_the_next_search() {
+        let mut solver = Solver::default();
+        let _ = solver.new_bounded_integer(0, 5);
+
+        let handle = solver.interrupt_handle();
+        let mut brancher = FirstDecisionBrancher {
+            brancher: solver.default_brancher_over_all_propositional_variables(),
+            on_decision: Some(move || handle.interrupt()),
+        };
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Unknown));
+        assert!(solver.interrupt_handle().is_interrupted());
+
+        let mut brancher = solver.default_brancher_over_all_propositional_variables();
+        let result = solver.satisfy(&mut brancher, &mut Indefinite);
+        assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+        assert!(!solver.interrupt_handle().is_interrupted());
+    }
+}
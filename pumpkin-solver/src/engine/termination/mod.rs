@@ -5,6 +5,7 @@
 
 pub(crate) mod combinator;
 pub(crate) mod indefinite;
+pub(crate) mod interrupt;
 pub(crate) mod os_signal;
 pub(crate) mod time_budget;
 
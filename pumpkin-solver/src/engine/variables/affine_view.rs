@@ -44,9 +44,26 @@ impl<Inner> AffineView<Inner> {
         }
     }
 
+    /// Returns the value in the domain of `self.inner` which is mapped to `value`, or [`None`] if
+    /// no such value exists (i.e. if `value - offset` is not divisible by the scale).
+    fn invert_exact(&self, value: i32) -> Option<i32> {
+        let inverted_translation = value - self.offset;
+
+        // The Euclidean remainder is used so that the divisibility check is independent of the
+        // signs of the scale and the translated value.
+        (inverted_translation.rem_euclid(self.scale) == 0)
+            .then(|| inverted_translation / self.scale)
+    }
+
     fn map(&self, value: i32) -> i32 {
         self.scale * value + self.offset
     }
+
+    /// Apply the transformation of this view on a value in `i64`, which cannot overflow for any
+    /// value of `self.inner` that fits in an `i32`.
+    fn map_i64(&self, value: i64) -> i64 {
+        self.scale as i64 * value + self.offset as i64
+    }
 }
 
 impl<View> IntegerVariable for AffineView<View>
@@ -71,15 +88,47 @@ where
         }
     }
 
-    fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
-        if (value - self.offset) % self.scale == 0 {
-            let inverted = self.invert(value, Rounding::Up);
-            self.inner.contains(assignment, inverted)
+    fn lower_bound_i64(&self, assignment: &AssignmentsInteger) -> i64 {
+        if self.scale < 0 {
+            self.map_i64(self.inner.upper_bound_i64(assignment))
         } else {
-            false
+            self.map_i64(self.inner.lower_bound_i64(assignment))
         }
     }
 
+    fn upper_bound_i64(&self, assignment: &AssignmentsInteger) -> i64 {
+        if self.scale < 0 {
+            self.map_i64(self.inner.lower_bound_i64(assignment))
+        } else {
+            self.map_i64(self.inner.upper_bound_i64(assignment))
+        }
+    }
+
+    fn has_representable_bounds(&self, assignment: &AssignmentsInteger) -> bool {
+        self.inner.has_representable_bounds(assignment)
+            && [
+                self.inner.lower_bound(assignment),
+                self.inner.upper_bound(assignment),
+            ]
+            .into_iter()
+            .all(|bound| {
+                self.scale
+                    .checked_mul(bound)
+                    .and_then(|scaled| scaled.checked_add(self.offset))
+                    .is_some()
+            })
+    }
+
+    fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool {
+        self.invert_exact(value)
+            .is_some_and(|inverted| self.inner.contains(assignment, inverted))
+    }
+
+    fn domain_size(&self, assignment: &AssignmentsInteger) -> u64 {
+        // An affine view maps every value of the inner domain to a distinct value.
+        self.inner.domain_size(assignment)
+    }
+
     fn describe_domain(&self, assignment: &AssignmentsInteger) -> Vec<Predicate> {
         // The description should not actually change. It is a description of the domain as seen by
         // the solver, not as seen by the user of this view.
@@ -92,11 +141,9 @@ where
         value: i32,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
-        if (value - self.offset) % self.scale == 0 {
-            let inverted = self.invert(value, Rounding::Up);
-            self.inner.remove(assignment, inverted, reason)
-        } else {
-            Ok(())
+        match self.invert_exact(value) {
+            Some(inverted) => self.inner.remove(assignment, inverted, reason),
+            None => Ok(()),
         }
     }
 
@@ -163,6 +210,16 @@ where
             self.inner.unpack_event(event)
         }
     }
+
+    fn decompose(&self) -> (DomainId, i64, i64) {
+        let (domain, inner_scale, inner_offset) = self.inner.decompose();
+
+        (
+            domain,
+            self.scale as i64 * inner_scale,
+            self.scale as i64 * inner_offset + self.offset as i64,
+        )
+    }
 }
 
 impl<View> TransformableVariable<AffineView<View>> for AffineView<View>
@@ -227,20 +284,16 @@ impl<Var: PredicateConstructor<Value = i32>> PredicateConstructor for AffineView
     }
 
     fn equality_predicate(&self, bound: Self::Value) -> Predicate {
-        if (bound - self.offset) % self.scale == 0 {
-            let inverted_bound = self.invert(bound, Rounding::Up);
-            self.inner.equality_predicate(inverted_bound)
-        } else {
-            Predicate::False
+        match self.invert_exact(bound) {
+            Some(inverted_bound) => self.inner.equality_predicate(inverted_bound),
+            None => Predicate::False,
         }
     }
 
     fn disequality_predicate(&self, bound: Self::Value) -> Predicate {
-        if (bound - self.offset) % self.scale == 0 {
-            let inverted_bound = self.invert(bound, Rounding::Up);
-            self.inner.disequality_predicate(inverted_bound)
-        } else {
-            Predicate::True
+        match self.invert_exact(bound) {
+            Some(inverted_bound) => self.inner.disequality_predicate(inverted_bound),
+            None => Predicate::True,
         }
     }
 }
@@ -259,8 +312,40 @@ enum Rounding {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::variables::FlattenedVariable;
     use crate::predicate;
 
+    #[test]
+    fn flattened_affine_view_has_its_scale_and_offset() {
+        let domain = DomainId::new(0);
+        let view = AffineView::new(domain, -3, 4);
+
+        assert_eq!(
+            FlattenedVariable {
+                id: domain,
+                scale: -3,
+                offset: 4
+            },
+            view.flatten()
+        );
+    }
+
+    #[test]
+    fn flattened_nested_affine_view_is_collapsed() {
+        let domain = DomainId::new(0);
+        // 2 * (-3x + 4) + 1 = -6x + 9
+        let view = AffineView::new(AffineView::new(domain, -3, 4), 2, 1);
+
+        assert_eq!(
+            FlattenedVariable {
+                id: domain,
+                scale: -6,
+                offset: 9
+            },
+            view.flatten()
+        );
+    }
+
     #[test]
     fn scaling_an_affine_view() {
         let view = AffineView::new(DomainId::new(0), 3, 4);
@@ -300,4 +385,117 @@ mod tests {
         assert_eq!(predicate!(view <= -3), predicate!(domain >= 2));
         assert_eq!(predicate!(view >= 5), predicate!(domain <= -3));
     }
+
+    /// Returns the value in `-30..=30` which `view` maps to `value`, found by enumeration.
+    fn enumerate_preimage(view: &AffineView<DomainId>, value: i32) -> Option<i32> {
+        (-30..=30).find(|&inner| view.map(inner) == value)
+    }
+
+    #[test]
+    fn equality_predicate_matches_enumerated_preimage_for_negative_scales() {
+        let domain = DomainId::new(0);
+
+        for scale in [-3, -2, -1] {
+            for offset in [-4, 0, 5] {
+                let view = AffineView::new(domain, scale, offset);
+
+                for value in -20..=20 {
+                    let expected = enumerate_preimage(&view, value)
+                        .map_or(Predicate::False, |inner| predicate!(domain == inner));
+                    assert_eq!(
+                        expected,
+                        view.equality_predicate(value),
+                        "wrong equality predicate for {view:?} == {value}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn disequality_predicate_matches_enumerated_preimage_for_negative_scales() {
+        let domain = DomainId::new(0);
+
+        for scale in [-3, -2, -1] {
+            for offset in [-4, 0, 5] {
+                let view = AffineView::new(domain, scale, offset);
+
+                for value in -20..=20 {
+                    let expected = enumerate_preimage(&view, value)
+                        .map_or(Predicate::True, |inner| predicate!(domain != inner));
+                    assert_eq!(
+                        expected,
+                        view.disequality_predicate(value),
+                        "wrong disequality predicate for {view:?} != {value}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn setting_bounds_of_scaled_view_rounds_to_values_in_the_view() {
+        for scale in [-3, -2, 2, 3] {
+            for offset in [-4, 0, 5] {
+                for value in -20..=20 {
+                    let mut assignment = AssignmentsInteger::default();
+                    let view = AffineView::new(assignment.grow(-5, 5), scale, offset);
+                    let expected_lower_bound = view
+                        .iter_domain_values(&assignment)
+                        .find(|&view_value| view_value >= value);
+
+                    let result = view.set_lower_bound(&mut assignment, value, None);
+                    match expected_lower_bound {
+                        Some(bound) => assert_eq!(bound, view.lower_bound(&assignment)),
+                        None => assert!(result.is_err()),
+                    }
+
+                    let mut assignment = AssignmentsInteger::default();
+                    let view = AffineView::new(assignment.grow(-5, 5), scale, offset);
+                    let expected_upper_bound = view
+                        .iter_domain_values(&assignment)
+                        .filter(|&view_value| view_value <= value)
+                        .last();
+
+                    let result = view.set_upper_bound(&mut assignment, value, None);
+                    match expected_upper_bound {
+                        Some(bound) => assert_eq!(bound, view.upper_bound(&assignment)),
+                        None => assert!(result.is_err()),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn i64_bounds_of_view_beyond_i32_are_exact() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(-1_000_000, 1_000_000);
+
+        let view = domain.scaled(10_000).offset(5);
+        assert!(!view.has_representable_bounds(&assignment));
+        assert_eq!(-9_999_999_995, view.lower_bound_i64(&assignment));
+        assert_eq!(10_000_000_005, view.upper_bound_i64(&assignment));
+
+        let negated_view = domain.scaled(-10_000).offset(5);
+        assert_eq!(-9_999_999_995, negated_view.lower_bound_i64(&assignment));
+        assert_eq!(10_000_000_005, negated_view.upper_bound_i64(&assignment));
+    }
+
+    #[test]
+    fn i64_bounds_match_i32_bounds_when_representable() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(-7, 12);
+
+        for view in [domain.scaled(3).offset(-2), domain.scaled(-5).offset(4)] {
+            assert_eq!(
+                view.lower_bound(&assignment) as i64,
+                view.lower_bound_i64(&assignment)
+            );
+            assert_eq!(
+                view.upper_bound(&assignment) as i64,
+                view.upper_bound_i64(&assignment)
+            );
+        }
+    }
 }
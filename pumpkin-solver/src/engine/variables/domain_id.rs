@@ -40,6 +40,10 @@ impl IntegerVariable for DomainId {
         assignment.is_value_in_domain(*self, value)
     }
 
+    fn domain_size(&self, assignment: &AssignmentsInteger) -> u64 {
+        assignment.get_domain_size(*self) as u64
+    }
+
     fn describe_domain(&self, assignment: &AssignmentsInteger) -> Vec<Predicate> {
         assignment.get_domain_description(*self)
     }
@@ -82,6 +86,10 @@ impl IntegerVariable for DomainId {
     fn unpack_event(&self, event: OpaqueDomainEvent) -> IntDomainEvent {
         event.unwrap()
     }
+
+    fn decompose(&self) -> (DomainId, i64, i64) {
+        (*self, 1, 0)
+    }
 }
 
 impl TransformableVariable<AffineView<DomainId>> for DomainId {
@@ -0,0 +1,12 @@
+use crate::engine::variables::DomainId;
+#[cfg(doc)]
+use crate::engine::variables::IntegerVariable;
+
+/// The representation of an [`IntegerVariable`] as the affine transformation
+/// `scale * id + offset` of a single [`DomainId`]; see [`IntegerVariable::flatten`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlattenedVariable {
+    pub id: DomainId,
+    pub scale: i64,
+    pub offset: i64,
+}
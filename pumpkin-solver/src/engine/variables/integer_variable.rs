@@ -5,6 +5,8 @@ use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::predicates::predicate_constructor::PredicateConstructor;
 use crate::engine::reason::ReasonRef;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::FlattenedVariable;
 use crate::engine::AssignmentsInteger;
 use crate::engine::EmptyDomain;
 use crate::engine::IntDomainEvent;
@@ -24,9 +26,44 @@ pub trait IntegerVariable:
     /// Get the upper bound of the variable.
     fn upper_bound(&self, assignment: &AssignmentsInteger) -> i32;
 
+    /// Get the lower bound of the variable as an `i64`.
+    ///
+    /// Unlike [`IntegerVariable::lower_bound`], this does not overflow for views which scale or
+    /// offset a variable beyond the range of an `i32`.
+    fn lower_bound_i64(&self, assignment: &AssignmentsInteger) -> i64 {
+        self.lower_bound(assignment) as i64
+    }
+
+    /// Get the upper bound of the variable as an `i64`; see
+    /// [`IntegerVariable::lower_bound_i64`].
+    fn upper_bound_i64(&self, assignment: &AssignmentsInteger) -> i64 {
+        self.upper_bound(assignment) as i64
+    }
+
     /// Determine whether the value is in the domain of this variable.
     fn contains(&self, assignment: &AssignmentsInteger, value: i32) -> bool;
 
+    /// Get the number of values in the domain of this variable.
+    fn domain_size(&self, assignment: &AssignmentsInteger) -> u64;
+
+    /// Determine whether both bounds of this variable can be represented by an `i32`. This may not
+    /// be the case for views which scale or offset a variable with a large domain.
+    fn has_representable_bounds(&self, _assignment: &AssignmentsInteger) -> bool {
+        true
+    }
+
+    /// Iterate over the values in the domain of this variable in ascending order.
+    ///
+    /// Every value between the bounds is checked with [`IntegerVariable::contains`], so this
+    /// should only be used for variables with small domains.
+    fn iter_domain_values<'a>(
+        &'a self,
+        assignment: &'a AssignmentsInteger,
+    ) -> impl Iterator<Item = i32> + 'a {
+        (self.lower_bound(assignment)..=self.upper_bound(assignment))
+            .filter(move |&value| self.contains(assignment, value))
+    }
+
     /// Get a predicate description (bounds + holes) of the domain of this variable.
     /// N.B. can be very expensive with large domains, and very large with holey domains
     ///
@@ -44,6 +81,9 @@ pub trait IntegerVariable:
     ) -> Result<(), EmptyDomain>;
 
     /// Tighten the lower bound of the domain of this variable.
+    ///
+    /// If `value` is not in the domain (e.g. because a view scales the values of its domain), then
+    /// the new lower bound is the smallest value in the domain which is larger than `value`.
     fn set_lower_bound(
         &self,
         assignment: &mut AssignmentsInteger,
@@ -52,6 +92,9 @@ pub trait IntegerVariable:
     ) -> Result<(), EmptyDomain>;
 
     /// Tighten the upper bound of the domain of this variable.
+    ///
+    /// If `value` is not in the domain (e.g. because a view scales the values of its domain), then
+    /// the new upper bound is the largest value in the domain which is smaller than `value`.
     fn set_upper_bound(
         &self,
         assignment: &mut AssignmentsInteger,
@@ -66,4 +109,129 @@ pub trait IntegerVariable:
 
     /// Decode a domain event for this variable.
     fn unpack_event(&self, event: OpaqueDomainEvent) -> IntDomainEvent;
+
+    /// Decompose this variable into the [`DomainId`] it is defined over, together with the scale
+    /// and offset which are applied to it; i.e. the variable is equal to `scale * domain + offset`.
+    fn decompose(&self) -> (DomainId, i64, i64);
+
+    /// Returns the representation of this variable as an affine transformation of a single
+    /// [`DomainId`]; nested views are collapsed into a single scale and offset.
+    ///
+    /// This gives a uniform way to extract the coefficients of a linear expression over arbitrary
+    /// variables.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::variables::FlattenedVariable;
+    /// # use pumpkin_solver::variables::IntegerVariable;
+    /// # use pumpkin_solver::variables::TransformableVariable;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    /// let x = solver.new_bounded_integer(0, 10);
+    ///
+    /// // 2 * (3x + 1) - 5 = 6x - 3
+    /// let view = x.scaled(3).offset(1).scaled(2).offset(-5);
+    /// assert_eq!(
+    ///     FlattenedVariable {
+    ///         id: x,
+    ///         scale: 6,
+    ///         offset: -3
+    ///     },
+    ///     view.flatten()
+    /// );
+    /// ```
+    fn flatten(&self) -> FlattenedVariable {
+        let (id, scale, offset) = self.decompose();
+        FlattenedVariable { id, scale, offset }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flattened_domain_id_is_the_identity() {
+        let domain = DomainId::new(3);
+
+        assert_eq!(
+            FlattenedVariable {
+                id: domain,
+                scale: 1,
+                offset: 0
+            },
+            domain.flatten()
+        );
+    }
+
+    #[test]
+    fn iterating_domain_values_skips_holes() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(1, 6);
+
+        let _ = domain.remove(&mut assignment, 2, None);
+        let _ = domain.remove(&mut assignment, 5, None);
+
+        let values = domain.iter_domain_values(&assignment).collect::<Vec<_>>();
+        assert_eq!(vec![1, 3, 4, 6], values);
+    }
+
+    #[test]
+    fn iterating_domain_values_of_negated_view_is_ascending() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(1, 4);
+        let _ = domain.remove(&mut assignment, 3, None);
+
+        let view = domain.scaled(-2).offset(1);
+
+        let values = view.iter_domain_values(&assignment).collect::<Vec<_>>();
+        assert_eq!(vec![-7, -3, -1], values);
+    }
+
+    fn assert_domain_size_matches_enumeration(assignment: &AssignmentsInteger, domain: DomainId) {
+        let expected = domain.iter_domain_values(assignment).count() as u64;
+        assert_eq!(expected, domain.domain_size(assignment));
+
+        let view = domain.scaled(-3).offset(2);
+        assert_eq!(expected, view.domain_size(assignment));
+    }
+
+    #[test]
+    fn domain_size_matches_enumeration_for_holey_domains() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(1, 20);
+        assert_domain_size_matches_enumeration(&assignment, domain);
+
+        for value in [3, 7, 8, 20, 1] {
+            let _ = domain.remove(&mut assignment, value, None);
+            assert_domain_size_matches_enumeration(&assignment, domain);
+        }
+
+        let _ = domain.set_lower_bound(&mut assignment, 5, None);
+        assert_domain_size_matches_enumeration(&assignment, domain);
+
+        let _ = domain.set_upper_bound(&mut assignment, 9, None);
+        assert_domain_size_matches_enumeration(&assignment, domain);
+
+        let _ = domain.set_upper_bound(&mut assignment, 7, None);
+        assert_domain_size_matches_enumeration(&assignment, domain);
+        assert_eq!(2, domain.domain_size(&assignment));
+    }
+
+    #[test]
+    fn domain_size_is_restored_when_backtracking() {
+        let mut assignment = AssignmentsInteger::default();
+        let domain = assignment.grow(-5, 5);
+        let _ = domain.remove(&mut assignment, 0, None);
+
+        assignment.increase_decision_level();
+        let _ = domain.remove(&mut assignment, 2, None);
+        let _ = domain.set_lower_bound(&mut assignment, -2, None);
+        let _ = domain.set_upper_bound(&mut assignment, 3, None);
+        assert_domain_size_matches_enumeration(&assignment, domain);
+
+        let _ = assignment.synchronise(0, false, 0);
+        assert_domain_size_matches_enumeration(&assignment, domain);
+        assert_eq!(10, domain.domain_size(&assignment));
+    }
 }
@@ -5,17 +5,21 @@
 mod affine_view;
 mod domain_generator_iterator;
 mod domain_id;
+mod flattened_variable;
 mod integer_variable;
 mod literal;
 mod propositional_variable;
 mod propositional_variable_generator_iterator;
+mod set_variable;
 mod transformable_variable;
 
 pub use affine_view::AffineView;
 pub(crate) use domain_generator_iterator::DomainGeneratorIterator;
 pub use domain_id::DomainId;
+pub use flattened_variable::FlattenedVariable;
 pub use integer_variable::IntegerVariable;
 pub use literal::Literal;
 pub use propositional_variable::PropositionalVariable;
 pub(crate) use propositional_variable_generator_iterator::PropositionalVariableGeneratorIterator;
+pub use set_variable::SetVariable;
 pub use transformable_variable::TransformableVariable;
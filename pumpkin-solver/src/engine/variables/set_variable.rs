@@ -0,0 +1,54 @@
+use crate::engine::variables::DomainId;
+#[cfg(doc)]
+use crate::Solver;
+
+/// A variable whose value is a subset of a fixed universe of integers; see
+/// [`Solver::new_set_variable`].
+///
+/// Every element of the universe has a 0/1 [`DomainId`] which indicates whether the element is a
+/// member of the set, such that constraints over sets can be defined in terms of the existing
+/// integer constraints.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SetVariable {
+    /// The elements which can be in the set, sorted in increasing order.
+    universe: Box<[i32]>,
+    /// The value at index `i` indicates whether `universe[i]` is a member of the set.
+    members: Box<[DomainId]>,
+}
+
+impl SetVariable {
+    /// Creates a set over `universe`, which should be sorted and free of duplicates, where
+    /// `members[i]` is the membership variable of `universe[i]`.
+    pub(crate) fn new(universe: Box<[i32]>, members: Box<[DomainId]>) -> SetVariable {
+        assert_eq!(universe.len(), members.len());
+        SetVariable { universe, members }
+    }
+
+    /// Returns the elements which can be in the set, in increasing order.
+    pub fn universe(&self) -> &[i32] {
+        &self.universe
+    }
+
+    /// Returns the 0/1 membership variables, in the order of [`SetVariable::universe`].
+    pub fn members(&self) -> &[DomainId] {
+        &self.members
+    }
+
+    /// Returns the membership variable of `element`, or [`None`] if `element` is not in the
+    /// universe of the set.
+    pub fn membership(&self, element: i32) -> Option<DomainId> {
+        self.universe
+            .binary_search(&element)
+            .ok()
+            .map(|index| self.members[index])
+    }
+
+    /// Returns an iterator over the elements of the universe together with their membership
+    /// variables.
+    pub fn iter(&self) -> impl Iterator<Item = (i32, DomainId)> + '_ {
+        self.universe
+            .iter()
+            .copied()
+            .zip(self.members.iter().copied())
+    }
+}
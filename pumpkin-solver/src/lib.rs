@@ -143,7 +143,7 @@
 //! // Then we solve to optimality
 //! let result = solver.minimise(&mut brancher, &mut termination, objective);
 //!
-//! if let OptimisationResult::Optimal(optimal_solution) = result {
+//! if let OptimisationResult::Optimal { solution: optimal_solution, .. } = result {
 //!     let value_x = optimal_solution.get_integer_value(x);
 //!     let value_y = optimal_solution.get_integer_value(y);
 //!     let value_z = optimal_solution.get_integer_value(z);
@@ -312,4 +312,7 @@ pub use api::*;
 pub use crate::api::solver::DefaultBrancher;
 pub use crate::api::solver::Solver;
 pub use crate::basic_types::ConstraintOperationError;
+pub use crate::basic_types::LinearTextError;
 pub use crate::basic_types::Random;
+pub use crate::basic_types::RestartOptionError;
+pub use crate::basic_types::VariableCreationError;
@@ -2,11 +2,15 @@
 
 pub(crate) trait NumExt {
     /// Division with rounding up.
+    ///
+    /// If the result does not fit in `Self` (i.e. `MIN / -1`), it saturates to `MAX`.
     fn div_ceil(self, other: Self) -> Self;
 
     /// Division with rounding down.
     ///
     /// Note this is different from truncating, which is rounding toward zero.
+    ///
+    /// If the result does not fit in `Self` (i.e. `MIN / -1`), it saturates to `MAX`.
     fn div_floor(self, other: Self) -> Self;
 }
 
@@ -15,6 +19,11 @@ impl NumExt for i32 {
         // TODO: The source is taken from the standard library nightly implementation of this
         // function and div_floor. Once they are stabilized, these definitions can be removed.
         // Tracking issue: https://github.com/rust-lang/rust/issues/88581
+        if self == i32::MIN && other == -1 {
+            // The exact result is `i32::MAX + 1`, which cannot be represented.
+            return i32::MAX;
+        }
+
         let d = self / other;
         let r = self % other;
         if (r > 0 && other > 0) || (r < 0 && other < 0) {
@@ -26,6 +35,10 @@ impl NumExt for i32 {
 
     fn div_floor(self, other: Self) -> Self {
         // TODO: See todo in `div_ceil`.
+        if self == i32::MIN && other == -1 {
+            return i32::MAX;
+        }
+
         let d = self / other;
         let r = self % other;
         if (r > 0 && other < 0) || (r < 0 && other > 0) {
@@ -35,3 +48,30 @@ impl NumExt for i32 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn div_ceil_of_min_by_minus_one_saturates() {
+        assert_eq!(i32::MAX, <i32 as NumExt>::div_ceil(i32::MIN, -1));
+    }
+
+    #[test]
+    fn div_floor_of_min_by_minus_one_saturates() {
+        assert_eq!(i32::MAX, <i32 as NumExt>::div_floor(i32::MIN, -1));
+    }
+
+    #[test]
+    fn div_ceil_of_min_by_positive_divisor() {
+        assert_eq!(-1_073_741_824, <i32 as NumExt>::div_ceil(i32::MIN, 2));
+        assert_eq!(-715_827_882, <i32 as NumExt>::div_ceil(i32::MIN, 3));
+    }
+
+    #[test]
+    fn div_floor_of_min_by_positive_divisor() {
+        assert_eq!(-1_073_741_824, <i32 as NumExt>::div_floor(i32::MIN, 2));
+        assert_eq!(-715_827_883, <i32 as NumExt>::div_floor(i32::MIN, 3));
+    }
+}
@@ -3,10 +3,12 @@ use crate::conjunction;
 use crate::engine::cp::propagation::ReadDomains;
 use crate::engine::domain_events::DomainEvents;
 use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
 use crate::engine::variables::IntegerVariable;
+use crate::predicates::PropositionalConjunction;
 
 /// Propagator for `absolute = |signed|`, where `absolute` and `signed` are integer variables.
 ///
@@ -28,7 +30,7 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
-    ) -> Result<(), crate::predicates::PropositionalConjunction> {
+    ) -> Result<(), PropositionalConjunction> {
         let _ = context.register(self.signed.clone(), DomainEvents::BOUNDS, LocalId::from(0));
         let _ = context.register(
             self.absolute.clone(),
@@ -47,6 +49,37 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
         "IntAbs"
     }
 
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        let signed_lb = context.lower_bound(&self.signed);
+        let signed_ub = context.upper_bound(&self.signed);
+        let absolute_lb = context.lower_bound(&self.absolute);
+        let absolute_ub = context.upper_bound(&self.absolute);
+
+        if absolute_ub < 0 {
+            // The absolute value can never be negative.
+            Some(conjunction!([self.absolute <= absolute_ub]))
+        } else if signed_lb > absolute_ub {
+            Some(conjunction!(
+                [self.signed >= signed_lb] & [self.absolute <= absolute_ub]
+            ))
+        } else if signed_ub < -absolute_ub {
+            Some(conjunction!(
+                [self.signed <= signed_ub] & [self.absolute <= absolute_ub]
+            ))
+        } else if i32::max(signed_lb.abs(), signed_ub.abs()) < absolute_lb {
+            Some(conjunction!(
+                [self.signed >= signed_lb]
+                    & [self.signed <= signed_ub]
+                    & [self.absolute >= absolute_lb]
+            ))
+        } else {
+            None
+        }
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
@@ -122,6 +155,7 @@ impl<VA: IntegerVariable, VB: IntegerVariable> Propagator for AbsoluteValuePropa
 mod tests {
     use super::*;
     use crate::engine::test_helper::TestSolver;
+    use crate::propagators::ReifiedPropagator;
 
     #[test]
     fn absolute_bounds_are_propagated_at_initialise() {
@@ -206,4 +240,40 @@ mod tests {
 
         solver.assert_bounds(signed, 3, 5);
     }
+
+    #[test]
+    fn reification_literal_is_propagated_to_false_when_absolute_is_too_large() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-4, 2);
+        let absolute = solver.new_variable(7, 7);
+        let reification_literal = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(ReifiedPropagator::new(
+                AbsoluteValuePropagator::new(signed, absolute),
+                reification_literal,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(reification_literal));
+    }
+
+    #[test]
+    fn reification_literal_is_propagated_to_false_when_absolute_is_too_small() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(2, 4);
+        let absolute = solver.new_variable(1, 1);
+        let reification_literal = solver.new_literal();
+
+        let _ = solver
+            .new_propagator(ReifiedPropagator::new(
+                AbsoluteValuePropagator::new(signed, absolute),
+                reification_literal,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(reification_literal));
+    }
 }
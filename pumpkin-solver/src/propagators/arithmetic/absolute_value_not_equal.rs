@@ -0,0 +1,157 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// Propagator for `absolute != |signed|`, where `absolute` and `signed` are integer variables.
+///
+/// This is the negation of the constraint enforced by
+/// [`AbsoluteValuePropagator`](super::absolute_value::AbsoluteValuePropagator). The propagator
+/// only propagates once one of the two variables is fixed, by removing the corresponding value(s)
+/// from the domain of the other variable.
+#[derive(Clone, Debug)]
+pub(crate) struct AbsoluteValueNotEqualPropagator<VA, VB> {
+    signed: VA,
+    absolute: VB,
+}
+
+impl<VA, VB> AbsoluteValueNotEqualPropagator<VA, VB> {
+    pub(crate) fn new(signed: VA, absolute: VB) -> Self {
+        AbsoluteValueNotEqualPropagator { signed, absolute }
+    }
+}
+
+impl<VA: IntegerVariable, VB: IntegerVariable> Propagator
+    for AbsoluteValueNotEqualPropagator<VA, VB>
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.signed.clone(), DomainEvents::ASSIGN, LocalId::from(0));
+        let _ = context.register(
+            self.absolute.clone(),
+            DomainEvents::ASSIGN,
+            LocalId::from(1),
+        );
+
+        if let Some(conjunction) = self.detect_inconsistency(context.as_readonly()) {
+            Err(conjunction)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &str {
+        "IntAbsNe"
+    }
+
+    fn detect_inconsistency(
+        &self,
+        context: PropagationContext,
+    ) -> Option<PropositionalConjunction> {
+        if !context.is_fixed(&self.signed) || !context.is_fixed(&self.absolute) {
+            return None;
+        }
+
+        let signed_value = context.lower_bound(&self.signed);
+        let absolute_value = context.lower_bound(&self.absolute);
+
+        if absolute_value == signed_value.abs() {
+            Some(conjunction!(
+                [self.signed == signed_value] & [self.absolute == absolute_value]
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        if context.is_fixed(&self.signed) {
+            let signed_value = context.lower_bound(&self.signed);
+            context.remove(
+                &self.absolute,
+                signed_value.abs(),
+                conjunction!([self.signed == signed_value]),
+            )?;
+        }
+
+        if context.is_fixed(&self.absolute) {
+            let absolute_value = context.lower_bound(&self.absolute);
+            context.remove(
+                &self.signed,
+                absolute_value,
+                conjunction!([self.absolute == absolute_value]),
+            )?;
+            context.remove(
+                &self.signed,
+                -absolute_value,
+                conjunction!([self.absolute == absolute_value]),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn fixed_signed_removes_its_absolute_value() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-3, -3);
+        let absolute = solver.new_variable(0, 5);
+
+        let _ = solver
+            .new_propagator(AbsoluteValueNotEqualPropagator::new(signed, absolute))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(absolute, 3));
+        solver.assert_bounds(absolute, 0, 5);
+    }
+
+    #[test]
+    fn fixed_absolute_removes_both_signed_values() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-5, 5);
+        let absolute = solver.new_variable(2, 2);
+
+        let _ = solver
+            .new_propagator(AbsoluteValueNotEqualPropagator::new(signed, absolute))
+            .expect("no empty domains");
+
+        assert!(!solver.contains(signed, 2));
+        assert!(!solver.contains(signed, -2));
+        assert!(solver.contains(signed, 0));
+    }
+
+    #[test]
+    fn equal_fixed_values_are_inconsistent() {
+        let mut solver = TestSolver::default();
+
+        let signed = solver.new_variable(-4, -4);
+        let absolute = solver.new_variable(4, 4);
+
+        let result = solver.new_propagator(AbsoluteValueNotEqualPropagator::new(signed, absolute));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Bounds-consistent propagator for the constraint `\sum x_i = c`.
+///
+/// Both the upper bound (`ub(x_i) <= c - \sum_{j != i} lb(x_j)`) and the lower bound
+/// (`lb(x_i) >= c - \sum_{j != i} ub(x_j)`) of every term are tightened by the same propagator,
+/// which is repeated until a fixpoint is reached. This replaces the pair of propagators for
+/// `\sum x_i <= c` and `\sum -x_i <= -c`.
+#[derive(Clone, Debug)]
+pub(crate) struct LinearEqualPropagator<Var> {
+    x: Box<[Var]>,
+    c: i32,
+}
+
+impl<Var> LinearEqualPropagator<Var>
+where
+    Var: IntegerVariable,
+{
+    pub(crate) fn new(x: Box<[Var]>, c: i32) -> Self {
+        LinearEqualPropagator { x, c }
+    }
+
+    /// Explains why the sum of the terms other than `x[excluded]` is at least its current lower
+    /// bound.
+    fn explain_lower_bounds(
+        &self,
+        context: PropagationContext,
+        excluded: Option<usize>,
+    ) -> PropositionalConjunction {
+        self.x
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| Some(index) != excluded)
+            .map(|(_, x_j)| predicate![x_j >= context.lower_bound(x_j)])
+            .collect()
+    }
+
+    /// Explains why the sum of the terms other than `x[excluded]` is at most its current upper
+    /// bound.
+    fn explain_upper_bounds(
+        &self,
+        context: PropagationContext,
+        excluded: Option<usize>,
+    ) -> PropositionalConjunction {
+        self.x
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| Some(index) != excluded)
+            .map(|(_, x_j)| predicate![x_j <= context.upper_bound(x_j)])
+            .collect()
+    }
+}
+
+impl<Var: 'static> Propagator for LinearEqualPropagator<Var>
+where
+    Var: IntegerVariable,
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.x.iter().enumerate().for_each(|(index, x_i)| {
+            let _ = context.register(
+                x_i.clone(),
+                DomainEvents::BOUNDS,
+                LocalId::from(index as u32),
+            );
+        });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &str {
+        "LinearEq"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        loop {
+            let lower_bound_left_hand_side = self
+                .x
+                .iter()
+                .map(|var| context.lower_bound_i64(var))
+                .sum::<i64>();
+            let upper_bound_left_hand_side = self
+                .x
+                .iter()
+                .map(|var| context.upper_bound_i64(var))
+                .sum::<i64>();
+
+            if lower_bound_left_hand_side > self.c as i64 {
+                return Err(self
+                    .explain_lower_bounds(context.as_readonly(), None)
+                    .into());
+            }
+            if upper_bound_left_hand_side < self.c as i64 {
+                return Err(self
+                    .explain_upper_bounds(context.as_readonly(), None)
+                    .into());
+            }
+
+            let mut changed = false;
+
+            for (index, x_i) in self.x.iter().enumerate() {
+                let lower_bound = context.lower_bound_i64(x_i);
+                let upper_bound = context.upper_bound_i64(x_i);
+
+                let new_upper_bound = self.c as i64 - (lower_bound_left_hand_side - lower_bound);
+                if new_upper_bound < upper_bound {
+                    if let Ok(bound) = i32::try_from(new_upper_bound) {
+                        let reason = self.explain_lower_bounds(context.as_readonly(), Some(index));
+                        context.set_upper_bound(x_i, bound, reason)?;
+                        changed = true;
+                    }
+                }
+
+                let new_lower_bound = self.c as i64 - (upper_bound_left_hand_side - upper_bound);
+                if new_lower_bound > lower_bound {
+                    if let Ok(bound) = i32::try_from(new_lower_bound) {
+                        let reason = self.explain_upper_bounds(context.as_readonly(), Some(index));
+                        context.set_lower_bound(x_i, bound, reason)?;
+                        changed = true;
+                    }
+                }
+            }
+
+            if !changed {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+    use crate::engine::variables::TransformableVariable;
+    use crate::propagators::linear_less_or_equal::LinearLessOrEqualPropagator;
+
+    #[test]
+    fn both_bounds_are_propagated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(LinearEqualPropagator::new([x, y].into(), 7))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 1, 5);
+        solver.assert_bounds(y, 2, 6);
+
+        let reason = solver.get_reason_int(predicate![y <= 6].try_into().unwrap());
+        assert_eq!(conjunction!([x >= 1]), reason.clone());
+        let reason = solver.get_reason_int(predicate![y >= 2].try_into().unwrap());
+        assert_eq!(conjunction!([x <= 5]), reason.clone());
+    }
+
+    #[test]
+    fn infeasible_sum_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 1);
+
+        let result = solver.new_propagator(LinearEqualPropagator::new([x, y].into(), 7));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn propagation_is_as_strong_as_the_two_inequalities() {
+        let mut equality_solver = TestSolver::default();
+        let x = equality_solver.new_variable(0, 10);
+        let y = equality_solver.new_variable(-3, 4);
+        let z = equality_solver.new_variable(2, 8);
+        let terms = [x.scaled(2), y.scaled(-3), z.scaled(1)];
+
+        let _ = equality_solver
+            .new_propagator(LinearEqualPropagator::new(terms.into(), 20))
+            .expect("no empty domains");
+
+        let mut inequality_solver = TestSolver::default();
+        let x = inequality_solver.new_variable(0, 10);
+        let y = inequality_solver.new_variable(-3, 4);
+        let z = inequality_solver.new_variable(2, 8);
+        let terms = [x.scaled(2), y.scaled(-3), z.scaled(1)];
+        let negated_terms = terms.map(|term| term.scaled(-1));
+
+        let _ = inequality_solver
+            .new_propagator(LinearLessOrEqualPropagator::new(terms.into(), 20))
+            .expect("no empty domains");
+        let _ = inequality_solver
+            .new_propagator(LinearLessOrEqualPropagator::new(negated_terms.into(), -20))
+            .expect("no empty domains");
+
+        for variable in [x, y, z] {
+            assert_eq!(
+                (
+                    inequality_solver.lower_bound(variable),
+                    inequality_solver.upper_bound(variable)
+                ),
+                (
+                    equality_solver.lower_bound(variable),
+                    equality_solver.upper_bound(variable)
+                ),
+            );
+        }
+    }
+}
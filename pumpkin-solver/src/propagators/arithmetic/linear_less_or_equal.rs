@@ -1,3 +1,4 @@
+use crate::basic_types::ConstraintOperationError;
 use crate::basic_types::PropagationStatusCP;
 use crate::basic_types::PropositionalConjunction;
 use crate::engine::cp::propagation::ReadDomains;
@@ -9,11 +10,19 @@ use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::AffineView;
+use crate::engine::variables::DomainId;
 use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::TransformableVariable;
 use crate::predicate;
 use crate::pumpkin_assert_simple;
 
 /// Propagator for the constraint `reif => \sum x_i <= c`.
+///
+/// The upper bound of `x_i` is only tightened if its width (i.e. `ub(x_i) - lb(x_i)`) exceeds the
+/// slack `c - \sum lb(x_i)`. Analogous to two-watched literals, the propagator watches the term
+/// with the largest width and the width of the runner-up: it is only enqueued when the slack drops
+/// below one of these, since no other term can be wider than the runner-up was.
 #[derive(Clone, Debug)]
 pub(crate) struct LinearLessOrEqualPropagator<Var> {
     x: Box<[Var]>,
@@ -22,7 +31,55 @@ pub(crate) struct LinearLessOrEqualPropagator<Var> {
     /// The lower bound of the sum of the left-hand side. This is incremental state.
     lower_bound_left_hand_side: i64,
     /// The value at index `i` is the bound for `x[i]`.
-    current_bounds: Box<[i32]>,
+    current_bounds: Box<[i64]>,
+    /// The value at index `i` is the upper bound of `x[i]` when the watches were last selected;
+    /// since the upper bounds can only decrease until backtracking, this over-approximates the
+    /// current upper bound.
+    watched_upper_bounds: Box<[i64]>,
+    /// The index of the term with the largest width when the watches were last selected.
+    widest_term: usize,
+    /// The second-largest width of a term when the watches were last selected, which bounds the
+    /// width of every term other than [`LinearLessOrEqualPropagator::widest_term`].
+    runner_up_width: i64,
+}
+
+impl LinearLessOrEqualPropagator<AffineView<DomainId>> {
+    /// Creates the propagator for `\sum a_i * v_i <= rhs` from the pairs `(v_i, a_i)`, where the
+    /// `v_i` can be arbitrary [`IntegerVariable`]s. Every `v_i` is flattened into a scaled
+    /// [`DomainId`] (see [`IntegerVariable::flatten`]) and its offset is moved to the right-hand
+    /// side; terms with a coefficient of 0 are dropped.
+    ///
+    /// Returns [`ConstraintOperationError::CoefficientOverflow`] with the index of the first term
+    /// for which the flattened coefficient, or the right-hand side after moving its offset, does
+    /// not fit in an `i32`.
+    pub(crate) fn from_terms<V: IntegerVariable>(
+        terms: &[(V, i32)],
+        rhs: i32,
+    ) -> Result<Self, ConstraintOperationError> {
+        let mut flattened_terms = Vec::with_capacity(terms.len());
+        let mut flattened_rhs = rhs as i64;
+
+        for (term, (variable, coefficient)) in terms.iter().enumerate() {
+            let overflow = ConstraintOperationError::CoefficientOverflow { term };
+            let flattened = variable.flatten();
+
+            let scale =
+                i32::try_from(flattened.scale * *coefficient as i64).map_err(|_| overflow)?;
+            flattened_rhs -= flattened.offset * *coefficient as i64;
+            if i32::try_from(flattened_rhs).is_err() {
+                return Err(overflow);
+            }
+
+            if scale != 0 {
+                flattened_terms.push(flattened.id.scaled(scale));
+            }
+        }
+
+        Ok(LinearLessOrEqualPropagator::new(
+            flattened_terms.into(),
+            flattened_rhs as i32,
+        ))
+    }
 }
 
 impl<Var> LinearLessOrEqualPropagator<Var>
@@ -31,6 +88,7 @@ where
 {
     pub(crate) fn new(x: Box<[Var]>, c: i32) -> Self {
         let current_bounds = vec![0; x.len()].into();
+        let watched_upper_bounds = vec![0; x.len()].into();
 
         // incremental state will be properly initialized in `Propagator::initialise_at_root`.
         LinearLessOrEqualPropagator::<Var> {
@@ -38,23 +96,105 @@ where
             c,
             lower_bound_left_hand_side: 0,
             current_bounds,
+            watched_upper_bounds,
+            widest_term: 0,
+            runner_up_width: 0,
         }
     }
 
+    /// Formats the constraint as `\sum a_i * x_i <= c` (e.g. `3*foo + bar <= 5`), where every
+    /// variable is given the name provided by `names`, or its default name (e.g. `x0`) if it has
+    /// no name. Every term is flattened (see [`IntegerVariable::flatten`]), and its offset is
+    /// moved to the right-hand side.
+    pub(crate) fn fmt_with<'a>(&self, names: &dyn Fn(DomainId) -> Option<&'a str>) -> String {
+        let mut left_hand_side = String::new();
+        let mut right_hand_side = self.c as i64;
+
+        for x_i in self.x.iter() {
+            let flattened = x_i.flatten();
+            right_hand_side -= flattened.offset;
+            if flattened.scale == 0 {
+                continue;
+            }
+
+            let name = names(flattened.id)
+                .map_or_else(|| flattened.id.to_string(), |name| name.to_owned());
+            let sign = if flattened.scale < 0 { "-" } else { "+" };
+
+            if left_hand_side.is_empty() {
+                if flattened.scale < 0 {
+                    left_hand_side.push('-');
+                }
+            } else {
+                left_hand_side.push_str(&format!(" {sign} "));
+            }
+
+            if flattened.scale.abs() != 1 {
+                left_hand_side.push_str(&format!("{}*", flattened.scale.abs()));
+            }
+            left_hand_side.push_str(&name);
+        }
+
+        if left_hand_side.is_empty() {
+            left_hand_side.push('0');
+        }
+
+        format!("{left_hand_side} <= {right_hand_side}")
+    }
+
+    /// Explains a conflict using the lower bounds of all the terms on the left-hand side.
+    fn create_conflict_explanation(&self, context: PropagationContext) -> PropositionalConjunction {
+        self.x
+            .iter()
+            .map(|var| predicate![var >= context.lower_bound(var)])
+            .collect()
+    }
+
     /// Recalculates the incremental state from scratch.
     fn recalculate_incremental_state(&mut self, context: PropagationContext) {
-        self.lower_bound_left_hand_side = self
-            .x
-            .iter()
-            .map(|var| context.lower_bound(var) as i64)
-            .sum();
+        self.lower_bound_left_hand_side =
+            self.x.iter().map(|var| context.lower_bound_i64(var)).sum();
 
         self.current_bounds
             .iter_mut()
             .enumerate()
             .for_each(|(index, bound)| {
-                *bound = context.lower_bound(&self.x[index]);
+                *bound = context.lower_bound_i64(&self.x[index]);
             });
+
+        self.select_watches(context);
+    }
+
+    /// Selects the widest term and records the width of the runner-up, based on the current
+    /// bounds of the terms.
+    fn select_watches(&mut self, context: PropagationContext) {
+        self.widest_term = 0;
+        self.runner_up_width = 0;
+        let mut largest_width = 0;
+
+        for (index, x_i) in self.x.iter().enumerate() {
+            let upper_bound = context.upper_bound_i64(x_i);
+            self.watched_upper_bounds[index] = upper_bound;
+
+            let width = upper_bound - context.lower_bound_i64(x_i);
+            if width > largest_width {
+                self.runner_up_width = largest_width;
+                largest_width = width;
+                self.widest_term = index;
+            } else if width > self.runner_up_width {
+                self.runner_up_width = width;
+            }
+        }
+    }
+
+    /// Returns whether a term can be wider than the slack `c - \sum lb(x_i)`, in which case its
+    /// upper bound can be tightened; this is also the case if the slack is negative.
+    fn can_propagate(&self) -> bool {
+        let slack = self.c as i64 - self.lower_bound_left_hand_side;
+        let widest_term_width =
+            self.watched_upper_bounds[self.widest_term] - self.current_bounds[self.widest_term];
+
+        slack < widest_term_width.max(self.runner_up_width)
     }
 }
 
@@ -88,12 +228,7 @@ where
         context: PropagationContext,
     ) -> Option<PropositionalConjunction> {
         if (self.c as i64) < self.lower_bound_left_hand_side {
-            let reason: PropositionalConjunction = self
-                .x
-                .iter()
-                .map(|var| predicate![var >= context.lower_bound(var)])
-                .collect();
-            Some(reason)
+            Some(self.create_conflict_explanation(context))
         } else {
             None
         }
@@ -109,7 +244,7 @@ where
 
         let x_i = &self.x[index];
         let old_bound = self.current_bounds[index];
-        let new_bound = context.lower_bound(x_i);
+        let new_bound = context.lower_bound_i64(x_i);
 
         pumpkin_assert_simple!(
             old_bound < new_bound,
@@ -117,9 +252,13 @@ where
         );
 
         self.current_bounds[index] = new_bound;
-        self.lower_bound_left_hand_side += (new_bound - old_bound) as i64;
+        self.lower_bound_left_hand_side += new_bound - old_bound;
 
-        EnqueueDecision::Enqueue
+        if self.can_propagate() {
+            EnqueueDecision::Enqueue
+        } else {
+            EnqueueDecision::Skip
+        }
     }
 
     fn synchronise(&mut self, context: PropagationContext) {
@@ -140,12 +279,16 @@ where
         }
 
         for (i, x_i) in self.x.iter().enumerate() {
-            let bound = (self.c as i64
-                - (self.lower_bound_left_hand_side - context.lower_bound(x_i) as i64))
-                .try_into()
-                .expect("Could not fit the lower-bound of lhs in an i32");
+            let bound =
+                self.c as i64 - (self.lower_bound_left_hand_side - context.lower_bound_i64(x_i));
+
+            if context.upper_bound_i64(x_i) > bound {
+                // A view of a variable can have bounds outside of the range of `i32`; if the
+                // new bound cannot be represented, then this term is not propagated.
+                let Ok(bound) = i32::try_from(bound) else {
+                    continue;
+                };
 
-            if context.upper_bound(x_i) > bound {
                 let reason: PropositionalConjunction = self
                     .x
                     .iter()
@@ -163,6 +306,8 @@ where
             }
         }
 
+        self.select_watches(context.as_readonly());
+
         Ok(())
     }
 
@@ -173,16 +318,27 @@ where
         let lower_bound_left_hand_side = self
             .x
             .iter()
-            .map(|var| context.lower_bound(var) as i64)
+            .map(|var| context.lower_bound_i64(var))
             .sum::<i64>();
 
+        // If the lower bound of the left-hand side exceeds the right-hand side, then tightening the
+        // upper bounds would wipe out a domain; the conflict is reported directly instead.
+        if (self.c as i64) < lower_bound_left_hand_side {
+            return Err(self
+                .create_conflict_explanation(context.as_readonly())
+                .into());
+        }
+
         for (i, x_i) in self.x.iter().enumerate() {
-            let bound = (self.c as i64
-                - (lower_bound_left_hand_side - context.lower_bound(x_i) as i64))
-                .try_into()
-                .expect("Could not fit the lower-bound of lhs in an i32");
+            let bound = self.c as i64 - (lower_bound_left_hand_side - context.lower_bound_i64(x_i));
+
+            if context.upper_bound_i64(x_i) > bound {
+                // A view of a variable can have bounds outside of the range of `i32`; if the
+                // new bound cannot be represented, then this term is not propagated.
+                let Ok(bound) = i32::try_from(bound) else {
+                    continue;
+                };
 
-            if context.upper_bound(x_i) > bound {
                 let reason: PropositionalConjunction = self
                     .x
                     .iter()
@@ -207,8 +363,60 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::basic_types::ConflictInfo;
+    use crate::basic_types::Inconsistency;
     use crate::conjunction;
     use crate::engine::test_helper::TestSolver;
+    use crate::engine::watcher_recorder::WatcherRecorder;
+
+    #[test]
+    fn constraint_is_formatted_with_the_provided_names() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        let propagator = LinearLessOrEqualPropagator::new([x.scaled(3), y.scaled(1)].into(), 5);
+        let names = |domain_id: DomainId| {
+            if domain_id == x {
+                Some("foo")
+            } else if domain_id == y {
+                Some("bar")
+            } else {
+                None
+            }
+        };
+
+        assert_eq!("3*foo + bar <= 5", propagator.fmt_with(&names));
+        assert_eq!(format!("3*{x} + {y} <= 5"), propagator.fmt_with(&|_| None));
+    }
+
+    #[test]
+    fn formatting_moves_offsets_to_the_right_hand_side() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        let propagator =
+            LinearLessOrEqualPropagator::new([x.scaled(-1).offset(2), y.scaled(-2)].into(), 5);
+
+        assert_eq!(
+            "-foo - 2*bar <= 3",
+            propagator.fmt_with(&|domain_id| Some(if domain_id == x { "foo" } else { "bar" }))
+        );
+    }
+
+    #[test]
+    fn single_term_with_negative_coefficient_rounds_the_bound_up() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+
+        // -3x <= -7 implies x >= 7/3, which is rounded up rather than truncated
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([x.scaled(-3)].into(), -7))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 3, 10);
+    }
 
     #[test]
     fn test_bounds_are_propagated() {
@@ -242,4 +450,287 @@ mod tests {
 
         assert_eq!(conjunction!([x >= 1]), *reason);
     }
+
+    #[test]
+    fn wipeout_is_reported_as_explanation() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 5);
+        let y = solver.new_variable(0, 10);
+
+        let mut propagator = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), 7))
+            .expect("no empty domains");
+
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 5);
+        let _ = solver.increase_lower_bound_and_notify(&mut propagator, 1, y, 6);
+
+        let result = solver.propagate(&mut propagator);
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Other(ConflictInfo::Explanation(ref explanation)))
+                if *explanation == conjunction!([x >= 5] & [y >= 6])
+        ));
+    }
+
+    #[test]
+    fn negatively_scaled_term_has_its_lower_bound_tightened() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        // -3x + y <= -6, i.e. x >= 2 when y >= 0
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(-3), y.scaled(1)].into(),
+                -6,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 2, 10);
+        solver.assert_bounds(y, 0, 10);
+
+        let reason = solver.get_reason_int(predicate![x >= 2].try_into().unwrap());
+        assert_eq!(conjunction!([y >= 0]), reason.clone());
+    }
+
+    #[test]
+    fn negatively_scaled_term_rounds_the_tightened_lower_bound_up() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        // -3x + y <= -7, i.e. x >= 7/3 when y >= 0
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(-3), y.scaled(1)].into(),
+                -7,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 3, 10);
+    }
+
+    #[test]
+    fn mixed_sign_terms_tighten_the_correct_bounds() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(4, 10);
+        let b = solver.new_variable(0, 3);
+
+        // 2a - 3b <= 1
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [a.scaled(2), b.scaled(-3)].into(),
+                1,
+            ))
+            .expect("no empty domains");
+
+        // The upper bound of `a` is limited by the upper bound of `b`, and the lower bound of `b`
+        // by the lower bound of `a`.
+        solver.assert_bounds(a, 4, 5);
+        solver.assert_bounds(b, 3, 3);
+
+        let reason = solver.get_reason_int(predicate![a <= 5].try_into().unwrap());
+        assert_eq!(conjunction!([b <= 3]), reason.clone());
+
+        let reason = solver.get_reason_int(predicate![b >= 3].try_into().unwrap());
+        assert_eq!(conjunction!([a >= 4]), reason.clone());
+    }
+
+    #[test]
+    fn negatively_scaled_term_conflict_is_explained_with_upper_bound() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 2);
+        let y = solver.new_variable(0, 10);
+
+        // -3x + y <= -7 requires x >= 3
+        let result = solver.new_propagator(LinearLessOrEqualPropagator::new(
+            [x.scaled(-3), y.scaled(1)].into(),
+            -7,
+        ));
+
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Other(ConflictInfo::Explanation(ref explanation)))
+                if *explanation == conjunction!([x <= 2] & [y >= 0])
+        ));
+    }
+
+    #[test]
+    fn removing_an_inner_value_does_not_wake_the_propagator() {
+        let mut recorder = WatcherRecorder::default();
+        let x = recorder.new_variable(0, 10);
+        let y = recorder.new_variable(0, 10);
+
+        let _ = recorder.add_propagator(LinearLessOrEqualPropagator::new([x, y].into(), 15));
+
+        let woken = recorder.remove(x, 5).expect("non-empty domain");
+        assert!(woken.is_empty());
+    }
+
+    #[test]
+    fn tightening_a_lower_bound_wakes_the_propagator() {
+        let mut recorder = WatcherRecorder::default();
+        let x = recorder.new_variable(0, 10);
+        let y = recorder.new_variable(0, 10);
+
+        let propagator =
+            recorder.add_propagator(LinearLessOrEqualPropagator::new([x, y].into(), 11));
+
+        let woken = recorder.set_upper_bound(x, 8).expect("non-empty domain");
+        assert!(woken.is_empty());
+
+        let woken = recorder.set_lower_bound(x, 2).expect("non-empty domain");
+        assert_eq!(vec![propagator], woken);
+    }
+
+    #[test]
+    fn negatively_scaled_term_wakes_the_propagator_on_upper_bound() {
+        let mut recorder = WatcherRecorder::default();
+        let x = recorder.new_variable(0, 10);
+        let y = recorder.new_variable(0, 10);
+
+        let propagator = recorder.add_propagator(LinearLessOrEqualPropagator::new(
+            [x.scaled(-1), y.scaled(1)].into(),
+            1,
+        ));
+
+        let woken = recorder.set_lower_bound(x, 2).expect("non-empty domain");
+        assert!(woken.is_empty());
+
+        let woken = recorder.set_upper_bound(x, 8).expect("non-empty domain");
+        assert_eq!(vec![propagator], woken);
+    }
+
+    #[test]
+    fn term_with_upper_bound_beyond_i32_is_propagated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 5_000);
+        let y = solver.new_variable(0, 10);
+
+        // The upper bound of `1_000_000 * x` does not fit in an i32.
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(1_000_000), y.scaled(1)].into(),
+                100,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 0, 0);
+        solver.assert_bounds(y, 0, 10);
+    }
+
+    #[test]
+    fn bound_outside_of_i32_range_is_not_propagated() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(-10, 0);
+        let z = solver.new_variable(1, 5);
+
+        // 3e8 x + 3e8 y + z <= 0; the new upper bound for `3e8 x` is `3e9 - 1`, which does not fit
+        // in an `i32`, so only `y` is tightened.
+        let _ = solver
+            .new_propagator(LinearLessOrEqualPropagator::new(
+                [x.scaled(300_000_000), y.scaled(300_000_000), z.scaled(1)].into(),
+                0,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(x, 0, 10);
+        solver.assert_bounds(y, -10, -1);
+        solver.assert_bounds(z, 1, 5);
+    }
+
+    #[test]
+    fn lower_bound_change_which_leaves_enough_slack_does_not_wake_the_propagator() {
+        let mut recorder = WatcherRecorder::default();
+        let x = recorder.new_variable(0, 10);
+        let y = recorder.new_variable(0, 10);
+        let z = recorder.new_variable(0, 4);
+
+        // The slack is 20, which exceeds the width of every term
+        let propagator =
+            recorder.add_propagator(LinearLessOrEqualPropagator::new([x, y, z].into(), 20));
+
+        let woken = recorder.set_lower_bound(z, 4).expect("non-empty domain");
+        assert!(woken.is_empty());
+
+        let woken = recorder.set_lower_bound(x, 3).expect("non-empty domain");
+        assert!(woken.is_empty());
+
+        // The slack is now 9, which is smaller than the width of `y`
+        let woken = recorder.set_lower_bound(x, 7).expect("non-empty domain");
+        assert_eq!(vec![propagator], woken);
+    }
+
+    #[test]
+    fn skipped_notifications_are_taken_into_account_when_propagating() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+        let z = solver.new_variable(0, 4);
+
+        let mut propagator = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([x, y, z].into(), 20))
+            .expect("no empty domains");
+
+        let decision = solver.increase_lower_bound_and_notify(&mut propagator, 2, z, 4);
+        assert_eq!(EnqueueDecision::Skip, decision);
+
+        let decision = solver.increase_lower_bound_and_notify(&mut propagator, 0, x, 8);
+        assert_eq!(EnqueueDecision::Enqueue, decision);
+
+        solver.propagate(&mut propagator).expect("non-empty domain");
+
+        solver.assert_bounds(x, 8, 10);
+        solver.assert_bounds(y, 0, 8);
+        solver.assert_bounds(z, 4, 4);
+    }
+
+    #[test]
+    fn terms_are_flattened_and_offsets_are_moved_to_the_rhs() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        // (2x + 1) + 2 * (y - 3) <= 9, i.e. 2x + 2y <= 14
+        let terms = [(x.scaled(2).offset(1), 1), (y.offset(-3), 2)];
+        let propagator = LinearLessOrEqualPropagator::from_terms(&terms, 9).unwrap();
+        assert_eq!(14, propagator.c);
+
+        let _ = solver.new_propagator(propagator).expect("no empty domains");
+
+        solver.assert_bounds(x, 0, 7);
+        solver.assert_bounds(y, 0, 7);
+    }
+
+    #[test]
+    fn nested_views_are_flattened() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(5, 10);
+
+        // -(2 * (x + 3)) + y <= -10, i.e. -2x + y <= -4
+        let terms = [(x.offset(3).scaled(2), -1), (y.scaled(1), 1)];
+        let propagator = LinearLessOrEqualPropagator::from_terms(&terms, -10).unwrap();
+        assert_eq!(-4, propagator.c);
+
+        let _ = solver.new_propagator(propagator).expect("no empty domains");
+
+        solver.assert_bounds(x, 5, 10);
+    }
+
+    #[test]
+    fn overflowing_coefficient_is_reported() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        let terms = [(x.scaled(1), 1), (y.scaled(2), i32::MAX)];
+        let result = LinearLessOrEqualPropagator::from_terms(&terms, 0);
+
+        assert!(matches!(
+            result,
+            Err(ConstraintOperationError::CoefficientOverflow { term: 1 })
+        ));
+    }
 }
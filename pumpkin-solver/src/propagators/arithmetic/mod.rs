@@ -1,6 +1,8 @@
 pub(crate) mod absolute_value;
+pub(crate) mod absolute_value_not_equal;
 pub(crate) mod division;
 pub(crate) mod integer_multiplication;
+pub(crate) mod linear_equal;
 pub(crate) mod linear_less_or_equal;
 pub(crate) mod linear_not_equal;
 pub(crate) mod maximum;
@@ -21,6 +21,14 @@ pub(crate) struct BasicClausalPropagator {
     pub(crate) watch_lists: KeyedVec<Literal, Vec<ClauseWatcher>>,
     pub(crate) next_position_on_trail_to_propagate: usize,
     pub(crate) permanent_clauses: Vec<ClauseReference>,
+    /// The literals of the permanent clauses which were unit at the root; these are not stored
+    /// as clauses but assigned at the root instead.
+    pub(crate) permanent_unit_clauses: Vec<Literal>,
+    /// The learned clauses whose asserting literal was assigned at a higher decision level than
+    /// the level at which it is implied, which happens when backtracking chronologically.
+    misplaced_assertions: Vec<ClauseReference>,
+    /// Whether the solver backtracked since the misplaced assertions were last inspected.
+    should_inspect_misplaced_assertions: bool,
     is_in_infeasible_state: bool,
 }
 
@@ -92,6 +100,8 @@ impl ClausalPropagator for BasicClausalPropagator {
                     return Err(ConstraintOperationError::InfeasibleClause);
                 }
             }
+
+            self.permanent_unit_clauses.push(literals[0]);
         } else {
             // standard case - the clause has at least two unassigned literals
             let _ = self.add_clause_unchecked(literals, false, clause_allocator);
@@ -160,6 +170,12 @@ impl ClausalPropagator for BasicClausalPropagator {
         clause_manager: &mut ClauseAllocator,
     ) -> Result<(), ConflictInfo> {
         pumpkin_assert_simple!(!self.is_in_infeasible_state);
+
+        if self.should_inspect_misplaced_assertions {
+            self.should_inspect_misplaced_assertions = false;
+            self.propagate_misplaced_assertions(assignments, clause_manager)?;
+        }
+
         // this function is implemented as one long function
         //  dividing this function into several smaller functions would normally make sense for
         // readability  however this is a performance hotspot, so it is hard to divide the
@@ -295,10 +311,16 @@ impl ClausalPropagator for BasicClausalPropagator {
     fn synchronise(&mut self, trail_size: usize) {
         pumpkin_assert_simple!(self.next_position_on_trail_to_propagate >= trail_size);
         self.next_position_on_trail_to_propagate = trail_size;
+        self.should_inspect_misplaced_assertions = !self.misplaced_assertions.is_empty();
     }
 
     fn is_propagation_complete(&self, trail_size: usize) -> bool {
         self.next_position_on_trail_to_propagate == trail_size
+            && !self.should_inspect_misplaced_assertions
+    }
+
+    fn add_misplaced_assertion(&mut self, clause_reference: ClauseReference) {
+        self.misplaced_assertions.push(clause_reference);
     }
 
     fn remove_clause_from_consideration(
@@ -322,6 +344,9 @@ impl ClausalPropagator for BasicClausalPropagator {
 
         remove_clause_from_watchers(&mut self.watch_lists[watched_literal1], clause_reference);
         remove_clause_from_watchers(&mut self.watch_lists[watched_literal2], clause_reference);
+
+        self.misplaced_assertions
+            .retain(|&misplaced_clause| misplaced_clause != clause_reference);
     }
 
     fn debug_check_state(
@@ -423,7 +448,9 @@ impl ClausalPropagator for BasicClausalPropagator {
                             .all(|x| assignments.is_literal_assigned_false(*x)),
                         "A clause is recorded as the reason for propagation, but the other literals are not all false."
                     );
-                    // ensure propagation was done at the correct decision level
+                    // ensure propagation was done at the correct decision level; after
+                    // backtracking chronologically, an asserting literal may be assigned at a
+                    // higher level than the other literals
                     let lit_max_decision_level = *clause.get_literal_slice()[1..]
                         .iter()
                         .max_by_key(|x| assignments.get_literal_assignment_level(**x))
@@ -431,7 +458,8 @@ impl ClausalPropagator for BasicClausalPropagator {
                     let max_decision_level =
                         assignments.get_literal_assignment_level(lit_max_decision_level);
                     assert!(
-                        max_decision_level == assignments.get_literal_assignment_level(literal),
+                        max_decision_level == assignments.get_literal_assignment_level(literal)
+                            || self.misplaced_assertions.contains(&clause_reference),
                         "Literal propagation level does not match the other literals."
                     );
                 }
@@ -472,6 +500,66 @@ impl ClausalPropagator for BasicClausalPropagator {
 }
 
 impl BasicClausalPropagator {
+    /// Propagates the asserting literals of the misplaced learned clauses which were unassigned by
+    /// backtracking while the clause remained unit. Since the other literals of such a clause are
+    /// not reassigned, the watchers would otherwise never trigger the propagation again.
+    ///
+    /// Clauses for which backtracking went past the level at which the asserting literal is
+    /// implied are handled by the watchers again, and are no longer considered misplaced.
+    fn propagate_misplaced_assertions(
+        &mut self,
+        assignments: &mut AssignmentsPropositional,
+        clause_manager: &mut ClauseAllocator,
+    ) -> Result<(), ConflictInfo> {
+        let misplaced_assertions = std::mem::take(&mut self.misplaced_assertions);
+
+        for (index, &clause_reference) in misplaced_assertions.iter().enumerate() {
+            let clause = clause_manager.get_mutable_clause(clause_reference);
+
+            if assignments.is_literal_assigned_false(clause[0])
+                && !assignments.is_literal_assigned_false(clause[1])
+            {
+                let literal = clause[0];
+                clause[0] = clause[1];
+                clause[1] = literal;
+            }
+
+            if !assignments.is_literal_assigned_false(clause[1]) {
+                continue;
+            }
+
+            pumpkin_assert_moderate!(
+                clause.get_literal_slice()[1..]
+                    .iter()
+                    .all(|&literal| assignments.is_literal_assigned_false(literal)),
+                "The watched literal of a misplaced assertion can only be falsified if the other literals are."
+            );
+
+            let implication_level = clause.get_literal_slice()[1..]
+                .iter()
+                .map(|&literal| assignments.get_literal_assignment_level(literal))
+                .max()
+                .unwrap();
+
+            if !assignments.is_literal_assigned_true(clause[0]) {
+                let conflict_info =
+                    assignments.enqueue_propagated_literal(clause[0], clause_reference.into());
+                if let Some(conflict_info) = conflict_info {
+                    // keep the remaining clauses, they are inspected after the next backtrack
+                    self.misplaced_assertions
+                        .extend_from_slice(&misplaced_assertions[index..]);
+                    return Err(conflict_info);
+                }
+            }
+
+            if assignments.get_literal_assignment_level(clause[0]) > implication_level {
+                self.misplaced_assertions.push(clause_reference);
+            }
+        }
+
+        Ok(())
+    }
+
     fn start_watching_clause_unchecked(
         &mut self,
         clause: &[Literal],
@@ -64,6 +64,11 @@ pub(crate) trait ClausalPropagator {
 
     fn is_propagation_complete(&self, trail_size: usize) -> bool;
 
+    /// Registers a learned clause whose asserting literal was assigned at a higher decision level
+    /// than the level at which it is implied. The propagator ensures that the literal is
+    /// propagated again when a backtrack unassigns it while the clause is still unit.
+    fn add_misplaced_assertion(&mut self, clause_reference: ClauseReference);
+
     fn remove_clause_from_consideration(
         &mut self,
         clause: &[Literal],
@@ -0,0 +1,207 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the constraint `|{i | x_i = value}| = count`, where `value` is a constant.
+///
+/// The propagator keeps track of the number of variables which are fixed to `value` (the
+/// definite count) and the number of variables which can still take `value` (the possible count),
+/// and propagates the following rules:
+/// - `count` lies between the definite count and the possible count.
+/// - If the upper bound of `count` equals the definite count, then no other variable can take
+///   `value`.
+/// - If the lower bound of `count` equals the possible count, then every variable which can take
+///   `value` is fixed to it.
+#[derive(Clone, Debug)]
+pub(crate) struct CountPropagator<VX, VC> {
+    array: Box<[VX]>,
+    value: i32,
+    count: VC,
+}
+
+impl<VX: IntegerVariable, VC: IntegerVariable> CountPropagator<VX, VC> {
+    pub(crate) fn new(array: Box<[VX]>, value: i32, count: VC) -> Self {
+        CountPropagator {
+            array,
+            value,
+            count,
+        }
+    }
+}
+
+impl<VX: IntegerVariable, VC: IntegerVariable> Propagator for CountPropagator<VX, VC> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.array.iter().enumerate().for_each(|(index, var)| {
+            let _ = context.register(
+                var.clone(),
+                DomainEvents::ANY_INT,
+                LocalId::from(index as u32),
+            );
+        });
+        let _ = context.register(
+            self.count.clone(),
+            DomainEvents::BOUNDS,
+            LocalId::from(self.array.len() as u32),
+        );
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &str {
+        "Count"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let value = self.value;
+
+        let definite = self
+            .array
+            .iter()
+            .filter(|var| context.is_fixed(*var) && context.lower_bound(*var) == value)
+            .collect::<Vec<_>>();
+        let impossible = self
+            .array
+            .iter()
+            .filter(|var| !context.contains(*var, value))
+            .collect::<Vec<_>>();
+
+        let num_definite = definite.len() as i32;
+        let num_possible = (self.array.len() - impossible.len()) as i32;
+
+        let definite_reason: PropositionalConjunction = definite
+            .iter()
+            .map(|var| predicate![var == value])
+            .collect();
+        let impossible_reason: PropositionalConjunction = impossible
+            .iter()
+            .map(|var| predicate![var != value])
+            .collect();
+
+        context.set_lower_bound(&self.count, num_definite, definite_reason.clone())?;
+        context.set_upper_bound(&self.count, num_possible, impossible_reason.clone())?;
+
+        let count_lb = context.lower_bound(&self.count);
+        let count_ub = context.upper_bound(&self.count);
+
+        // The count is saturated, so none of the undecided variables can take the value.
+        if count_ub == num_definite && num_possible > num_definite {
+            let mut reason = definite_reason;
+            reason.add(predicate![self.count <= count_ub]);
+
+            for var in self.array.iter() {
+                if !context.is_fixed(var) && context.contains(var, value) {
+                    context.remove(var, value, reason.clone())?;
+                }
+            }
+        }
+
+        // Every variable which can take the value is needed to reach the count.
+        if count_lb == num_possible && num_possible > num_definite {
+            let mut reason = impossible_reason;
+            reason.add(predicate![self.count >= count_lb]);
+
+            for var in self.array.iter() {
+                if !context.is_fixed(var) && context.contains(var, value) {
+                    context.set_lower_bound(var, value, reason.clone())?;
+                    context.set_upper_bound(var, value, reason.clone())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn count_is_bounded_by_definite_and_possible_counts() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(2, 2);
+        let b = solver.new_variable(2, 2);
+        let c = solver.new_variable(0, 5);
+        let d = solver.new_variable(3, 5);
+        let count = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(CountPropagator::new([a, b, c, d].into(), 2, count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(count, 2, 3);
+
+        let reason = solver.get_reason_int(predicate![count >= 2].try_into().unwrap());
+        assert_eq!(conjunction!([a == 2] & [b == 2]), reason.clone());
+
+        let reason = solver.get_reason_int(predicate![count <= 3].try_into().unwrap());
+        assert_eq!(conjunction!([d != 2]), reason.clone());
+    }
+
+    #[test]
+    fn value_is_removed_when_count_is_saturated() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(2, 2);
+        let b = solver.new_variable(0, 5);
+        let c = solver.new_variable(0, 5);
+        let count = solver.new_variable(0, 1);
+
+        let _ = solver
+            .new_propagator(CountPropagator::new([a, b, c].into(), 2, count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(count, 1, 1);
+        assert!(!solver.contains(b, 2));
+        assert!(!solver.contains(c, 2));
+
+        let reason = solver.get_reason_int(predicate![b != 2].try_into().unwrap());
+        assert_eq!(conjunction!([a == 2] & [count <= 1]), reason.clone());
+    }
+
+    #[test]
+    fn variables_are_fixed_when_all_possible_are_needed() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 5);
+        let b = solver.new_variable(0, 5);
+        let c = solver.new_variable(3, 5);
+        let count = solver.new_variable(2, 3);
+
+        let _ = solver
+            .new_propagator(CountPropagator::new([a, b, c].into(), 2, count))
+            .expect("no empty domains");
+
+        solver.assert_bounds(count, 2, 2);
+        solver.assert_bounds(a, 2, 2);
+        solver.assert_bounds(b, 2, 2);
+    }
+
+    #[test]
+    fn conflict_when_too_many_variables_take_the_value() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(2, 2);
+        let b = solver.new_variable(2, 2);
+        let count = solver.new_variable(0, 1);
+
+        let result = solver.new_propagator(CountPropagator::new([a, b].into(), 2, count));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the constraint that no two tasks overlap in time, where task `i` starts at
+/// `start_times[i]` and has duration `durations[i]`.
+///
+/// The propagator only tightens the lower bounds of the start times, using overload checking and
+/// the edge-finding rule \[1\] over task intervals: if a task `i` cannot be scheduled before a set
+/// of tasks `Ω` (because `min(est_Ω, est_i) + p_Ω + p_i > lct_Ω`), then `i` has to end after all
+/// tasks in `Ω`, and thus cannot start before the earliest completion time of `Ω`. The upper
+/// bounds are tightened by a second propagator over the mirrored tasks; see
+/// [`crate::constraints::disjunctive`].
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Filtering algorithms for the unary resource constraint’, Archives of Control
+/// Sciences, vol. 18, no. 2, pp. 159–202, 2008.
+#[derive(Clone, Debug)]
+pub(crate) struct DisjunctivePropagator<Var> {
+    start_times: Box<[Var]>,
+    durations: Box<[i32]>,
+}
+
+impl<Var: IntegerVariable> DisjunctivePropagator<Var> {
+    pub(crate) fn new(start_times: Box<[Var]>, durations: Box<[i32]>) -> Self {
+        DisjunctivePropagator {
+            start_times,
+            durations,
+        }
+    }
+}
+
+impl<Var: IntegerVariable> Propagator for DisjunctivePropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.start_times
+            .iter()
+            .enumerate()
+            .for_each(|(index, start_time)| {
+                let _ = context.register(
+                    start_time.clone(),
+                    DomainEvents::BOUNDS,
+                    LocalId::from(index as u32),
+                );
+            });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "Disjunctive"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        // The times are computed as i64, since a start time plus durations can exceed i32::MAX
+        let earliest_starts = self
+            .start_times
+            .iter()
+            .map(|start_time| context.lower_bound(start_time) as i64)
+            .collect::<Vec<_>>();
+        let latest_completions = self
+            .start_times
+            .iter()
+            .zip(self.durations.iter())
+            .map(|(start_time, &duration)| context.upper_bound(start_time) as i64 + duration as i64)
+            .collect::<Vec<_>>();
+
+        // Every task interval consists of the tasks which start no earlier than the earliest start
+        // time of a task, and complete no later than the latest completion time of a task.
+        for &interval_start in earliest_starts.iter() {
+            for &interval_end in latest_completions.iter() {
+                let omega = (0..self.start_times.len())
+                    .filter(|&task| {
+                        earliest_starts[task] >= interval_start
+                            && latest_completions[task] <= interval_end
+                    })
+                    .collect::<Vec<_>>();
+                if omega.is_empty() {
+                    continue;
+                }
+
+                let processing_omega: i64 =
+                    omega.iter().map(|&task| self.durations[task] as i64).sum();
+                let earliest_start_omega = omega
+                    .iter()
+                    .map(|&task| earliest_starts[task])
+                    .min()
+                    .unwrap();
+                let latest_completion_omega = omega
+                    .iter()
+                    .map(|&task| latest_completions[task])
+                    .max()
+                    .unwrap();
+
+                // The tasks in omega together with their bounds are the basis of every
+                // explanation.
+                let omega_reason = omega
+                    .iter()
+                    .flat_map(|&task| {
+                        let start_time = &self.start_times[task];
+                        [
+                            predicate![start_time >= to_bound(earliest_starts[task])],
+                            predicate![
+                                start_time
+                                    <= to_bound(
+                                        latest_completion_omega - self.durations[task] as i64
+                                    )
+                            ],
+                        ]
+                    })
+                    .collect::<PropositionalConjunction>();
+
+                // Overload check: the tasks in omega do not fit in their interval
+                if earliest_start_omega + processing_omega > latest_completion_omega {
+                    return Err(omega_reason.into());
+                }
+
+                let earliest_completion_omega =
+                    self.earliest_completion_time(&omega, &earliest_starts);
+
+                for (task, start_time) in self.start_times.iter().enumerate() {
+                    if omega.contains(&task)
+                        || context.lower_bound(start_time) as i64 >= earliest_completion_omega
+                    {
+                        continue;
+                    }
+
+                    // Edge finding: the task cannot be scheduled before all tasks in omega, so it
+                    // is scheduled after all of them.
+                    let earliest_start = earliest_start_omega.min(earliest_starts[task]);
+                    if earliest_start + processing_omega + self.durations[task] as i64
+                        > latest_completion_omega
+                    {
+                        let mut reason = omega_reason.clone();
+                        reason.add(predicate![start_time >= to_bound(earliest_start)]);
+
+                        context.set_lower_bound(
+                            start_time,
+                            to_bound(earliest_completion_omega),
+                            reason,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var: IntegerVariable> DisjunctivePropagator<Var> {
+    /// Computes the earliest time at which all tasks in `omega` can be completed, i.e. the maximum
+    /// over all subsets of `omega` of the earliest start time plus the total duration.
+    fn earliest_completion_time(&self, omega: &[usize], earliest_starts: &[i64]) -> i64 {
+        let mut omega = omega.to_vec();
+        omega.sort_by_key(|&task| earliest_starts[task]);
+
+        omega.iter().fold(i64::MIN, |completion_time, &task| {
+            completion_time.max(earliest_starts[task]) + self.durations[task] as i64
+        })
+    }
+}
+
+/// Converts a time to a bound on a start time, clamping it to the range of i32; clamping only
+/// weakens the bound, so the propagation remains sound.
+fn to_bound(time: i64) -> i32 {
+    time.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn task_which_does_not_fit_before_others_is_scheduled_after_them() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(0, 10);
+        let c = solver.new_variable(0, 4);
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new(
+                [a, b, c].into(),
+                [4, 3, 2].into(),
+            ))
+            .expect("no empty domains");
+
+        // Tasks `a` and `c` both have to complete by time 6, which leaves no room for `b` before
+        // them.
+        solver.assert_bounds(b, 6, 10);
+
+        let reason = solver.get_reason_int(predicate![b >= 6].try_into().unwrap());
+        assert_eq!(
+            conjunction!([a >= 0] & [a <= 2] & [c >= 0] & [c <= 4] & [b >= 0]),
+            reason.clone()
+        );
+    }
+
+    #[test]
+    fn overloaded_interval_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 1);
+        let b = solver.new_variable(0, 3);
+
+        let result =
+            solver.new_propagator(DisjunctivePropagator::new([a, b].into(), [4, 2].into()));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn completion_times_beyond_i32_do_not_overflow() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(i32::MAX - 10, i32::MAX - 5);
+        let b = solver.new_variable(i32::MAX - 10, i32::MAX - 5);
+
+        let result =
+            solver.new_propagator(DisjunctivePropagator::new([a, b].into(), [8, 8].into()));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn tasks_which_fit_before_each_other_are_not_propagated() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(0, 10);
+        let b = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(DisjunctivePropagator::new([a, b].into(), [3, 3].into()))
+            .expect("no empty domains");
+
+        solver.assert_bounds(a, 0, 10);
+        solver.assert_bounds(b, 0, 10);
+    }
+}
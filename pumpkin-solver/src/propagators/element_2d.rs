@@ -0,0 +1,259 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the constraint `matrix[row][column] = rhs`, where `matrix` is a matrix of
+/// constants and `row`, `column` and `rhs` are variables.
+///
+/// A cell `(r, c)` is reachable if `r` is in the domain of `row` and `c` is in the domain of
+/// `column`. The propagator removes every row and column which has no reachable cell with a value
+/// in the domain of `rhs`, and restricts `rhs` to the values of the reachable cells.
+///
+/// Note that this propagator is 0-indexed.
+#[derive(Clone, Debug)]
+pub(crate) struct Element2DPropagator<VR, VC, VE> {
+    matrix: Box<[Box<[i32]>]>,
+    row: VR,
+    column: VC,
+    rhs: VE,
+}
+
+const ID_ROW: LocalId = LocalId::from(0);
+const ID_COLUMN: LocalId = LocalId::from(1);
+const ID_RHS: LocalId = LocalId::from(2);
+
+impl<VR: IntegerVariable, VC: IntegerVariable, VE: IntegerVariable>
+    Element2DPropagator<VR, VC, VE>
+{
+    pub(crate) fn new(matrix: Box<[Box<[i32]>]>, row: VR, column: VC, rhs: VE) -> Self {
+        Element2DPropagator {
+            matrix,
+            row,
+            column,
+            rhs,
+        }
+    }
+
+    fn num_rows(&self) -> i32 {
+        self.matrix.len() as i32
+    }
+
+    fn num_columns(&self) -> i32 {
+        self.matrix.first().map_or(0, |row| row.len() as i32)
+    }
+
+    fn value(&self, row: i32, column: i32) -> i32 {
+        self.matrix[row as usize][column as usize]
+    }
+
+    fn is_reachable(&self, context: PropagationContext, row: i32, column: i32) -> bool {
+        context.contains(&self.row, row) && context.contains(&self.column, column)
+    }
+
+    /// Returns a predicate which is true in the current state and which explains why the cell
+    /// `(row, column)` is not reachable.
+    fn explain_unreachable(&self, context: PropagationContext, row: i32, column: i32) -> Predicate {
+        if !context.contains(&self.row, row) {
+            predicate![self.row != row]
+        } else {
+            predicate![self.column != column]
+        }
+    }
+
+    /// Explains why none of the cells for which `is_excluded` holds can be reached.
+    fn explain_unreachable_cells(
+        &self,
+        context: PropagationContext,
+        is_excluded: impl Fn(i32) -> bool,
+    ) -> PropositionalConjunction {
+        (0..self.num_rows())
+            .flat_map(|row| (0..self.num_columns()).map(move |column| (row, column)))
+            .filter(|&(row, column)| is_excluded(self.value(row, column)))
+            .map(|(row, column)| self.explain_unreachable(context, row, column))
+            .collect()
+    }
+}
+
+impl<VR: IntegerVariable, VC: IntegerVariable, VE: IntegerVariable> Propagator
+    for Element2DPropagator<VR, VC, VE>
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.row.clone(), DomainEvents::ANY_INT, ID_ROW);
+        let _ = context.register(self.column.clone(), DomainEvents::ANY_INT, ID_COLUMN);
+        let _ = context.register(self.rhs.clone(), DomainEvents::ANY_INT, ID_RHS);
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &str {
+        "Element2D"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        context.set_lower_bound(&self.row, 0, conjunction!())?;
+        context.set_upper_bound(&self.row, self.num_rows() - 1, conjunction!())?;
+        context.set_lower_bound(&self.column, 0, conjunction!())?;
+        context.set_upper_bound(&self.column, self.num_columns() - 1, conjunction!())?;
+
+        // Remove the rows in which no reachable cell has a value in the domain of the rhs
+        for row in 0..self.num_rows() {
+            if !context.contains(&self.row, row) {
+                continue;
+            }
+
+            let is_supported = (0..self.num_columns()).any(|column| {
+                context.contains(&self.column, column)
+                    && context.contains(&self.rhs, self.value(row, column))
+            });
+            if !is_supported {
+                let reason: PropositionalConjunction = (0..self.num_columns())
+                    .map(|column| {
+                        if context.contains(&self.column, column) {
+                            predicate![self.rhs != self.value(row, column)]
+                        } else {
+                            predicate![self.column != column]
+                        }
+                    })
+                    .collect();
+                context.remove(&self.row, row, reason)?;
+            }
+        }
+
+        // Remove the columns in which no reachable cell has a value in the domain of the rhs
+        for column in 0..self.num_columns() {
+            if !context.contains(&self.column, column) {
+                continue;
+            }
+
+            let is_supported = (0..self.num_rows()).any(|row| {
+                context.contains(&self.row, row)
+                    && context.contains(&self.rhs, self.value(row, column))
+            });
+            if !is_supported {
+                let reason: PropositionalConjunction = (0..self.num_rows())
+                    .map(|row| {
+                        if context.contains(&self.row, row) {
+                            predicate![self.rhs != self.value(row, column)]
+                        } else {
+                            predicate![self.row != row]
+                        }
+                    })
+                    .collect();
+                context.remove(&self.column, column, reason)?;
+            }
+        }
+
+        // Restrict the rhs to the values of the reachable cells
+        let reachable_values = (0..self.num_rows())
+            .flat_map(|row| (0..self.num_columns()).map(move |column| (row, column)))
+            .filter(|&(row, column)| self.is_reachable(context.as_readonly(), row, column))
+            .map(|(row, column)| self.value(row, column))
+            .collect::<Vec<_>>();
+
+        // Every remaining row has a reachable cell, otherwise a conflict would have been reported
+        let min_value = *reachable_values.iter().min().unwrap();
+        let max_value = *reachable_values.iter().max().unwrap();
+
+        let reason =
+            self.explain_unreachable_cells(context.as_readonly(), |value| value < min_value);
+        context.set_lower_bound(&self.rhs, min_value, reason)?;
+
+        let reason =
+            self.explain_unreachable_cells(context.as_readonly(), |value| value > max_value);
+        context.set_upper_bound(&self.rhs, max_value, reason)?;
+
+        for value in context.lower_bound(&self.rhs)..=context.upper_bound(&self.rhs) {
+            if context.contains(&self.rhs, value) && !reachable_values.contains(&value) {
+                let reason =
+                    self.explain_unreachable_cells(context.as_readonly(), |cell| cell == value);
+                context.remove(&self.rhs, value, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_helper::TestSolver;
+
+    fn create_matrix() -> Box<[Box<[i32]>]> {
+        // 1 2 3
+        // 4 5 2
+        [[1, 2, 3].into(), [4, 5, 2].into()].into()
+    }
+
+    #[test]
+    fn value_in_a_single_cell_fixes_row_and_column() {
+        let mut solver = TestSolver::default();
+        let row = solver.new_variable(-5, 5);
+        let column = solver.new_variable(-5, 5);
+        let rhs = solver.new_variable(5, 5);
+
+        let _ = solver
+            .new_propagator(Element2DPropagator::new(create_matrix(), row, column, rhs))
+            .expect("no empty domains");
+
+        solver.assert_bounds(row, 1, 1);
+        solver.assert_bounds(column, 1, 1);
+
+        let reason = solver.get_reason_int(predicate![row != 0].try_into().unwrap());
+        assert_eq!(
+            conjunction!([rhs != 1] & [rhs != 2] & [rhs != 3]),
+            reason.clone()
+        );
+    }
+
+    #[test]
+    fn rhs_is_restricted_to_values_of_reachable_cells() {
+        let mut solver = TestSolver::default();
+        let row = solver.new_variable(0, 1);
+        let column = solver.new_variable(1, 2);
+        let rhs = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(Element2DPropagator::new(create_matrix(), row, column, rhs))
+            .expect("no empty domains");
+
+        // The reachable cells have the values 2, 3 and 5
+        solver.assert_bounds(rhs, 2, 5);
+        assert!(!solver.contains(rhs, 4));
+
+        let reason = solver.get_reason_int(predicate![rhs != 4].try_into().unwrap());
+        assert_eq!(conjunction!([column != 0]), reason.clone());
+    }
+
+    #[test]
+    fn value_in_no_cell_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let row = solver.new_variable(0, 1);
+        let column = solver.new_variable(0, 2);
+        let rhs = solver.new_variable(6, 8);
+
+        let result =
+            solver.new_propagator(Element2DPropagator::new(create_matrix(), row, column, rhs));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,308 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::variables::Literal;
+use crate::predicate;
+
+/// Bounds-consistent propagator for the constraint `result = (condition ? then_value :
+/// else_value)`.
+///
+/// If `condition` is assigned, then the bounds of `result` and of the selected branch are made
+/// equal. Otherwise, the bounds of `result` are restricted to the union of the bounds of both
+/// branches, and `condition` is assigned if the bounds of `result` are disjoint from the bounds of
+/// one of the branches.
+#[derive(Clone, Debug)]
+pub(crate) struct IfThenElsePropagator<VT, VE, VR> {
+    condition: Literal,
+    then_value: VT,
+    else_value: VE,
+    result: VR,
+}
+
+const ID_RESULT: LocalId = LocalId::from(0);
+const ID_THEN: LocalId = LocalId::from(1);
+const ID_ELSE: LocalId = LocalId::from(2);
+const ID_CONDITION: LocalId = LocalId::from(3);
+
+impl<VT: IntegerVariable, VE: IntegerVariable, VR: IntegerVariable>
+    IfThenElsePropagator<VT, VE, VR>
+{
+    pub(crate) fn new(condition: Literal, then_value: VT, else_value: VE, result: VR) -> Self {
+        IfThenElsePropagator {
+            condition,
+            then_value,
+            else_value,
+            result,
+        }
+    }
+
+    /// Returns the reason why the bounds of `result` are disjoint from the bounds of `branch`, or
+    /// [`None`] if they are not.
+    fn explain_disjoint(
+        &self,
+        context: &PropagationContextMut,
+        branch: &impl IntegerVariable,
+    ) -> Option<PropositionalConjunction> {
+        let result_lower_bound = context.lower_bound(&self.result);
+        let result_upper_bound = context.upper_bound(&self.result);
+
+        if result_upper_bound < context.lower_bound(branch) {
+            Some(
+                [
+                    predicate![self.result <= result_upper_bound],
+                    predicate![branch >= result_upper_bound + 1],
+                ]
+                .into_iter()
+                .collect(),
+            )
+        } else if result_lower_bound > context.upper_bound(branch) {
+            Some(
+                [
+                    predicate![self.result >= result_lower_bound],
+                    predicate![branch <= result_lower_bound - 1],
+                ]
+                .into_iter()
+                .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Makes the bounds of `result` and `branch`, which is selected by `selection`, equal.
+    fn propagate_equality(
+        &self,
+        context: &mut PropagationContextMut,
+        branch: &impl IntegerVariable,
+        selection: Predicate,
+    ) -> PropagationStatusCP {
+        let lower_bound = context.lower_bound(branch);
+        let upper_bound = context.upper_bound(branch);
+        context.set_lower_bound(
+            &self.result,
+            lower_bound,
+            [selection, predicate![branch >= lower_bound]]
+                .into_iter()
+                .collect::<PropositionalConjunction>(),
+        )?;
+        context.set_upper_bound(
+            &self.result,
+            upper_bound,
+            [selection, predicate![branch <= upper_bound]]
+                .into_iter()
+                .collect::<PropositionalConjunction>(),
+        )?;
+
+        let lower_bound = context.lower_bound(&self.result);
+        let upper_bound = context.upper_bound(&self.result);
+        context.set_lower_bound(
+            branch,
+            lower_bound,
+            [selection, predicate![self.result >= lower_bound]]
+                .into_iter()
+                .collect::<PropositionalConjunction>(),
+        )?;
+        context.set_upper_bound(
+            branch,
+            upper_bound,
+            [selection, predicate![self.result <= upper_bound]]
+                .into_iter()
+                .collect::<PropositionalConjunction>(),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<VT: IntegerVariable, VE: IntegerVariable, VR: IntegerVariable> Propagator
+    for IfThenElsePropagator<VT, VE, VR>
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        let _ = context.register(self.result.clone(), DomainEvents::BOUNDS, ID_RESULT);
+        let _ = context.register(self.then_value.clone(), DomainEvents::BOUNDS, ID_THEN);
+        let _ = context.register(self.else_value.clone(), DomainEvents::BOUNDS, ID_ELSE);
+        let _ = context.register_literal(self.condition, DomainEvents::ANY_BOOL, ID_CONDITION);
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        1
+    }
+
+    fn name(&self) -> &str {
+        "IfThenElse"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        if !context.is_literal_fixed(self.condition) {
+            if let Some(reason) = self.explain_disjoint(&context, &self.then_value) {
+                context.assign_literal(self.condition, false, reason)?;
+            }
+        }
+        if !context.is_literal_fixed(self.condition) {
+            if let Some(reason) = self.explain_disjoint(&context, &self.else_value) {
+                context.assign_literal(self.condition, true, reason)?;
+            }
+        }
+
+        if context.is_literal_true(self.condition) {
+            return self.propagate_equality(&mut context, &self.then_value, self.condition.into());
+        }
+        if context.is_literal_false(self.condition) {
+            return self.propagate_equality(
+                &mut context,
+                &self.else_value,
+                (!self.condition).into(),
+            );
+        }
+
+        // The result takes the value of one of the branches
+        let lower_bound = context
+            .lower_bound(&self.then_value)
+            .min(context.lower_bound(&self.else_value));
+        context.set_lower_bound(
+            &self.result,
+            lower_bound,
+            [
+                predicate![self.then_value >= lower_bound],
+                predicate![self.else_value >= lower_bound],
+            ]
+            .into_iter()
+            .collect::<PropositionalConjunction>(),
+        )?;
+
+        let upper_bound = context
+            .upper_bound(&self.then_value)
+            .max(context.upper_bound(&self.else_value));
+        context.set_upper_bound(
+            &self.result,
+            upper_bound,
+            [
+                predicate![self.then_value <= upper_bound],
+                predicate![self.else_value <= upper_bound],
+            ]
+            .into_iter()
+            .collect::<PropositionalConjunction>(),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_helper::TestSolver;
+
+    #[test]
+    fn true_condition_makes_result_equal_to_then_value() {
+        let mut solver = TestSolver::default();
+        let condition = solver.new_literal();
+        let then_value = solver.new_variable(3, 6);
+        let else_value = solver.new_variable(0, 10);
+        let result = solver.new_variable(5, 10);
+        solver.set_literal(condition, true);
+
+        let _ = solver
+            .new_propagator(IfThenElsePropagator::new(
+                condition, then_value, else_value, result,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(result, 5, 6);
+        solver.assert_bounds(then_value, 5, 6);
+        solver.assert_bounds(else_value, 0, 10);
+    }
+
+    #[test]
+    fn false_condition_makes_result_equal_to_else_value() {
+        let mut solver = TestSolver::default();
+        let condition = solver.new_literal();
+        let then_value = solver.new_variable(0, 10);
+        let else_value = solver.new_variable(3, 6);
+        let result = solver.new_variable(0, 4);
+        solver.set_literal(condition, false);
+
+        let _ = solver
+            .new_propagator(IfThenElsePropagator::new(
+                condition, then_value, else_value, result,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(result, 3, 4);
+        solver.assert_bounds(else_value, 3, 4);
+        solver.assert_bounds(then_value, 0, 10);
+    }
+
+    #[test]
+    fn unassigned_condition_restricts_result_to_both_branches() {
+        let mut solver = TestSolver::default();
+        let condition = solver.new_literal();
+        let then_value = solver.new_variable(2, 4);
+        let else_value = solver.new_variable(3, 7);
+        let result = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(IfThenElsePropagator::new(
+                condition, then_value, else_value, result,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(result, 2, 7);
+        assert!(!solver.is_literal_false(condition));
+        assert!(!solver.is_literal_false(!condition));
+    }
+
+    #[test]
+    fn result_disjoint_from_then_value_fixes_the_condition() {
+        let mut solver = TestSolver::default();
+        let condition = solver.new_literal();
+        let then_value = solver.new_variable(0, 4);
+        let else_value = solver.new_variable(3, 10);
+        let result = solver.new_variable(6, 8);
+
+        let _ = solver
+            .new_propagator(IfThenElsePropagator::new(
+                condition, then_value, else_value, result,
+            ))
+            .expect("no empty domains");
+
+        assert!(solver.is_literal_false(condition));
+        solver.assert_bounds(else_value, 6, 8);
+
+        let reason = solver.get_reason_bool(condition, false);
+        assert_eq!(
+            conjunction!([result >= 6] & [then_value <= 5]),
+            reason.clone()
+        );
+    }
+
+    #[test]
+    fn result_disjoint_from_both_branches_is_a_conflict() {
+        let mut solver = TestSolver::default();
+        let condition = solver.new_literal();
+        let then_value = solver.new_variable(0, 2);
+        let else_value = solver.new_variable(8, 10);
+        let result = solver.new_variable(4, 6);
+
+        let result = solver.new_propagator(IfThenElsePropagator::new(
+            condition, then_value, else_value, result,
+        ));
+        assert!(result.is_err());
+    }
+}
@@ -4,8 +4,12 @@
 
 pub(crate) mod arithmetic;
 pub(crate) mod clausal;
+pub(crate) mod count;
 mod cumulative;
+pub(crate) mod disjunctive;
 pub(crate) mod element;
+pub(crate) mod element_2d;
+pub(crate) mod if_then_else;
 mod reified_propagator;
 pub(crate) use arithmetic::*;
 pub use cumulative::CumulativeExplanationType;
@@ -11,6 +11,8 @@ pub use statistic_logging::log_statistic_postfix;
 pub use statistic_logging::should_log_statistics;
 pub use statistic_logging::StatisticOptions;
 
+pub use crate::engine::propagation::PropagatorStatistics;
+
 #[cfg(doc)]
 use crate::create_statistics_struct;
 #[cfg(doc)]
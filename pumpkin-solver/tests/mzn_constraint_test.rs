@@ -61,6 +61,7 @@ mzn_test!(bool_not);
 
 mzn_test!(bool_lin_eq);
 mzn_test!(bool_lin_le);
+mzn_test!(bool2int_in_int_lin_le);
 mzn_test!(bool_clause);
 
 cumulative!(time_table_per_point);
@@ -80,3 +81,4 @@ cumulative_synchronised!(
 );
 
 mzn_test!(all_different);
+mzn_test!(all_different_int);